@@ -0,0 +1,148 @@
+//! Benchmarks of the encoding and decoding of the messages relayers handle most,
+//! through `prost` and through the `Protobuf` trait of a domain type.
+//!
+//! Run with `cargo bench --bench protobuf`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use prost::Message;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{MsgRecvPacket, Packet};
+use ibc_proto::ibc::core::client::v1::{Height, MsgUpdateClient};
+use ibc_proto::ibc::lightclients::tendermint::v1::Header;
+use ibc_proto::protobuf::{IntoConversionError, Name, Protobuf};
+use ibc_proto::tendermint::types::{Commit, CommitSig, SignedHeader, Validator, ValidatorSet};
+
+fn recv_packet() -> MsgRecvPacket {
+    MsgRecvPacket {
+        packet: Some(Packet {
+            sequence: 42,
+            source_port: "transfer".to_owned(),
+            source_channel: "channel-0".to_owned(),
+            destination_port: "transfer".to_owned(),
+            destination_channel: "channel-141".to_owned(),
+            data: vec![0x7b; 512],
+            timeout_height: Some(Height::new(1, 1_000_000)),
+            timeout_timestamp: 1_700_000_000_000_000_000,
+        }),
+        proof_commitment: vec![0xab; 4096].into(),
+        proof_height: Some(Height::new(1, 999_000)),
+        signer: "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu".to_owned(),
+    }
+}
+
+fn update_client() -> MsgUpdateClient {
+    let validators = (0..100u8)
+        .map(|i| Validator {
+            address: vec![i; 20],
+            voting_power: 1_000,
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let header = Header {
+        signed_header: Some(Box::new(SignedHeader {
+            commit: Some(Commit {
+                height: 1_000_000,
+                signatures: (0..100u8)
+                    .map(|i| CommitSig {
+                        block_id_flag: 2,
+                        validator_address: vec![i; 20],
+                        signature: vec![i; 64],
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })),
+        validator_set: Some(Box::new(ValidatorSet {
+            validators: validators.clone(),
+            ..Default::default()
+        })),
+        trusted_height: Some(Height::new(1, 999_000)),
+        trusted_validators: Some(Box::new(ValidatorSet {
+            validators,
+            ..Default::default()
+        })),
+    };
+
+    MsgUpdateClient {
+        client_id: "07-tendermint-0".to_owned(),
+        header: Some(Any {
+            type_url: Header::type_url(),
+            value: header.encode_to_vec(),
+        }),
+        signer: "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu".to_owned(),
+    }
+}
+
+/// A domain type wrapping the raw message, as encoded by the default
+/// `Protobuf::to_raw`, ie. by cloning it.
+#[derive(Clone)]
+struct Domain<T>(T);
+
+impl<T> From<T> for Domain<T> {
+    fn from(raw: T) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<Domain<MsgRecvPacket>> for MsgRecvPacket {
+    fn from(domain: Domain<MsgRecvPacket>) -> Self {
+        domain.0
+    }
+}
+
+impl From<Domain<MsgUpdateClient>> for MsgUpdateClient {
+    fn from(domain: Domain<MsgUpdateClient>) -> Self {
+        domain.0
+    }
+}
+
+impl Protobuf<MsgRecvPacket> for Domain<MsgRecvPacket> {}
+
+impl Protobuf<MsgUpdateClient> for Domain<MsgUpdateClient> {}
+
+fn bench_message<T>(c: &mut Criterion, name: &str, raw: T)
+where
+    T: Message + Default + Clone,
+    Domain<T>: Protobuf<T>,
+    <Domain<T> as ibc_proto::protobuf::erased::TryFrom<T>>::Error: IntoConversionError,
+{
+    let encoded = raw.encode_to_vec();
+    let domain = Domain(raw.clone());
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("prost/encode_to_vec", |b| {
+        b.iter(|| black_box(&raw).encode_to_vec())
+    });
+    group.bench_function("prost/decode", |b| {
+        b.iter(|| T::decode(black_box(encoded.as_slice())).unwrap())
+    });
+    group.bench_function("protobuf/encode_vec", |b| {
+        b.iter(|| black_box(&domain).encode_vec())
+    });
+    group.bench_function("protobuf/encode", |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            buf.clear();
+            black_box(&domain).encode(&mut buf).unwrap();
+        })
+    });
+    group.bench_function("protobuf/encode_length_delimited_vec", |b| {
+        b.iter(|| black_box(&domain).encode_length_delimited_vec())
+    });
+    group.bench_function("protobuf/decode_vec", |b| {
+        b.iter(|| Domain::<T>::decode_vec(black_box(&encoded)).unwrap())
+    });
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_message(c, "MsgRecvPacket", recv_packet());
+    bench_message(c, "MsgUpdateClient", update_client());
+}
+
+criterion_group!(protobuf, benches);
+criterion_main!(protobuf);
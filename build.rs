@@ -0,0 +1,89 @@
+//! Regenerates the Rust sources from the vendored `.proto` files into `OUT_DIR`
+//! when the `compile-protos` feature is enabled, see `build/codegen.rs`.
+//!
+//! The following environment variables are taken into account:
+//!
+//! - `IBC_PROTO_DIR`: directory holding the `cosmos-sdk`, `ibc-go` and
+//!   `interchain-security` protos, in place of the ones vendored under `proto`.
+//! - `IBC_PROTO_TYPE_ATTRIBUTES`: additional type attributes, one
+//!   `<proto path>=<attribute>` pair per line, eg.
+//!   `.ibc.core.client.v1.Height=#[derive(Hash)]`.
+//! - `IBC_PROTO_CODEC`: path of the `tonic` codec of the gRPC clients and servers, in
+//!   place of `tonic::codec::ProstCodec`, eg. `crate::codec::JsonCodec`, which requires
+//!   the `json` feature.
+
+#[cfg(feature = "compile-protos")]
+#[path = "build/codegen.rs"]
+mod codegen;
+
+fn main() {
+    #[cfg(feature = "compile-protos")]
+    compile_protos();
+}
+
+#[cfg(feature = "compile-protos")]
+fn compile_protos() {
+    use std::env;
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-env-changed=IBC_PROTO_DIR");
+    println!("cargo:rerun-if-env-changed=IBC_PROTO_TYPE_ATTRIBUTES");
+    println!("cargo:rerun-if-env-changed=IBC_PROTO_CODEC");
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let proto_dir = env::var_os("IBC_PROTO_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("proto"));
+    let definitions_dir = root.join("definitions");
+
+    if !proto_dir.is_dir() {
+        panic!(
+            "the protos are not found under {}, sync them with `scripts/sync-protobuf.sh` \
+             or point `IBC_PROTO_DIR` to them",
+            proto_dir.display()
+        );
+    }
+
+    println!("cargo:rerun-if-changed={}", proto_dir.display());
+    println!("cargo:rerun-if-changed={}", definitions_dir.display());
+
+    let type_attributes: Vec<(String, String)> = env::var("IBC_PROTO_TYPE_ATTRIBUTES")
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| match line.split_once('=') {
+            Some((path, attribute)) => (path.trim().to_string(), attribute.trim().to_string()),
+            None => panic!("invalid entry in IBC_PROTO_TYPE_ATTRIBUTES: {}", line),
+        })
+        .collect();
+
+    let descriptor_set_path = out_dir.join("ibc-proto-descriptor-set.bin");
+
+    codegen::compile_ibc_protos(
+        &proto_dir.join("ibc-go"),
+        &proto_dir.join("cosmos-sdk"),
+        &proto_dir.join("interchain-security"),
+        &definitions_dir,
+        &out_dir,
+        &descriptor_set_path,
+        &type_attributes,
+    )
+    .expect("failed to compile protos");
+
+    codegen::append_name_impls(&out_dir, &descriptor_set_path)
+        .expect("failed to append `Name` implementations");
+
+    codegen::append_file_descriptor_sets(&out_dir, &descriptor_set_path)
+        .expect("failed to append file descriptor sets");
+
+    codegen::generate_json_impls(&out_dir, &descriptor_set_path)
+        .expect("failed to generate JSON serialization");
+
+    codegen::patch_generated_files(&out_dir).expect("failed to patch generated files");
+
+    if let Ok(codec_path) = env::var("IBC_PROTO_CODEC") {
+        codegen::replace_codec(&out_dir, codec_path.trim()).expect("failed to replace the codec");
+    }
+}
@@ -0,0 +1,996 @@
+//! Generation of the Rust sources from the `.proto` files.
+//!
+//! This module is shared by the `ibc-proto-compiler` tool, which regenerates the sources
+//! checked in under `src/prost`, and by the build script of the crate, which regenerates
+//! them into `OUT_DIR` when the `compile-protos` feature is enabled.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FileDescriptorProto, FileDescriptorSet};
+use similar::TextDiff;
+use walkdir::WalkDir;
+
+/// The packages and messages deriving `serde::Serialize` and `serde::Deserialize`
+/// (unless the `json` feature is enabled), on top of the well-known types.
+const SERDE_PATHS: [&str; 18] = [
+    ".ibc.core.client.v1",
+    ".ibc.core.commitment.v1",
+    ".ibc.core.channel.v1",
+    ".ibc.core.connection.v1",
+    ".ibc.core.types.v1",
+    ".ibc.applications.transfer.v1",
+    ".ibc.applications.transfer.v2",
+    ".ibc.applications.fee.v1",
+    ".ibc.applications.nft_transfer.v1",
+    ".ibc.applications.interchain_accounts.controller.v1",
+    ".ibc.applications.interchain_accounts.v1.InterchainAccountPacketData",
+    ".cosmos.crypto",
+    ".cosmos.base.abci",
+    ".cosmos.tx",
+    ".cosmos.upgrade.v1beta1",
+    ".cosmos.base.v1beta1",
+    ".cosmos.base.query.v1beta1",
+    ".cosmos.bank.v1beta1",
+];
+
+/// The packages and messages deriving `schemars::JsonSchema`, on top of the well-known
+/// types. `MerkleProof` and the types of `tendermint_proto` do not implement it, so it is
+/// only derived on the packages which do not depend on them.
+const JSON_SCHEMA_PATHS: [&str; 14] = [
+    ".ibc.core.client.v1",
+    ".ibc.core.commitment.v1.MerkleRoot",
+    ".ibc.core.commitment.v1.MerklePrefix",
+    ".ibc.core.commitment.v1.MerklePath",
+    ".ibc.core.channel.v1",
+    ".ibc.core.connection.v1",
+    ".ibc.core.types.v1",
+    ".ibc.applications.transfer.v1",
+    ".ibc.applications.transfer.v2",
+    ".ibc.applications.fee.v1",
+    ".cosmos.base.v1beta1",
+    ".cosmos.base.query.v1beta1",
+    ".cosmos.bank.v1beta1",
+    ".cosmos.upgrade.v1beta1",
+];
+
+/// The messages and fields whose `bytes` fields are decoded into `bytes::Bytes` rather
+/// than `Vec<u8>`, so as not to copy the large payloads, on top of the proofs of the IBC
+/// core messages, see `proof_field_paths`.
+const BYTES_PATHS: [&str; 6] = [
+    ".cosmos.tx.v1beta1.TxRaw",
+    ".cosmos.tx.v1beta1.BroadcastTxRequest.tx_bytes",
+    ".cosmos.tx.v1beta1.SimulateRequest.tx_bytes",
+    ".ibc.lightclients.wasm.v1.Contract.code_bytes",
+    ".ibc.lightclients.wasm.v1.MsgStoreCode.wasm_byte_code",
+    ".ibc.lightclients.wasm.v1.QueryCodeResponse.data",
+];
+
+/// The fields of `tendermint_proto` types which are boxed, as these types are large
+/// enough to make the size of the messages holding them, and of the enums holding these
+/// messages downstream, way larger than the ones of the other messages.
+const BOXED_PATHS: [&str; 5] = [
+    ".cosmos.base.tendermint.v1beta1.GetBlockByHeightResponse.block",
+    ".cosmos.base.tendermint.v1beta1.GetLatestBlockResponse.block",
+    ".ibc.lightclients.tendermint.v1.Header.signed_header",
+    ".ibc.lightclients.tendermint.v1.Header.validator_set",
+    ".ibc.lightclients.tendermint.v1.Header.trusted_validators",
+];
+
+/// The files of the well-known types which are compiled on top of the ones imported by
+/// the packages, so that they are all available under `google::protobuf`, eg. for the
+/// packages of newer middlewares which use them. They are found by `protoc` among the
+/// files it ships with.
+const WELL_KNOWN_TYPES: [&str; 4] = [
+    "google/protobuf/empty.proto",
+    "google/protobuf/field_mask.proto",
+    "google/protobuf/struct.proto",
+    "google/protobuf/wrappers.proto",
+];
+
+/// The well-known types whose proto3 canonical JSON representation is not the one of
+/// their fields, and which are thus implemented by hand, see `src/google.rs`, rather
+/// than with `pbjson`.
+const JSON_WELL_KNOWN_PATHS: [&str; 18] = [
+    ".google.protobuf.Any",
+    ".google.protobuf.BoolValue",
+    ".google.protobuf.BytesValue",
+    ".google.protobuf.DoubleValue",
+    ".google.protobuf.Duration",
+    ".google.protobuf.Empty",
+    ".google.protobuf.FieldMask",
+    ".google.protobuf.FloatValue",
+    ".google.protobuf.Int32Value",
+    ".google.protobuf.Int64Value",
+    ".google.protobuf.ListValue",
+    ".google.protobuf.NullValue",
+    ".google.protobuf.StringValue",
+    ".google.protobuf.Struct",
+    ".google.protobuf.Timestamp",
+    ".google.protobuf.UInt32Value",
+    ".google.protobuf.UInt64Value",
+    ".google.protobuf.Value",
+];
+
+/// Compiles the `.proto` files of IBC Go, the Cosmos SDK, the Cosmos ICS and of the
+/// `definitions` directory to Rust, along with the descriptor set of all of them.
+///
+/// `extra_type_attributes` are added on top of the attributes below, as `(path, attribute)`
+/// pairs in the format of [`tonic_build::Builder::type_attribute`].
+pub fn compile_ibc_protos(
+    ibc_dir: &Path,
+    sdk_dir: &Path,
+    ics_dir: &Path,
+    definitions_dir: &Path,
+    out_dir: &Path,
+    descriptor_set_path: &Path,
+    extra_type_attributes: &[(String, String)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "[info ] Compiling IBC .proto files to Rust into '{}'...",
+        out_dir.display()
+    );
+
+    // Paths
+    let proto_paths = [
+        format!("{}/mock", definitions_dir.display()),
+        format!("{}/stride/interchainquery/v1", definitions_dir.display()),
+        format!(
+            "{}/ibc/applications/nft_transfer/v1",
+            definitions_dir.display()
+        ),
+        format!("{}/icq/v1", definitions_dir.display()),
+        format!("{}/packetforward/v1", definitions_dir.display()),
+        format!("{}/ratelimit/v1", definitions_dir.display()),
+        format!("{}/cosmos/tx/signing/v1beta1", definitions_dir.display()),
+        format!("{}/ibc", ibc_dir.display()),
+        format!("{}/cosmos/auth", sdk_dir.display()),
+        format!("{}/cosmos/autocli", sdk_dir.display()),
+        format!("{}/cosmos/authz", sdk_dir.display()),
+        format!("{}/cosmos/consensus", sdk_dir.display()),
+        format!("{}/cosmos/crypto/ed25519", sdk_dir.display()),
+        format!("{}/cosmos/crypto/multisig", sdk_dir.display()),
+        format!("{}/cosmos/crypto/secp256k1", sdk_dir.display()),
+        format!("{}/cosmos/crypto/secp256r1", sdk_dir.display()),
+        format!("{}/cosmos/distribution", sdk_dir.display()),
+        format!("{}/cosmos/evidence", sdk_dir.display()),
+        format!("{}/cosmos/feegrant", sdk_dir.display()),
+        format!("{}/cosmos/gov", sdk_dir.display()),
+        format!("{}/cosmos/group", sdk_dir.display()),
+        format!("{}/cosmos/mint", sdk_dir.display()),
+        format!("{}/cosmos/nft", sdk_dir.display()),
+        format!("{}/cosmos/slashing", sdk_dir.display()),
+        format!("{}/cosmos/tx", sdk_dir.display()),
+        format!("{}/cosmos/base", sdk_dir.display()),
+        format!("{}/cosmos/bank", sdk_dir.display()),
+        format!("{}/cosmos/staking", sdk_dir.display()),
+        format!("{}/cosmos/upgrade", sdk_dir.display()),
+        format!("{}/cosmos/vesting", sdk_dir.display()),
+        format!("{}/interchain_security/ccv/v1", ics_dir.display()),
+        format!("{}/interchain_security/ccv/provider", ics_dir.display()),
+        format!("{}/interchain_security/ccv/consumer", ics_dir.display()),
+    ];
+
+    let proto_includes_paths = [
+        format!("{}", sdk_dir.display()),
+        format!("{}", ibc_dir.display()),
+        format!("{}", ics_dir.display()),
+        format!("{}/mock", definitions_dir.display()),
+        format!("{}/stride/interchainquery/v1", definitions_dir.display()),
+        format!("{}", definitions_dir.display()),
+    ];
+
+    // List available proto files
+    let mut protos: Vec<PathBuf> = vec![];
+    for proto_path in &proto_paths {
+        println!("Looking for proto files in {:?}", proto_path);
+        protos.append(
+            &mut WalkDir::new(proto_path)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| {
+                    e.file_type().is_file()
+                        && e.path().extension().is_some()
+                        && e.path().extension().unwrap() == "proto"
+                })
+                .map(|e| e.into_path())
+                .collect(),
+        );
+    }
+    protos.extend(WELL_KNOWN_TYPES.iter().map(PathBuf::from));
+
+    println!("Found the following protos:");
+    // Show which protos will be compiled
+    for proto in &protos {
+        println!("\t-> {:?}", proto);
+    }
+    println!("[info ] Compiling..");
+
+    // List available paths for dependencies
+    let includes: Vec<PathBuf> = proto_includes_paths.iter().map(PathBuf::from).collect();
+
+    // We can only enable JSON serialization when the `std` feature is enabled,
+    // as it is currently required by `ics23` for it to implement JSON serialization
+    // let attrs_serde = r#"#[derive(::serde::Serialize, ::serde::Deserialize)]"#;
+    // let attrs_serde_default = r#"#[serde(default)]"#;
+    // let attrs_jsonschema =
+    //     r#"#[cfg_attr(feature = "json-schema", derive(::schemars::JsonSchema))]"#;
+
+    let attrs_jsonschema = r#"#[cfg_attr(all(feature = "json-schema", feature = "std"), derive(::schemars::JsonSchema))]"#;
+    let attrs_ord = "#[derive(Eq, PartialOrd, Ord)]";
+    let attrs_eq = "#[derive(Eq)]";
+    // The derived (non-canonical) JSON serialization is replaced by the one generated
+    // with `pbjson` when the `json` feature is enabled, see `generate_json_impls`.
+    let attrs_serde = r#"#[cfg_attr(all(feature = "std", not(feature = "json")), derive(::serde::Serialize, ::serde::Deserialize))]"#;
+    let attrs_serde_default =
+        r#"#[cfg_attr(all(feature = "std", not(feature = "json")), serde(default))]"#;
+    let attrs_borsh =
+        r#"#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]"#;
+    let attrs_arbitrary = r#"#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]"#;
+    let attrs_scale = r#"#[cfg_attr(feature = "parity-scale-codec", derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo))]"#;
+    let attrs_arbitrary_bytes =
+        r#"#[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::bytes))]"#;
+
+    let mut builder = tonic_build::configure()
+        .build_client(true)
+        .compile_well_known_types(true)
+        .client_mod_attribute(".", r#"#[cfg(feature = "client")]"#)
+        .build_server(true)
+        .server_mod_attribute(".", r#"#[cfg(feature = "server")]"#)
+        .out_dir(out_dir)
+        .file_descriptor_set_path(descriptor_set_path)
+        .extern_path(".tendermint", "::tendermint_proto")
+        .extern_path(".ics23", "::ics23");
+
+    // The descriptor set is needed ahead of the code generation to pick some attributes.
+    let descriptor_set = compile_descriptor_set(&protos, &includes, descriptor_set_path)?;
+
+    for (path, attribute) in serde_field_attributes(&descriptor_set) {
+        builder = builder.field_attribute(path, attribute);
+    }
+    for path in SERDE_PATHS {
+        builder = builder.type_attribute(path, attrs_serde);
+    }
+    for path in JSON_SCHEMA_PATHS {
+        builder = builder.type_attribute(path, attrs_jsonschema);
+    }
+
+    // `bytes::Bytes` does not implement `Arbitrary`, and the proofs are all in packages
+    // deriving it.
+    let proof_paths = proof_field_paths(&descriptor_set);
+    for path in &proof_paths {
+        builder = builder.field_attribute(path, attrs_arbitrary_bytes);
+    }
+
+    builder = builder
+        .type_attribute(".google.protobuf.Any", attrs_serde)
+        .type_attribute(".google.protobuf.Any", attrs_eq)
+        .type_attribute(".google.protobuf.Timestamp", attrs_serde)
+        .type_attribute(".google.protobuf.Duration", attrs_serde)
+        .type_attribute(".google.protobuf.Duration", attrs_eq)
+        .type_attribute(".google.protobuf.Empty", attrs_serde)
+        .type_attribute(".google.protobuf.FieldMask", attrs_serde)
+        .type_attribute(".google.protobuf.Struct", attrs_serde)
+        .type_attribute(".google.protobuf.Value", attrs_serde)
+        .type_attribute(".google.protobuf.ListValue", attrs_serde)
+        .type_attribute(".google.protobuf.NullValue", attrs_serde)
+        .type_attribute(".google.protobuf.DoubleValue", attrs_serde)
+        .type_attribute(".google.protobuf.FloatValue", attrs_serde)
+        .type_attribute(".google.protobuf.Int64Value", attrs_serde)
+        .type_attribute(".google.protobuf.UInt64Value", attrs_serde)
+        .type_attribute(".google.protobuf.Int32Value", attrs_serde)
+        .type_attribute(".google.protobuf.UInt32Value", attrs_serde)
+        .type_attribute(".google.protobuf.BoolValue", attrs_serde)
+        .type_attribute(".google.protobuf.StringValue", attrs_serde)
+        .type_attribute(".google.protobuf.BytesValue", attrs_serde)
+        .type_attribute(".ibc.core.client.v1.Height", attrs_ord)
+        .field_attribute(".ibc.core.client.v1.Height", attrs_serde_default)
+        .type_attribute(".google.protobuf.Any", attrs_jsonschema)
+        .type_attribute(".google.protobuf.Timestamp", attrs_jsonschema)
+        .type_attribute(".google.protobuf.Duration", attrs_jsonschema)
+        // `MerkleProof` implements `Arbitrary` by hand, see `src/arbitrary.rs`.
+        .type_attribute(".google.protobuf.Any", attrs_arbitrary)
+        .type_attribute(".google.protobuf.Timestamp", attrs_arbitrary)
+        .type_attribute(".google.protobuf.Duration", attrs_arbitrary)
+        .type_attribute(".cosmos.base.query.v1beta1", attrs_arbitrary)
+        .type_attribute(".cosmos.upgrade.v1beta1", attrs_arbitrary)
+        .type_attribute(".ibc.core.client.v1", attrs_arbitrary)
+        .type_attribute(".ibc.core.commitment.v1.MerkleRoot", attrs_arbitrary)
+        .type_attribute(".ibc.core.commitment.v1.MerklePrefix", attrs_arbitrary)
+        .type_attribute(".ibc.core.commitment.v1.MerklePath", attrs_arbitrary)
+        .type_attribute(".ibc.core.connection.v1", attrs_arbitrary)
+        .type_attribute(".ibc.core.channel.v1", attrs_arbitrary)
+        // `MerkleProof` and the Tendermint `ClientState` are left out, as the `ics23`
+        // types they contain implement neither Borsh nor SCALE.
+        .type_attribute(".google.protobuf.Timestamp", attrs_borsh)
+        .type_attribute(".google.protobuf.Timestamp", attrs_scale)
+        .type_attribute(".google.protobuf.Duration", attrs_borsh)
+        .type_attribute(".google.protobuf.Duration", attrs_scale)
+        .type_attribute(".ibc.core.client.v1.Height", attrs_borsh)
+        .type_attribute(".ibc.core.client.v1.Height", attrs_scale)
+        .type_attribute(".ibc.core.client.v1.IdentifiedClientState", attrs_borsh)
+        .type_attribute(".ibc.core.client.v1.IdentifiedClientState", attrs_scale)
+        .type_attribute(".ibc.core.client.v1.ConsensusStateWithHeight", attrs_borsh)
+        .type_attribute(".ibc.core.client.v1.ConsensusStateWithHeight", attrs_scale)
+        .type_attribute(".ibc.core.client.v1.ClientConsensusStates", attrs_borsh)
+        .type_attribute(".ibc.core.client.v1.ClientConsensusStates", attrs_scale)
+        .type_attribute(".ibc.core.commitment.v1.MerkleRoot", attrs_borsh)
+        .type_attribute(".ibc.core.commitment.v1.MerkleRoot", attrs_scale)
+        .type_attribute(".ibc.core.commitment.v1.MerklePrefix", attrs_borsh)
+        .type_attribute(".ibc.core.commitment.v1.MerklePrefix", attrs_scale)
+        .type_attribute(".ibc.core.commitment.v1.MerklePath", attrs_borsh)
+        .type_attribute(".ibc.core.commitment.v1.MerklePath", attrs_scale)
+        .type_attribute(".ibc.core.channel.v1.Packet", attrs_borsh)
+        .type_attribute(".ibc.core.channel.v1.Packet", attrs_scale)
+        .type_attribute(".ibc.core.channel.v1.PacketState", attrs_borsh)
+        .type_attribute(".ibc.core.channel.v1.PacketState", attrs_scale)
+        .type_attribute(".ibc.core.channel.v1.PacketId", attrs_borsh)
+        .type_attribute(".ibc.core.channel.v1.PacketId", attrs_scale)
+        .type_attribute(
+            ".ibc.lightclients.tendermint.v1.ConsensusState",
+            attrs_borsh,
+        )
+        .type_attribute(
+            ".ibc.lightclients.tendermint.v1.ConsensusState",
+            attrs_scale,
+        )
+        .type_attribute(".ibc.lightclients.tendermint.v1.Fraction", attrs_borsh)
+        .type_attribute(".ibc.lightclients.tendermint.v1.Fraction", attrs_scale)
+        .type_attribute(".ibc.lightclients.solomachine.v3.ClientState", attrs_borsh)
+        .type_attribute(".ibc.lightclients.solomachine.v3.ClientState", attrs_scale)
+        .type_attribute(
+            ".ibc.lightclients.solomachine.v3.ConsensusState",
+            attrs_borsh,
+        )
+        .type_attribute(
+            ".ibc.lightclients.solomachine.v3.ConsensusState",
+            attrs_scale,
+        )
+        .type_attribute(".ibc.lightclients.wasm.v1.ClientState", attrs_borsh)
+        .type_attribute(".ibc.lightclients.wasm.v1.ClientState", attrs_scale)
+        .type_attribute(".ibc.lightclients.wasm.v1.ConsensusState", attrs_borsh)
+        .type_attribute(".ibc.lightclients.wasm.v1.ConsensusState", attrs_scale)
+        .type_attribute(".ibc.lightclients.localhost.v2.ClientState", attrs_borsh)
+        .type_attribute(".ibc.lightclients.localhost.v2.ClientState", attrs_scale);
+
+    // Derive `Eq` and `Hash` on the messages which allow it, see `eq_hash_type_attributes`.
+    for (path, attribute) in eq_hash_type_attributes(&descriptor_set) {
+        builder = builder.type_attribute(path, attribute);
+    }
+
+    for (path, attribute) in extra_type_attributes {
+        builder = builder.type_attribute(path, attribute);
+    }
+
+    let mut config = prost_build::Config::new();
+    // Use `BTreeMap` for map fields, as `HashMap` is not available in `no_std`.
+    config.btree_map(["."]);
+    config.bytes(
+        BYTES_PATHS
+            .iter()
+            .map(|path| path.to_string())
+            .chain(proof_paths),
+    );
+    for path in BOXED_PATHS {
+        config.boxed(path);
+    }
+
+    builder.compile_with_config(config, &protos, &includes)?;
+
+    println!("[info ] Protos compiled successfully");
+
+    Ok(())
+}
+
+/// Runs `protoc` on the given `.proto` files to get their descriptor set ahead of the
+/// code generation, in order to pick some of the type attributes from it.
+fn compile_descriptor_set(
+    protos: &[PathBuf],
+    includes: &[PathBuf],
+    descriptor_set_path: &Path,
+) -> Result<FileDescriptorSet, Box<dyn std::error::Error>> {
+    let mut cmd = std::process::Command::new(prost_build::protoc_from_env());
+    cmd.arg("--include_imports")
+        .arg("-o")
+        .arg(descriptor_set_path);
+    for include in includes {
+        cmd.arg("-I").arg(include);
+    }
+    if let Some(include) = prost_build::protoc_include_from_env() {
+        cmd.arg("-I").arg(include);
+    }
+    cmd.args(protos);
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        return Err(format!("protoc failed: {}", String::from_utf8_lossy(&output.stderr)).into());
+    }
+
+    let bytes = std::fs::read(descriptor_set_path)?;
+    Ok(FileDescriptorSet::decode(bytes.as_slice())?)
+}
+
+/// Returns the type attributes deriving `Eq` and `Hash` on every message which does not
+/// hold, directly or through another message, a floating point number or a type of
+/// `tendermint_proto` or `ics23`, as these do not implement both traits.
+///
+/// The attributes of a message also apply to its nested messages, oneofs and enums,
+/// hence only the outermost eligible messages are returned, and messages with nested
+/// enums are left out since `prost` already derives both traits on enums.
+fn eq_hash_type_attributes(descriptor_set: &FileDescriptorSet) -> Vec<(String, &'static str)> {
+    // `Timestamp` implements both traits by hand, see `src/google.rs`.
+    const EXCLUDED: [&str; 1] = [".google.protobuf.Timestamp"];
+    // These messages already derive `Eq`, see `compile_ibc_protos`.
+    const WITH_EQ: [&str; 3] = [
+        ".google.protobuf.Any",
+        ".google.protobuf.Duration",
+        ".ibc.core.client.v1.Height",
+    ];
+    const EXTERN_PACKAGES: [&str; 2] = [".tendermint.", ".ics23."];
+
+    // All the messages by fully-qualified name, along with the name of their parent.
+    let mut messages: BTreeMap<String, (Option<String>, &DescriptorProto)> = BTreeMap::new();
+    for file in &descriptor_set.file {
+        for message in &file.message_type {
+            collect_messages(
+                &mut messages,
+                &format!(".{}", file.package()),
+                None,
+                message,
+            );
+        }
+    }
+
+    let mut ineligible: BTreeSet<String> = messages
+        .iter()
+        .filter(|(_, (_, message))| {
+            !message.enum_type.is_empty()
+                || message.field.iter().any(|field| {
+                    matches!(field.r#type(), Type::Float | Type::Double)
+                        || EXTERN_PACKAGES
+                            .iter()
+                            .any(|package| field.type_name().starts_with(package))
+                })
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    // Propagate to the messages holding an ineligible message, as a field or nested type.
+    loop {
+        let len = ineligible.len();
+        for (name, (parent, message)) in &messages {
+            if ineligible.contains(name) {
+                if let Some(parent) = parent {
+                    ineligible.insert(parent.clone());
+                }
+            } else if message
+                .field
+                .iter()
+                .any(|field| ineligible.contains(field.type_name()))
+            {
+                ineligible.insert(name.clone());
+            }
+        }
+        if ineligible.len() == len {
+            break;
+        }
+    }
+
+    messages
+        .iter()
+        .filter(|(name, (parent, message))| {
+            !ineligible.contains(name.as_str())
+                && !EXCLUDED.contains(&name.as_str())
+                && !message.options.as_ref().is_some_and(|o| o.map_entry())
+                && parent
+                    .as_ref()
+                    .is_none_or(|parent| ineligible.contains(parent))
+        })
+        .map(|(name, _)| {
+            let attribute = if WITH_EQ.contains(&name.as_str()) {
+                "#[derive(Hash)]"
+            } else {
+                "#[derive(Eq, Hash)]"
+            };
+            (name.clone(), attribute)
+        })
+        .collect()
+}
+
+/// Returns the field attributes (de)serializing the 64-bit integers as strings and the
+/// bytes as base64 in the messages deriving `serde`, as the REST endpoints of the chains
+/// do, see `src/serializers.rs`. The JSON schema of these fields is set to match.
+fn serde_field_attributes(descriptor_set: &FileDescriptorSet) -> Vec<(String, String)> {
+    let matches = |paths: &[&str], name: &str| {
+        paths
+            .iter()
+            .any(|path| name == *path || name.starts_with(&format!("{}.", path)))
+    };
+
+    let mut messages = BTreeMap::new();
+    for file in &descriptor_set.file {
+        for message in &file.message_type {
+            collect_messages(
+                &mut messages,
+                &format!(".{}", file.package()),
+                None,
+                message,
+            );
+        }
+    }
+
+    let mut attributes = Vec::new();
+    for (name, (_, message)) in &messages {
+        let map_entry = message.options.as_ref().is_some_and(|o| o.map_entry());
+        if map_entry || !matches(&SERDE_PATHS, name) {
+            continue;
+        }
+
+        // Optional fields are left out, as the serializers do not handle `Option`s.
+        for field in message.field.iter().filter(|f| !f.proto3_optional()) {
+            let serializer = match field.r#type() {
+                Type::Int64 | Type::Uint64 | Type::Sint64 | Type::Fixed64 | Type::Sfixed64 => {
+                    "from_str"
+                }
+                Type::Bytes => "base64",
+                _ => continue,
+            };
+            let repeated = field.label() == Label::Repeated;
+
+            // The variants of a oneof are addressed through the name of the oneof.
+            let path = match field.oneof_index {
+                Some(index) => format!(
+                    "{}.{}.{}",
+                    name,
+                    message.oneof_decl[index as usize].name(),
+                    field.name()
+                ),
+                None => format!("{}.{}", name, field.name()),
+            };
+
+            attributes.push((
+                path.clone(),
+                format!(
+                    r#"#[cfg_attr(all(feature = "std", not(feature = "json")), serde(with = "crate::serializers::{}{}"))]"#,
+                    serializer,
+                    if repeated { "_vec" } else { "" }
+                ),
+            ));
+
+            if matches(&JSON_SCHEMA_PATHS, name) {
+                attributes.push((
+                    path,
+                    format!(
+                        r#"#[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "{}"))]"#,
+                        if repeated { "::prost::alloc::vec::Vec<String>" } else { "String" }
+                    ),
+                ));
+            }
+        }
+    }
+
+    attributes
+}
+
+/// Returns the paths of the proofs of the IBC core messages, ie. of their `bytes` fields
+/// named `proof*`, which are decoded into `bytes::Bytes` along with `BYTES_PATHS`.
+fn proof_field_paths(descriptor_set: &FileDescriptorSet) -> Vec<String> {
+    let mut messages = BTreeMap::new();
+    for file in &descriptor_set.file {
+        for message in &file.message_type {
+            collect_messages(
+                &mut messages,
+                &format!(".{}", file.package()),
+                None,
+                message,
+            );
+        }
+    }
+
+    messages
+        .iter()
+        .filter(|(name, _)| name.starts_with(".ibc.core."))
+        .flat_map(|(name, (_, message))| {
+            message
+                .field
+                .iter()
+                .filter(|field| field.r#type() == Type::Bytes && field.name().starts_with("proof"))
+                .map(move |field| format!("{}.{}", name, field.name()))
+        })
+        .collect()
+}
+
+fn collect_messages<'a>(
+    messages: &mut BTreeMap<String, (Option<String>, &'a DescriptorProto)>,
+    prefix: &str,
+    parent: Option<&str>,
+    message: &'a DescriptorProto,
+) {
+    let name = format!("{}.{}", prefix, message.name());
+    for nested in &message.nested_type {
+        collect_messages(messages, &name, Some(&name), nested);
+    }
+    messages.insert(name.clone(), (parent.map(str::to_string), message));
+}
+
+/// Appends an implementation of `ibc_proto::protobuf::Name` for every message
+/// to the generated file of the package it belongs to, along with a
+/// `register_messages` function registering all of them in a
+/// `ibc_proto::registry::Registry`.
+pub fn append_name_impls(
+    out_dir: &Path,
+    descriptor_set_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "[info ] Appending `Name` implementations to generated files in '{}'...",
+        out_dir.display()
+    );
+
+    let bytes = std::fs::read(descriptor_set_path)?;
+    let descriptor_set = FileDescriptorSet::decode(bytes.as_slice())?;
+
+    let mut impls: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
+    for file in &descriptor_set.file {
+        let (code, paths) = impls.entry(file.package().to_string()).or_default();
+        for message in &file.message_type {
+            write_name_impls(code, paths, file.package(), &[], message);
+        }
+    }
+
+    for (package, (mut code, paths)) in impls {
+        let path = out_dir.join(format!("{}.rs", package));
+
+        // Packages mapped to external crates (eg. `tendermint_proto`) are not generated.
+        if code.is_empty() || !path.exists() {
+            continue;
+        }
+
+        writeln!(
+            code,
+            "/// Registers all the messages of this package in the given registry."
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {{"
+        )
+        .unwrap();
+        for rust_path in paths {
+            writeln!(code, "    registry.register::<{}>();", rust_path).unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+
+        let mut contents = std::fs::read_to_string(&path)?;
+        contents.push_str(&code);
+        std::fs::write(&path, contents)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the descriptor set of every generated package, ie. the descriptors of its files
+/// and of all their dependencies, into a `<package>.bin` file next to the generated file
+/// of the package, to which a `FILE_DESCRIPTOR_SET` constant embedding it is appended,
+/// eg. to register the package with the gRPC reflection service.
+pub fn append_file_descriptor_sets(
+    out_dir: &Path,
+    descriptor_set_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "[info ] Appending file descriptor sets to generated files in '{}'...",
+        out_dir.display()
+    );
+
+    let bytes = std::fs::read(descriptor_set_path)?;
+    let descriptor_set = FileDescriptorSet::decode(bytes.as_slice())?;
+
+    let files: BTreeMap<&str, &FileDescriptorProto> = descriptor_set
+        .file
+        .iter()
+        .map(|file| (file.name(), file))
+        .collect();
+    let mut packages: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for file in &descriptor_set.file {
+        packages
+            .entry(file.package())
+            .or_default()
+            .push(file.name());
+    }
+
+    for (package, names) in packages {
+        let path = out_dir.join(format!("{}.rs", package));
+
+        // Packages mapped to external crates (eg. `tendermint_proto`) are not generated.
+        if !path.exists() {
+            continue;
+        }
+
+        let mut needed = BTreeSet::new();
+        let mut pending = names;
+        while let Some(name) = pending.pop() {
+            if needed.insert(name) {
+                if let Some(file) = files.get(name) {
+                    pending.extend(file.dependency.iter().map(String::as_str));
+                }
+            }
+        }
+
+        // `protoc` gives the files after their dependencies, which this order preserves.
+        let package_set = FileDescriptorSet {
+            file: descriptor_set
+                .file
+                .iter()
+                .filter(|file| needed.contains(file.name()))
+                .cloned()
+                .collect(),
+        };
+        let bin = format!("{}.bin", package);
+        std::fs::write(out_dir.join(&bin), package_set.encode_to_vec())?;
+
+        let mut contents = std::fs::read_to_string(&path)?;
+        writeln!(
+            contents,
+            "/// The descriptors of the files of this package and of their dependencies, eg. to\n\
+             /// register the package with the gRPC reflection service.\n\
+             pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(\"{}\");",
+            bin
+        )
+        .unwrap();
+        std::fs::write(&path, contents)?;
+    }
+
+    Ok(())
+}
+
+/// Generates proto3 canonical JSON (de)serialization for every message and enum with
+/// `pbjson`, into a `<package>.serde.rs` file next to the generated file of each package.
+///
+/// The well-known types with a dedicated representation are implemented by hand instead,
+/// see `JSON_WELL_KNOWN_PATHS`, eg. `google.protobuf.Timestamp` as an RFC 3339 string,
+/// `google.protobuf.Struct` as a JSON object, and `google.protobuf.Any`, which is encoded
+/// with an `@type` field as in the Cosmos SDK.
+pub fn generate_json_impls(
+    out_dir: &Path,
+    descriptor_set_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "[info ] Generating JSON serialization into '{}'...",
+        out_dir.display()
+    );
+
+    let descriptor_set = std::fs::read(descriptor_set_path)?;
+
+    pbjson_build::Builder::new()
+        .register_descriptors(&descriptor_set)?
+        .out_dir(out_dir)
+        .emit_fields()
+        .ignore_unknown_fields()
+        .btree_map(["."])
+        .exclude([".cosmos.ics23"])
+        .exclude(JSON_WELL_KNOWN_PATHS)
+        .build(&[
+            ".cosmos",
+            ".cosmos_proto",
+            ".google",
+            ".ibc",
+            ".icq",
+            ".interchain_security",
+            ".packetforward",
+            ".ratelimit",
+            ".stride",
+        ])?;
+
+    Ok(())
+}
+
+pub fn patch_generated_files(out_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "[info ] Patching generated files in '{}'...",
+        out_dir.display()
+    );
+
+    {
+        println!("[info ] Patching cosmos.staking.v1beta1.rs...");
+
+        let path = out_dir.join("cosmos.staking.v1beta1.rs");
+        let contents = std::fs::read_to_string(&path)?;
+
+        let patched_contents = contents
+            .replace("pub struct Validators", "pub struct ValidatorsVec")
+            .replace(
+                "for stake_authorization::Validators {",
+                "for stake_authorization::ValidatorsVec {",
+            )
+            .replace("AllowList(Validators)", "AllowList(ValidatorsVec)")
+            .replace("DenyList(Validators)", "DenyList(ValidatorsVec)");
+
+        let diff = TextDiff::from_lines(&contents, &patched_contents);
+        println!("{}", diff.unified_diff().context_radius(3));
+
+        std::fs::write(&path, patched_contents)?;
+    }
+
+    {
+        println!("[info ] Patching cosmos.staking.v1beta1.serde.rs...");
+
+        let path = out_dir.join("cosmos.staking.v1beta1.serde.rs");
+        let contents = std::fs::read_to_string(&path)?;
+
+        let patched_contents = contents
+            .replace(
+                "for stake_authorization::Validators {",
+                "for stake_authorization::ValidatorsVec {",
+            )
+            .replace(
+                "= stake_authorization::Validators;",
+                "= stake_authorization::ValidatorsVec;",
+            )
+            .replace(
+                "<stake_authorization::Validators, V::Error>",
+                "<stake_authorization::ValidatorsVec, V::Error>",
+            )
+            .replace(
+                "Ok(stake_authorization::Validators {",
+                "Ok(stake_authorization::ValidatorsVec {",
+            );
+
+        let diff = TextDiff::from_lines(&contents, &patched_contents);
+        println!("{}", diff.unified_diff().context_radius(3));
+
+        std::fs::write(&path, patched_contents)?;
+    }
+
+    {
+        println!("[info ] Adding the TLS and gRPC-web constructors to the gRPC clients...");
+
+        const CONNECT: &str = "            \
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+";
+        const CONNECT_TLS: &str = r#"        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+"#;
+
+        for entry in std::fs::read_dir(out_dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "rs") {
+                continue;
+            }
+
+            let contents = std::fs::read_to_string(&path)?;
+            if contents.contains(CONNECT) {
+                let patched_contents =
+                    contents.replace(CONNECT, &format!("{CONNECT}{CONNECT_TLS}"));
+                std::fs::write(&path, add_grpc_web_constructors(&patched_contents))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces the `ProstCodec` of the gRPC clients and servers with the codec at the given
+/// path, eg. `crate::codec::JsonCodec`, to exchange the messages in another encoding.
+pub fn replace_codec(out_dir: &Path, codec_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    println!("[info ] Replacing the codec of the gRPC clients and servers with {codec_path}...");
+
+    const PROST_CODEC: &str = "tonic::codec::ProstCodec::default()";
+
+    for entry in std::fs::read_dir(out_dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            continue;
+        }
+
+        let contents = std::fs::read_to_string(&path)?;
+        if contents.contains(PROST_CODEC) {
+            let patched_contents =
+                contents.replace(PROST_CODEC, &format!("{codec_path}::default()"));
+            std::fs::write(&path, patched_contents)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Gates the constructors of the gRPC clients using the `tonic` transport behind the
+/// `transport` feature, as the transport is not available on `wasm32`, and adds a
+/// constructor using a gRPC-web client instead, eg. to query the chains from a browser.
+fn add_grpc_web_constructors(contents: &str) -> String {
+    let mut patched_contents = String::with_capacity(contents.len());
+    let mut client = None;
+    for line in contents.lines() {
+        if let Some(name) = line
+            .strip_prefix("    impl ")
+            .and_then(|line| line.strip_suffix("<tonic::transport::Channel> {"))
+        {
+            patched_contents.push_str("    #[cfg(feature = \"transport\")]\n");
+            client = Some(name.to_owned());
+        }
+
+        patched_contents.push_str(line);
+        patched_contents.push('\n');
+
+        if line == "    }" {
+            if let Some(name) = client.take() {
+                let _ = write!(
+                    patched_contents,
+                    r#"    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl {name}<tonic_web_wasm_client::Client> {{
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {{
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }}
+    }}
+"#
+                );
+            }
+        }
+    }
+
+    patched_contents
+}
+
+fn write_name_impls(
+    code: &mut String,
+    paths: &mut Vec<String>,
+    package: &str,
+    parents: &[&str],
+    message: &DescriptorProto,
+) {
+    // Map entries do not get a Rust struct of their own.
+    if message
+        .options
+        .as_ref()
+        .is_some_and(|options| options.map_entry())
+    {
+        return;
+    }
+
+    let rust_path = parents
+        .iter()
+        .map(|parent| parent.to_snake_case())
+        .chain(std::iter::once(message.name().to_upper_camel_case()))
+        .collect::<Vec<_>>()
+        .join("::");
+
+    let proto_name = parents
+        .iter()
+        .copied()
+        .chain(std::iter::once(message.name()))
+        .collect::<Vec<_>>()
+        .join(".");
+
+    writeln!(code, "impl crate::protobuf::Name for {} {{", rust_path).unwrap();
+    writeln!(code, "    const NAME: &'static str = \"{}\";", proto_name).unwrap();
+    writeln!(code, "    const PACKAGE: &'static str = \"{}\";", package).unwrap();
+    writeln!(code, "}}").unwrap();
+    paths.push(rust_path);
+
+    let parents = parents
+        .iter()
+        .copied()
+        .chain(std::iter::once(message.name()))
+        .collect::<Vec<_>>();
+
+    for nested in &message.nested_type {
+        write_name_impls(code, paths, package, &parents, nested);
+    }
+}
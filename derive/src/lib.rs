@@ -0,0 +1,218 @@
+//! Derive macros for the `ibc_proto::protobuf::Protobuf` trait.
+//!
+//! This crate is not meant to be used directly, enable the `derive` feature
+//! of `ibc-proto` instead and use the macro re-exported as
+//! `ibc_proto::protobuf::Protobuf`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, LitStr, Path, Type,
+};
+
+/// Derives `Protobuf<Raw>` for a domain type.
+///
+/// The raw type is given with the `#[protobuf(raw = "RawType")]` attribute.
+/// The domain type must still implement `Clone`, `TryFrom<RawType>` and
+/// `Into<RawType>`, either by hand or by asking the macro to derive them
+/// field by field:
+///
+/// - `from` derives `From<Self> for RawType`, converting each field with `Into`;
+/// - `try_from = "ErrorType"` derives `TryFrom<RawType> for Self`, converting
+///   each field with `TryFrom` and the resulting errors into `ErrorType` with
+///   `Into`. Fields whose conversion cannot fail can be marked with
+///   `#[protobuf(into)]` so that they are converted with `Into` instead,
+///   which is an error without `try_from`.
+///
+/// Conversions can only be derived for structs with named fields, which must
+/// have the same names as the fields of the raw type.
+///
+/// The path to the `ibc_proto` crate can be overridden with
+/// `#[protobuf(crate = "path::to::ibc_proto")]`.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(raw = "RawHeight", from, try_from = "HeightError")]
+/// pub struct Height {
+///     revision_number: RevisionNumber,
+///     #[protobuf(into)]
+///     revision_height: u64,
+/// }
+/// ```
+#[proc_macro_derive(Protobuf, attributes(protobuf))]
+pub fn derive_protobuf(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct ContainerAttrs {
+    raw: Type,
+    from: bool,
+    try_from: Option<Type>,
+    krate: Path,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut raw = None;
+        let mut from = false;
+        let mut try_from = None;
+        let mut krate = None;
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("protobuf")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("raw") {
+                    raw = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("from") {
+                    from = true;
+                } else if meta.path.is_ident("try_from") {
+                    try_from = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("crate") {
+                    krate = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported `protobuf` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let raw = raw.ok_or_else(|| {
+            Error::new(
+                input.ident.span(),
+                "missing `#[protobuf(raw = \"RawType\")]` attribute",
+            )
+        })?;
+
+        Ok(Self {
+            raw,
+            from,
+            try_from,
+            krate: krate.unwrap_or_else(|| syn::parse_quote!(::ibc_proto)),
+        })
+    }
+}
+
+struct FieldAttrs {
+    into: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut into = false;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("protobuf")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("into") {
+                    into = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `protobuf` field attribute"))
+                }
+            })?;
+        }
+
+        Ok(Self { into })
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&input)?;
+    let ident = &input.ident;
+    let raw = &attrs.raw;
+    let krate = &attrs.krate;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut expanded = quote! {
+        impl #impl_generics #krate::protobuf::Protobuf<#raw> for #ident #ty_generics #where_clause {}
+    };
+
+    // `into` is only taken into account by the derived `TryFrom`.
+    if attrs.try_from.is_none() {
+        if let Data::Struct(data) = &input.data {
+            for field in &data.fields {
+                if FieldAttrs::parse(field)?.into {
+                    return Err(Error::new(
+                        field.span(),
+                        "`#[protobuf(into)]` requires `#[protobuf(try_from = \"ErrorType\")]`",
+                    ));
+                }
+            }
+        }
+    }
+
+    if !attrs.from && attrs.try_from.is_none() {
+        return Ok(expanded);
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    data.fields.span(),
+                    "conversions can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "conversions can only be derived for structs",
+            ))
+        }
+    };
+
+    if attrs.from {
+        let names = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+
+        expanded.extend(quote! {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #raw #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    Self {
+                        #(#names: ::core::convert::Into::into(value.#names),)*
+                    }
+                }
+            }
+        });
+    }
+
+    if let Some(error) = &attrs.try_from {
+        let conversions = fields
+            .iter()
+            .map(|f| {
+                let name = &f.ident;
+                let conversion = if FieldAttrs::parse(f)?.into {
+                    quote! { ::core::convert::Into::into(raw.#name) }
+                } else {
+                    quote! {
+                        ::core::convert::TryFrom::try_from(raw.#name)
+                            .map_err(::core::convert::Into::<#error>::into)?
+                    }
+                };
+                Ok(quote! { #name: #conversion })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        expanded.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#raw> for #ident #ty_generics #where_clause {
+                type Error = #error;
+
+                fn try_from(raw: #raw) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok(Self {
+                        #(#conversions,)*
+                    })
+                }
+            }
+        });
+    }
+
+    Ok(expanded)
+}
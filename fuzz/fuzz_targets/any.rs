@@ -0,0 +1,16 @@
+#![no_main]
+
+use ibc_proto::protobuf::Protobuf;
+use libfuzzer_sys::fuzz_target;
+
+use ibc_proto_fuzz::{Any, MerkleProof, MsgRecvPacket, TendermintHeader};
+
+// Unpacks the `Any`, eg. the client message of a `MsgUpdateClient`, into each of the
+// types matching its type URL.
+fuzz_target!(|data: &[u8]| {
+    if let Some(Any(any)) = Any::fuzz(data) {
+        let _ = MerkleProof::from_any(&any);
+        let _ = MsgRecvPacket::from_any(&any);
+        let _ = TendermintHeader::from_any(&any);
+    }
+});
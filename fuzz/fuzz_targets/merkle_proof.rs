@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc_proto_fuzz::MerkleProof;
+
+fuzz_target!(|data: &[u8]| {
+    MerkleProof::fuzz(data);
+});
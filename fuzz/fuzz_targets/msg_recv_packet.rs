@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc_proto_fuzz::MsgRecvPacket;
+
+fuzz_target!(|data: &[u8]| {
+    MsgRecvPacket::fuzz(data);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc_proto_fuzz::TendermintHeader;
+
+fuzz_target!(|data: &[u8]| {
+    TendermintHeader::fuzz(data);
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc_proto_fuzz::{AuthInfo, Tx, TxBody, TxRaw};
+
+// Decodes the input as each of the tx envelope types, as well as the body and auth
+// info of the `TxRaw`, as a chain does.
+fuzz_target!(|data: &[u8]| {
+    Tx::fuzz(data);
+    if let Some(TxRaw(raw)) = TxRaw::fuzz(data) {
+        TxBody::fuzz(&raw.body_bytes);
+        AuthInfo::fuzz(&raw.auth_info_bytes);
+    }
+});
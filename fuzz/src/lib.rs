@@ -0,0 +1,82 @@
+//! Fuzzing of the decoding of the messages which relayers receive from untrusted
+//! counterparties, eg. the headers and proofs of the IBC messages, through the
+//! [`Protobuf`] trait.
+//!
+//! As `Protobuf` is implemented by domain types, each raw type is wrapped into a
+//! domain type of its own, whose conversions cannot fail, with [`domain_types!`].
+//!
+//! The targets are run with `cargo fuzz`, on a nightly toolchain, from this directory,
+//! eg. `cargo +nightly fuzz run merkle_proof`.
+
+use core::fmt::Debug;
+
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+
+/// Defines a domain type wrapping each of the given raw types, along with a `fuzz`
+/// function decoding it.
+macro_rules! domain_types {
+    ($($name:ident => $raw:ty),* $(,)?) => {
+        $(
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct $name(pub $raw);
+
+            impl From<$raw> for $name {
+                fn from(raw: $raw) -> Self {
+                    Self(raw)
+                }
+            }
+
+            impl From<$name> for $raw {
+                fn from(domain: $name) -> Self {
+                    domain.0
+                }
+            }
+
+            impl Protobuf<$raw> for $name {}
+
+            impl $name {
+                /// Decodes the input, see [`decode`].
+                pub fn fuzz(data: &[u8]) -> Option<Self> {
+                    decode::<Self, $raw>(data)
+                }
+            }
+        )*
+    };
+}
+
+domain_types! {
+    Any => ibc_proto::google::protobuf::Any,
+    AuthInfo => ibc_proto::cosmos::tx::v1beta1::AuthInfo,
+    MerkleProof => ibc_proto::ibc::core::commitment::v1::MerkleProof,
+    MsgRecvPacket => ibc_proto::ibc::core::channel::v1::MsgRecvPacket,
+    TendermintHeader => ibc_proto::ibc::lightclients::tendermint::v1::Header,
+    Tx => ibc_proto::cosmos::tx::v1beta1::Tx,
+    TxBody => ibc_proto::cosmos::tx::v1beta1::TxBody,
+    TxRaw => ibc_proto::cosmos::tx::v1beta1::TxRaw,
+}
+
+/// Decodes the input, as is and length-delimited, and checks that the messages which
+/// are decoded are decoded back from their encoding.
+pub fn decode<D, Raw>(data: &[u8]) -> Option<D>
+where
+    D: Protobuf<Raw> + Debug + PartialEq,
+    Raw: Message + Default,
+{
+    if let Ok(domain) = D::decode_length_delimited_vec(data) {
+        check_round_trip(&domain);
+    }
+
+    let domain = D::decode_vec(data).ok()?;
+    check_round_trip(&domain);
+    Some(domain)
+}
+
+fn check_round_trip<D, Raw>(domain: &D)
+where
+    D: Protobuf<Raw> + Debug + PartialEq,
+    Raw: Message + Default,
+{
+    let decoded = D::decode_vec(&domain.encode_vec()).expect("cannot decode the encoding");
+    assert_eq!(&decoded, domain);
+}
@@ -172,6 +172,16 @@ cd proto
 buf export -v -o ../proto-include
 popd
 
+# Vendor the exported proto files, so that the code
+# can be regenerated at build time with the
+# `compile-protos` feature.
+
+rm -rf proto
+mkdir -p proto
+cp -r "$COSMOS_SDK_DIR/proto-include" proto/cosmos-sdk
+cp -r "$IBC_GO_DIR/proto-include" proto/ibc-go
+cp -r "$COSMOS_ICS_DIR/proto-include" proto/interchain-security
+
 # Remove the existing generated protobuf files
 # so that the newly generated code does not
 # contain removed files.
@@ -0,0 +1,177 @@
+//! Helpers to build the ICS-4 `ibc.core.channel.v1.Acknowledgement` of the packets, and
+//! to encode it to and decode it from its JSON form, which is the one written by the
+//! applications and relayed in `MsgAcknowledgement`, rather than its Protobuf encoding,
+//! ie. `{"result":"<base64>"}` for a success and `{"error":"<message>"}` otherwise.
+//!
+//! The JSON is encoded as by ibc-go, so that the commitments of the acknowledgements
+//! match, eg. with the `<`, `>` and `&` characters of the error messages escaped.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use ::base64::prelude::*;
+
+use crate::ibc::core::channel::v1::acknowledgement::Response;
+use crate::ibc::core::channel::v1::Acknowledgement;
+use crate::wire_json;
+
+impl Acknowledgement {
+    /// Creates a successful acknowledgement with the given result.
+    pub fn success(result: impl Into<Vec<u8>>) -> Self {
+        Self {
+            response: Some(Response::Result(result.into())),
+        }
+    }
+
+    /// Creates the successful acknowledgement of the ICS-20 transfers, whose result is
+    /// the single byte `1`, ie. `{"result":"AQ=="}`.
+    pub fn ics20_success() -> Self {
+        Self::success([1])
+    }
+
+    /// Creates an error acknowledgement with the given message.
+    pub fn error(error: impl Into<String>) -> Self {
+        Self {
+            response: Some(Response::Error(error.into())),
+        }
+    }
+
+    /// Returns whether the acknowledgement is successful.
+    pub fn is_success(&self) -> bool {
+        matches!(self.response, Some(Response::Result(_)))
+    }
+
+    /// Encodes the acknowledgement to its JSON form, eg. `{"result":"AQ=="}`.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        match &self.response {
+            Some(Response::Result(result)) => {
+                let result = BASE64_STANDARD.encode(result);
+                wire_json::write_object([("result", result.as_str())])
+            }
+            Some(Response::Error(error)) => wire_json::write_object([("error", error.as_str())]),
+            None => wire_json::write_object([]),
+        }
+    }
+
+    /// Decodes an acknowledgement from its JSON form, which must hold either a `result`
+    /// or an `error` field.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, AcknowledgementError> {
+        let mut fields = wire_json::parse_object(bytes).ok_or(AcknowledgementError::InvalidJson)?;
+        if fields.len() != 1 {
+            return Err(AcknowledgementError::InvalidJson);
+        }
+
+        let (key, value) = fields.remove(0);
+
+        match key.as_str() {
+            "result" => BASE64_STANDARD
+                .decode(value)
+                .map(Self::success)
+                .map_err(|_| AcknowledgementError::InvalidResult),
+            "error" => Ok(Self::error(value)),
+            _ => Err(AcknowledgementError::UnknownField(key)),
+        }
+    }
+}
+
+/// The errors of the decoding of an [`Acknowledgement`] from its JSON form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcknowledgementError {
+    /// The acknowledgement is not a JSON object made of a single string field.
+    InvalidJson,
+    /// The field is neither `result` nor `error`.
+    UnknownField(String),
+    /// The result is not valid base64.
+    InvalidResult,
+}
+
+impl fmt::Display for AcknowledgementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AcknowledgementError::InvalidJson => {
+                write!(
+                    f,
+                    "acknowledgement is not a JSON object with a single string field"
+                )
+            }
+            AcknowledgementError::UnknownField(key) => {
+                write!(
+                    f,
+                    "unknown acknowledgement field `{key}`, expected `result` or `error`"
+                )
+            }
+            AcknowledgementError::InvalidResult => {
+                write!(f, "acknowledgement result is not valid base64")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AcknowledgementError {}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_ics20_success_json() {
+        let ack = Acknowledgement::ics20_success();
+
+        assert!(ack.is_success());
+        assert_eq!(ack.to_json_bytes(), br#"{"result":"AQ=="}"#);
+        assert_eq!(
+            Acknowledgement::from_json_bytes(br#"{"result":"AQ=="}"#),
+            Ok(ack)
+        );
+    }
+
+    #[test]
+    fn test_error_json() {
+        let ack =
+            Acknowledgement::error("ABCI code: 1: error handling packet: see events for details");
+
+        assert!(!ack.is_success());
+        assert_eq!(
+            ack.to_json_bytes(),
+            br#"{"error":"ABCI code: 1: error handling packet: see events for details"}"#
+        );
+        assert_eq!(
+            Acknowledgement::from_json_bytes(&ack.to_json_bytes()),
+            Ok(ack)
+        );
+
+        assert_eq!(
+            Acknowledgement::error("a < b & c").to_json_bytes(),
+            br#"{"error":"a \u003c b \u0026 c"}"#
+        );
+    }
+
+    #[test]
+    fn test_from_json_bytes_errors() {
+        let cases: [(&[u8], AcknowledgementError); 5] = [
+            (b"{}", AcknowledgementError::InvalidJson),
+            (
+                br#"{"result":"AQ==","error":"e"}"#,
+                AcknowledgementError::InvalidJson,
+            ),
+            (br#"{"result":1}"#, AcknowledgementError::InvalidJson),
+            (
+                br#"{"result":"not base64"}"#,
+                AcknowledgementError::InvalidResult,
+            ),
+            (
+                br#"{"ok":"AQ=="}"#,
+                AcknowledgementError::UnknownField("ok".to_string()),
+            ),
+        ];
+
+        for (json, error) in cases {
+            assert_eq!(Acknowledgement::from_json_bytes(json), Err(error));
+        }
+    }
+}
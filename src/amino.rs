@@ -0,0 +1,524 @@
+//! Support for `SIGN_MODE_LEGACY_AMINO_JSON`, still required by the Ledger hardware
+//! wallets.
+//!
+//! The sign bytes are the [`StdSignDoc`] of the transaction serialized to JSON, with
+//! its keys sorted and without whitespace. Each message is given by its Amino name,
+//! looked up by type URL in a [`Registry`], along with its JSON representation.
+//!
+//! As with the Amino JSON codec of the Cosmos SDK, the messages are rendered from their
+//! descriptors, see [`dynamic`](crate::dynamic), whatever the `serde` implementations
+//! enabled by the `std` or `json` features. The fields are named as in the protos, and
+//! those holding the default value of their type are left out, eg. the empty strings
+//! and lists, `false` or the zero integers, while eg. a `"0"` string is kept. 64-bit
+//! integers are encoded as strings, bytes as base64, enums as numbers, and
+//! `google.protobuf.Any` fields are replaced with the Amino representation of the
+//! message they hold. Messages which need a different representation can be registered
+//! with [`Registry::register_with`].
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use ::base64::prelude::*;
+use prost::DecodeError;
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, MapKey, ReflectMessage, Value as ReflectValue,
+};
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+use crate::cosmos::base::v1beta1::Coin;
+use crate::cosmos::tx::signing::v1beta1::SignMode;
+use crate::cosmos::tx::v1beta1::mode_info::{self, Sum};
+use crate::cosmos::tx::v1beta1::{Fee, ModeInfo, SignerInfo, TxBody};
+use crate::dynamic;
+use crate::google::protobuf::Any;
+use crate::protobuf::Name;
+
+/// Converts the encoded value of a `google.protobuf.Any` to its JSON representation.
+pub type Converter = fn(&[u8]) -> Result<Value, Error>;
+
+/// Maps the type URLs of the messages to their Amino names, along with the conversion
+/// of the messages to JSON.
+#[derive(Clone, Debug)]
+pub struct Registry {
+    pool: DescriptorPool,
+    entries: BTreeMap<String, (String, Option<Converter>)>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Registry {
+    /// Creates an empty registry, rendering the messages from the descriptors of the
+    /// packages of the enabled features.
+    pub fn new() -> Self {
+        let pool = dynamic::descriptor_pool().expect("the descriptors of the crate are valid");
+
+        Self::with_pool(pool)
+    }
+
+    /// Creates an empty registry, rendering the messages from the descriptors of the
+    /// given pool, eg. one shared with [`dynamic`](crate::dynamic) or holding the
+    /// descriptors of the messages of other crates.
+    pub fn with_pool(pool: DescriptorPool) -> Self {
+        Self {
+            pool,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry of the messages of this crate supporting Amino JSON.
+    pub fn with_defaults() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::new();
+
+        #[cfg(feature = "cosmos-bank")]
+        {
+            use crate::cosmos::bank::v1beta1::{MsgMultiSend, MsgSend};
+
+            registry.register::<MsgSend>("cosmos-sdk/MsgSend");
+            registry.register::<MsgMultiSend>("cosmos-sdk/MsgMultiSend");
+        }
+
+        #[cfg(feature = "ibc-transfer")]
+        registry.register::<crate::ibc::applications::transfer::v1::MsgTransfer>(
+            "cosmos-sdk/MsgTransfer",
+        );
+
+        registry
+    }
+
+    /// Registers the Amino name of messages of type `M`, rendered from their descriptor,
+    /// which must be found in the pool of the registry.
+    pub fn register<M: Name>(&mut self, amino_name: impl Into<String>) {
+        self.entries
+            .insert(M::type_url(), (amino_name.into(), None));
+    }
+
+    /// Registers the Amino name of the messages with the given type URL, converted
+    /// to JSON with `converter`, eg. for the messages of other crates.
+    pub fn register_with(
+        &mut self,
+        type_url: impl Into<String>,
+        amino_name: impl Into<String>,
+        converter: Converter,
+    ) {
+        self.entries
+            .insert(type_url.into(), (amino_name.into(), Some(converter)));
+    }
+
+    /// Returns the Amino name registered for the given type URL, if any.
+    pub fn amino_name(&self, type_url: &str) -> Option<&str> {
+        self.entries.get(type_url).map(|(name, _)| name.as_str())
+    }
+
+    /// Converts a packed message to its Amino JSON representation.
+    pub fn to_amino(&self, any: &Any) -> Result<AminoMsg, Error> {
+        let (amino_name, converter) = self
+            .entries
+            .get(&any.type_url)
+            .ok_or_else(|| Error::UnknownTypeUrl(any.type_url.clone()))?;
+
+        let value = match converter {
+            Some(converter) => converter(&any.value)?,
+            None => {
+                let message = dynamic::decode_any(&self.pool, any).map_err(|e| match e {
+                    dynamic::DynamicError::Decode(e) => Error::DecodeMessage(e),
+                    _ => Error::UnknownTypeUrl(any.type_url.clone()),
+                })?;
+                self.message_to_json(&message)?
+            }
+        };
+
+        Ok(AminoMsg {
+            r#type: amino_name.clone(),
+            value,
+        })
+    }
+
+    /// Renders the fields of a message which do not hold their default value.
+    fn message_to_json(&self, message: &DynamicMessage) -> Result<Value, Error> {
+        let mut fields = Map::new();
+        for (field, value) in message.fields() {
+            fields.insert(field.name().to_owned(), self.value_to_json(value)?);
+        }
+
+        Ok(Value::Object(fields))
+    }
+
+    fn value_to_json(&self, value: &ReflectValue) -> Result<Value, Error> {
+        let json = match value {
+            ReflectValue::Bool(boolean) => Value::Bool(*boolean),
+            ReflectValue::I32(n) => Value::from(*n),
+            ReflectValue::U32(n) => Value::from(*n),
+            ReflectValue::I64(n) => Value::String(n.to_string()),
+            ReflectValue::U64(n) => Value::String(n.to_string()),
+            ReflectValue::F32(n) => float_to_json(f64::from(*n)),
+            ReflectValue::F64(n) => float_to_json(*n),
+            ReflectValue::String(string) => Value::String(string.clone()),
+            ReflectValue::Bytes(bytes) => Value::String(BASE64_STANDARD.encode(bytes)),
+            ReflectValue::EnumNumber(n) => Value::from(*n),
+            ReflectValue::Message(message) => self.nested_message_to_json(message)?,
+            ReflectValue::List(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|value| self.value_to_json(value))
+                    .collect::<Result<_, _>>()?,
+            ),
+            ReflectValue::Map(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| Ok((map_key(key), self.value_to_json(value)?)))
+                    .collect::<Result<_, Error>>()?,
+            ),
+        };
+
+        Ok(json)
+    }
+
+    /// Renders a message nested in another one, where `google.protobuf.Any` holds the
+    /// Amino representation of its message, `google.protobuf.Timestamp` is an RFC 3339
+    /// string and `google.protobuf.Duration` a number of nanoseconds, as the `time.Time`
+    /// and `time.Duration` of Go.
+    fn nested_message_to_json(&self, message: &DynamicMessage) -> Result<Value, Error> {
+        match message.descriptor().full_name() {
+            "google.protobuf.Any" => {
+                let any = message
+                    .transcode_to::<Any>()
+                    .map_err(Error::DecodeMessage)?;
+                let msg = self.to_amino(&any)?;
+                serde_json::to_value(msg).map_err(|e| Error::SerializeJson(e.to_string()))
+            }
+            "google.protobuf.Timestamp" => {
+                serde_json::to_value(message).map_err(|e| Error::SerializeJson(e.to_string()))
+            }
+            "google.protobuf.Duration" => {
+                let field = |name: &str| match message.get_field_by_name(name).as_deref() {
+                    Some(ReflectValue::I64(n)) => i128::from(*n),
+                    Some(ReflectValue::I32(n)) => i128::from(*n),
+                    _ => 0,
+                };
+                let nanos = field("seconds") * 1_000_000_000 + field("nanos");
+                Ok(Value::String(nanos.to_string()))
+            }
+            _ => self.message_to_json(message),
+        }
+    }
+}
+
+/// The non-finite numbers, which JSON cannot represent, are rendered as `null`.
+fn float_to_json(n: f64) -> Value {
+    Number::from_f64(n).map_or(Value::Null, Value::Number)
+}
+
+fn map_key(key: &MapKey) -> String {
+    match key {
+        MapKey::Bool(boolean) => boolean.to_string(),
+        MapKey::I32(n) => n.to_string(),
+        MapKey::I64(n) => n.to_string(),
+        MapKey::U32(n) => n.to_string(),
+        MapKey::U64(n) => n.to_string(),
+        MapKey::String(string) => string.clone(),
+    }
+}
+
+/// Sorts the keys of the objects, as required for the sign bytes.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let sorted: BTreeMap<String, Value> = map
+                .into_iter()
+                .map(|(key, value)| (key, sort_keys(value)))
+                .collect();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// A message in its Amino JSON representation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AminoMsg {
+    /// The Amino name of the message, eg. `cosmos-sdk/MsgSend`.
+    pub r#type: String,
+    /// The JSON representation of the message.
+    pub value: Value,
+}
+
+/// The fee of a transaction, in its Amino JSON representation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StdFee {
+    #[serde(serialize_with = "serialize_coins")]
+    pub amount: Vec<Coin>,
+    #[serde(serialize_with = "serialize_u64")]
+    pub gas: u64,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub payer: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub granter: String,
+}
+
+impl From<Fee> for StdFee {
+    fn from(fee: Fee) -> Self {
+        Self {
+            amount: fee.amount,
+            gas: fee.gas_limit,
+            payer: fee.payer,
+            granter: fee.granter,
+        }
+    }
+}
+
+/// The document to sign with `SIGN_MODE_LEGACY_AMINO_JSON`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StdSignDoc {
+    #[serde(serialize_with = "serialize_u64")]
+    pub account_number: u64,
+    pub chain_id: String,
+    pub fee: StdFee,
+    pub memo: String,
+    pub msgs: Vec<AminoMsg>,
+    #[serde(serialize_with = "serialize_u64")]
+    pub sequence: u64,
+    #[serde(serialize_with = "serialize_u64", skip_serializing_if = "is_zero")]
+    pub timeout_height: u64,
+}
+
+fn is_zero(n: &u64) -> bool {
+    *n == 0
+}
+
+/// Serializes the 64-bit integers as strings, as Amino JSON does.
+fn serialize_u64<S: Serializer>(n: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(n)
+}
+
+/// Serializes the coins with both of their fields, even when empty, whatever the `serde`
+/// implementation of `Coin`.
+fn serialize_coins<S: Serializer>(coins: &[Coin], serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct AminoCoin<'a> {
+        amount: &'a str,
+        denom: &'a str,
+    }
+
+    serializer.collect_seq(coins.iter().map(|coin| AminoCoin {
+        amount: &coin.amount,
+        denom: &coin.denom,
+    }))
+}
+
+impl StdSignDoc {
+    /// Creates the document to sign for the given transaction body and fee, converting
+    /// the messages of the body with the given registry.
+    pub fn new(
+        registry: &Registry,
+        body: &TxBody,
+        fee: impl Into<StdFee>,
+        chain_id: impl Into<String>,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, Error> {
+        let msgs = body
+            .messages
+            .iter()
+            .map(|any| registry.to_amino(any))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            account_number,
+            chain_id: chain_id.into(),
+            fee: fee.into(),
+            memo: body.memo.clone(),
+            msgs,
+            sequence,
+            timeout_height: body.timeout_height,
+        })
+    }
+
+    /// Returns the bytes to sign with `SIGN_MODE_LEGACY_AMINO_JSON`, ie. the document
+    /// serialized to JSON with its keys sorted, no whitespace, and with `<`, `>` and `&`
+    /// escaped as in the Cosmos SDK.
+    pub fn sign_bytes(&self) -> Result<Vec<u8>, Error> {
+        let value = serde_json::to_value(self).map_err(|e| Error::SerializeJson(e.to_string()))?;
+        let json = serde_json::to_string(&sort_keys(value))
+            .map_err(|e| Error::SerializeJson(e.to_string()))?;
+
+        Ok(json
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026")
+            .into_bytes())
+    }
+}
+
+impl SignerInfo {
+    /// Creates the info of a single signer, signing with `SIGN_MODE_LEGACY_AMINO_JSON`.
+    pub fn legacy_amino_json(public_key: Option<Any>, sequence: u64) -> Self {
+        Self {
+            public_key,
+            mode_info: Some(ModeInfo {
+                sum: Some(Sum::Single(mode_info::Single {
+                    mode: SignMode::LegacyAminoJson as i32,
+                })),
+            }),
+            sequence,
+        }
+    }
+}
+
+/// The errors of the conversion of the messages to Amino JSON.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// No Amino name is registered for the type URL, or its message is not found in the
+    /// pool of the registry.
+    UnknownTypeUrl(String),
+    /// The message could not be decoded.
+    DecodeMessage(DecodeError),
+    /// The message could not be serialized to JSON.
+    SerializeJson(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownTypeUrl(type_url) => {
+                write!(f, "no Amino name registered for type URL `{type_url}`")
+            }
+            Error::DecodeMessage(e) => {
+                write!(f, "error decoding message to convert to Amino JSON: {e}")
+            }
+            Error::SerializeJson(e) => write!(f, "error serializing to JSON: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DecodeMessage(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "cosmos-bank"))]
+mod test {
+    use super::*;
+    use crate::cosmos::bank::v1beta1::MsgSend;
+    use prost::Message;
+
+    fn coin(amount: &str, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn msg_send(amount: Vec<Coin>) -> Any {
+        let msg = MsgSend {
+            from_address: "cosmos1from".to_string(),
+            to_address: "cosmos1to".to_string(),
+            amount,
+        };
+
+        Any {
+            type_url: MsgSend::type_url(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_sign_bytes_msg_send() {
+        let registry = Registry::with_defaults();
+        let body = TxBody {
+            messages: vec![msg_send(vec![coin("10", "uatom")])],
+            memo: "a <memo> & more".to_string(),
+            ..Default::default()
+        };
+        let fee = Fee {
+            amount: vec![coin("150", "uatom")],
+            gas_limit: 200000,
+            ..Default::default()
+        };
+
+        let sign_doc = StdSignDoc::new(&registry, &body, fee, "cosmoshub-4", 1, 0).unwrap();
+
+        assert_eq!(
+            String::from_utf8(sign_doc.sign_bytes().unwrap()).unwrap(),
+            concat!(
+                r#"{"account_number":"1","chain_id":"cosmoshub-4","#,
+                r#""fee":{"amount":[{"amount":"150","denom":"uatom"}],"gas":"200000"},"#,
+                r#""memo":"a \u003cmemo\u003e \u0026 more","#,
+                r#""msgs":[{"type":"cosmos-sdk/MsgSend","value":{"#,
+                r#""amount":[{"amount":"10","denom":"uatom"}],"#,
+                r#""from_address":"cosmos1from","to_address":"cosmos1to"}}],"#,
+                r#""sequence":"0"}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_amino_omits_defaults_by_field_type() {
+        let registry = Registry::with_defaults();
+
+        let msg = registry
+            .to_amino(&msg_send(vec![coin("0", "uatom"), coin("", "")]))
+            .unwrap();
+
+        assert_eq!(msg.r#type, "cosmos-sdk/MsgSend");
+        assert_eq!(
+            msg.value,
+            serde_json::json!({
+                "from_address": "cosmos1from",
+                "to_address": "cosmos1to",
+                "amount": [{"denom": "uatom", "amount": "0"}, {}],
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_amino_unknown_type_url() {
+        let any = Any {
+            type_url: "/cosmos.bank.v1beta1.Unknown".to_string(),
+            value: Vec::new(),
+        };
+
+        assert_eq!(
+            Registry::with_defaults().to_amino(&any),
+            Err(Error::UnknownTypeUrl(any.type_url))
+        );
+    }
+
+    #[test]
+    fn test_register_with_converter() {
+        fn converter(value: &[u8]) -> Result<Value, Error> {
+            Ok(Value::String(BASE64_STANDARD.encode(value)))
+        }
+
+        let mut registry = Registry::with_defaults();
+        registry.register_with("/example.v1.MsgFoo", "example/MsgFoo", converter);
+
+        let any = Any {
+            type_url: "/example.v1.MsgFoo".to_string(),
+            value: vec![1],
+        };
+
+        assert_eq!(registry.amino_name(&any.type_url), Some("example/MsgFoo"));
+        assert_eq!(
+            registry.to_amino(&any),
+            Ok(AminoMsg {
+                r#type: "example/MsgFoo".to_string(),
+                value: Value::String("AQ==".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! JSON representation of `google.protobuf.Any`, as used by the Cosmos SDK and the
+//! proto3 JSON mapping: the type URL is given in an `@type` field, alongside the fields
+//! of the packed message, eg. for a gov proposal:
+//!
+//! ```json
+//! {
+//!   "@type": "/cosmos.gov.v1beta1.TextProposal",
+//!   "title": "...",
+//!   "description": "..."
+//! }
+//! ```
+//!
+//! The packed message is only expanded if its type is known, see `find`, eg. the IBC
+//! client states and the gov proposals. The well-known types, which have a dedicated JSON
+//! representation, eg. `google.protobuf.Timestamp` or `google.protobuf.Struct`, are
+//! given in a `value` field, as are the messages of unknown types, encoded as base64.
+//!
+//! The `{"typeUrl": ..., "value": ...}` representation produced by `pbjson` is still
+//! accepted when deserializing.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use ::base64::prelude::*;
+use prost::Message;
+use serde::de::{self, DeserializeOwned};
+use serde::ser::{self, SerializeMap};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::google::protobuf::Any;
+use crate::protobuf::Name;
+
+/// The conversions of a message type to and from JSON.
+struct JsonType {
+    to_json: fn(&[u8]) -> Result<Value, String>,
+    from_json: fn(Value) -> Result<Vec<u8>, String>,
+    /// Whether the JSON of the message is given in a `value` field, rather than
+    /// alongside the `@type` field, as for the well-known types.
+    nested: bool,
+}
+
+impl JsonType {
+    fn of<M>(nested: bool) -> Self
+    where
+        M: Message + Default + Serialize + DeserializeOwned,
+    {
+        Self {
+            to_json: to_json::<M>,
+            from_json: from_json::<M>,
+            nested,
+        }
+    }
+}
+
+fn to_json<M>(bytes: &[u8]) -> Result<Value, String>
+where
+    M: Message + Default + Serialize,
+{
+    let msg = M::decode(bytes).map_err(|e| e.to_string())?;
+    serde_json::to_value(msg).map_err(|e| e.to_string())
+}
+
+fn from_json<M>(value: Value) -> Result<Vec<u8>, String>
+where
+    M: Message + DeserializeOwned,
+{
+    let msg: M = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok(msg.encode_to_vec())
+}
+
+/// Returns whether the type URL is the one of `M`, without allocating it.
+fn is_type_url<M: Name>(type_url: &str) -> bool {
+    type_url
+        .strip_prefix('/')
+        .and_then(|name| name.strip_prefix(M::PACKAGE))
+        .and_then(|name| name.strip_prefix('.'))
+        == Some(M::NAME)
+}
+
+/// Returns the conversions of the messages with the given type URL, if known.
+fn find(type_url: &str) -> Option<JsonType> {
+    macro_rules! find {
+        ($($(#[$meta:meta])* $nested:literal => [$($ty:path),* $(,)?]),* $(,)?) => {
+            $(
+                $(#[$meta])*
+                {
+                    $(
+                        if is_type_url::<$ty>(type_url) {
+                            return Some(JsonType::of::<$ty>($nested));
+                        }
+                    )*
+                }
+            )*
+        };
+    }
+
+    find! {
+        true => [
+            crate::google::protobuf::BoolValue,
+            crate::google::protobuf::BytesValue,
+            crate::google::protobuf::DoubleValue,
+            crate::google::protobuf::Duration,
+            crate::google::protobuf::Empty,
+            crate::google::protobuf::FieldMask,
+            crate::google::protobuf::FloatValue,
+            crate::google::protobuf::Int32Value,
+            crate::google::protobuf::Int64Value,
+            crate::google::protobuf::ListValue,
+            crate::google::protobuf::StringValue,
+            crate::google::protobuf::Struct,
+            crate::google::protobuf::Timestamp,
+            crate::google::protobuf::UInt32Value,
+            crate::google::protobuf::UInt64Value,
+            crate::google::protobuf::Value,
+        ],
+        false => [
+            crate::cosmos::crypto::ed25519::PubKey,
+            crate::cosmos::crypto::multisig::LegacyAminoPubKey,
+            crate::cosmos::crypto::secp256k1::PubKey,
+            crate::cosmos::crypto::secp256r1::PubKey,
+        ],
+        #[cfg(feature = "cosmos-auth")]
+        false => [
+            crate::cosmos::auth::v1beta1::BaseAccount,
+            crate::cosmos::auth::v1beta1::ModuleAccount,
+        ],
+        #[cfg(feature = "cosmos-authz")]
+        false => [crate::cosmos::authz::v1beta1::GenericAuthorization],
+        #[cfg(feature = "cosmos-bank")]
+        false => [
+            crate::cosmos::bank::v1beta1::MsgMultiSend,
+            crate::cosmos::bank::v1beta1::MsgSend,
+            crate::cosmos::bank::v1beta1::SendAuthorization,
+        ],
+        #[cfg(feature = "cosmos-feegrant")]
+        false => [
+            crate::cosmos::feegrant::v1beta1::AllowedMsgAllowance,
+            crate::cosmos::feegrant::v1beta1::BasicAllowance,
+            crate::cosmos::feegrant::v1beta1::PeriodicAllowance,
+        ],
+        #[cfg(feature = "cosmos-gov")]
+        false => [
+            crate::cosmos::gov::v1::MsgExecLegacyContent,
+            crate::cosmos::gov::v1::MsgSubmitProposal,
+            crate::cosmos::gov::v1beta1::TextProposal,
+        ],
+        #[cfg(feature = "cosmos-staking")]
+        false => [crate::cosmos::staking::v1beta1::StakeAuthorization],
+        #[cfg(feature = "cosmos-upgrade")]
+        false => [
+            crate::cosmos::upgrade::v1beta1::CancelSoftwareUpgradeProposal,
+            crate::cosmos::upgrade::v1beta1::SoftwareUpgradeProposal,
+        ],
+        #[cfg(feature = "ibc-core")]
+        false => [
+            crate::ibc::core::client::v1::ClientUpdateProposal,
+            crate::ibc::core::client::v1::MsgCreateClient,
+            crate::ibc::core::client::v1::MsgIBCSoftwareUpgrade,
+            crate::ibc::core::client::v1::MsgRecoverClient,
+            crate::ibc::core::client::v1::MsgUpdateClient,
+            crate::ibc::core::client::v1::MsgUpdateParams,
+            crate::ibc::core::client::v1::UpgradeProposal,
+            crate::ibc::core::channel::v1::MsgUpdateParams,
+            crate::ibc::core::connection::v1::MsgUpdateParams,
+        ],
+        #[cfg(feature = "ibc-lightclients")]
+        false => [
+            crate::ibc::lightclients::localhost::v2::ClientState,
+            crate::ibc::lightclients::solomachine::v3::ClientState,
+            crate::ibc::lightclients::solomachine::v3::ConsensusState,
+            crate::ibc::lightclients::solomachine::v3::Header,
+            crate::ibc::lightclients::tendermint::v1::ClientState,
+            crate::ibc::lightclients::tendermint::v1::ConsensusState,
+            crate::ibc::lightclients::tendermint::v1::Header,
+            crate::ibc::lightclients::tendermint::v1::Misbehaviour,
+            crate::ibc::lightclients::wasm::v1::ClientState,
+            crate::ibc::lightclients::wasm::v1::ConsensusState,
+        ],
+        #[cfg(feature = "ibc-transfer")]
+        false => [crate::ibc::applications::transfer::v1::MsgTransfer],
+    }
+
+    None
+}
+
+impl Serialize for Any {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("@type", &self.type_url)?;
+
+        match find(&self.type_url) {
+            Some(json_type) => {
+                let json = (json_type.to_json)(&self.value).map_err(ser::Error::custom)?;
+                match json {
+                    Value::Object(fields) if !json_type.nested => {
+                        for (key, value) in &fields {
+                            map.serialize_entry(key, value)?;
+                        }
+                    }
+                    json => map.serialize_entry("value", &json)?,
+                }
+            }
+            None => map.serialize_entry("value", &BASE64_STANDARD.encode(&self.value))?,
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Any {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Map::<String, Value>::deserialize(deserializer)?;
+
+        // The representation of `pbjson`, with the value always encoded as base64.
+        let legacy = !fields.contains_key("@type");
+        let type_url = match ["@type", "typeUrl", "type_url"]
+            .iter()
+            .find_map(|key| fields.remove(*key))
+        {
+            Some(Value::String(type_url)) => type_url,
+            Some(_) => return Err(de::Error::custom("expected the type URL as a string")),
+            None => return Err(de::Error::missing_field("@type")),
+        };
+
+        let value = match find(&type_url) {
+            Some(json_type) if !legacy => {
+                let json = if json_type.nested {
+                    fields.remove("value").unwrap_or(Value::Null)
+                } else {
+                    Value::Object(fields)
+                };
+                (json_type.from_json)(json).map_err(de::Error::custom)?
+            }
+            _ => decode_base64(fields.remove("value"))?,
+        };
+
+        Ok(Any { type_url, value })
+    }
+}
+
+fn decode_base64<E: de::Error>(value: Option<Value>) -> Result<Vec<u8>, E> {
+    match value {
+        Some(Value::String(base64)) => BASE64_STANDARD.decode(base64).map_err(E::custom),
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(_) => Err(E::custom("expected the value of an `Any` as base64")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use alloc::vec;
+    use serde_json::json;
+
+    use crate::cosmos::crypto::secp256k1;
+    use crate::google::protobuf::Duration;
+
+    fn roundtrip(any: &Any, expected: Value) {
+        let json = serde_json::to_value(any).unwrap();
+        assert_eq!(json, expected);
+        assert_eq!(&serde_json::from_value::<Any>(json).unwrap(), any);
+    }
+
+    #[test]
+    fn test_is_type_url() {
+        assert!(is_type_url::<secp256k1::PubKey>(
+            "/cosmos.crypto.secp256k1.PubKey"
+        ));
+        assert!(!is_type_url::<secp256k1::PubKey>(
+            "cosmos.crypto.secp256k1.PubKey"
+        ));
+        assert!(!is_type_url::<secp256k1::PubKey>(
+            "/cosmos.crypto.secp256k1PubKey"
+        ));
+        assert!(!is_type_url::<secp256k1::PubKey>(
+            "/cosmos.crypto.secp256k1.PubKey2"
+        ));
+        assert!(!is_type_url::<secp256k1::PubKey>(
+            "/cosmos.crypto.ed25519.PubKey"
+        ));
+        assert!(!is_type_url::<secp256k1::PubKey>(""));
+    }
+
+    #[test]
+    fn test_known_type_json() {
+        let key = secp256k1::PubKey {
+            key: vec![0x02, 0xa1],
+        };
+        let any = Any {
+            type_url: secp256k1::PubKey::type_url(),
+            value: key.encode_to_vec(),
+        };
+        roundtrip(
+            &any,
+            json!({"@type": "/cosmos.crypto.secp256k1.PubKey", "key": "AqE="}),
+        );
+
+        // The representation of `pbjson` is accepted as well.
+        let json = json!({"typeUrl": "/cosmos.crypto.secp256k1.PubKey", "value": "CgICoQ=="});
+        assert_eq!(serde_json::from_value::<Any>(json).unwrap(), any);
+    }
+
+    #[test]
+    fn test_unknown_type_json() {
+        let any = Any {
+            type_url: "/unknown.v1.Message".into(),
+            value: vec![0x01, 0x02],
+        };
+        roundtrip(
+            &any,
+            json!({"@type": "/unknown.v1.Message", "value": "AQI="}),
+        );
+
+        let any = Any {
+            type_url: "/unknown.v1.Message".into(),
+            value: vec![],
+        };
+        let json = json!({"@type": "/unknown.v1.Message"});
+        assert_eq!(serde_json::from_value::<Any>(json).unwrap(), any);
+
+        assert!(serde_json::from_value::<Any>(json!({"value": "AQI="})).is_err());
+        assert!(serde_json::from_value::<Any>(json!({"@type": 1})).is_err());
+        assert!(serde_json::from_value::<Any>(json!({"@type": "/a.B", "value": 1})).is_err());
+    }
+
+    #[test]
+    fn test_well_known_type_json() {
+        let duration = Duration {
+            seconds: 1,
+            nanos: 500_000_000,
+        };
+        let any = Any {
+            type_url: Duration::type_url(),
+            value: duration.encode_to_vec(),
+        };
+        roundtrip(
+            &any,
+            json!({"@type": "/google.protobuf.Duration", "value": "1.500s"}),
+        );
+
+        // The JSON objects of the well-known types are not merged with the `@type` field.
+        let json = json!({"a": [1.0, "b"]});
+        let value: crate::google::protobuf::Value = serde_json::from_value(json.clone()).unwrap();
+        let any = Any {
+            type_url: crate::google::protobuf::Value::type_url(),
+            value: value.encode_to_vec(),
+        };
+        roundtrip(
+            &any,
+            json!({"@type": "/google.protobuf.Value", "value": json}),
+        );
+    }
+}
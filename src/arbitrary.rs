@@ -0,0 +1,151 @@
+//! Implementation of [`Arbitrary`] for [`MerkleProof`], by hand as the `ics23` proofs
+//! it wraps do not implement it.
+//!
+//! The generated proofs are well-formed, ie. made of existence or non-existence proofs
+//! using supported hash and length operations, but are not expected to verify.
+//!
+//! The `bytes::Bytes` fields, eg. the proofs of the IBC messages, are generated with
+//! [`bytes`], as `Bytes` does not implement `Arbitrary` either.
+
+use alloc::vec::Vec;
+
+use bytes::Bytes;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use ics23::commitment_proof::Proof;
+use ics23::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof};
+
+use crate::commitment::generate::{HASH_OPS, LENGTH_OPS, MAX_PATH_LEN, MAX_PROOFS_LEN};
+use crate::ibc::core::commitment::v1::MerkleProof;
+
+impl<'a> Arbitrary<'a> for MerkleProof {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(1..=MAX_PROOFS_LEN)?;
+        let proofs = (0..len)
+            .map(|_| arbitrary_commitment_proof(u))
+            .collect::<Result<_>>()?;
+
+        Ok(MerkleProof { proofs })
+    }
+}
+
+fn arbitrary_commitment_proof(u: &mut Unstructured<'_>) -> Result<CommitmentProof> {
+    let proof = if u.arbitrary()? {
+        Proof::Exist(arbitrary_existence_proof(u)?)
+    } else {
+        Proof::Nonexist(arbitrary_non_existence_proof(u)?)
+    };
+
+    Ok(CommitmentProof { proof: Some(proof) })
+}
+
+fn arbitrary_non_existence_proof(u: &mut Unstructured<'_>) -> Result<NonExistenceProof> {
+    let left = if u.arbitrary()? {
+        Some(arbitrary_existence_proof(u)?)
+    } else {
+        None
+    };
+    let right = if left.is_none() || u.arbitrary()? {
+        Some(arbitrary_existence_proof(u)?)
+    } else {
+        None
+    };
+
+    Ok(NonExistenceProof {
+        key: u.arbitrary()?,
+        left,
+        right,
+    })
+}
+
+fn arbitrary_existence_proof(u: &mut Unstructured<'_>) -> Result<ExistenceProof> {
+    let len = u.int_in_range(0..=MAX_PATH_LEN)?;
+    let path = (0..len)
+        .map(|_| arbitrary_inner_op(u))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ExistenceProof {
+        key: u.arbitrary()?,
+        value: u.arbitrary()?,
+        leaf: Some(arbitrary_leaf_op(u)?),
+        path,
+    })
+}
+
+fn arbitrary_leaf_op(u: &mut Unstructured<'_>) -> Result<LeafOp> {
+    Ok(LeafOp {
+        hash: arbitrary_hash_op(u)?,
+        prehash_key: arbitrary_hash_op(u)?,
+        prehash_value: arbitrary_hash_op(u)?,
+        length: *u.choose(&LENGTH_OPS)?,
+        prefix: u.arbitrary()?,
+    })
+}
+
+fn arbitrary_inner_op(u: &mut Unstructured<'_>) -> Result<InnerOp> {
+    Ok(InnerOp {
+        hash: arbitrary_hash_op(u)?,
+        prefix: u.arbitrary()?,
+        suffix: u.arbitrary()?,
+    })
+}
+
+fn arbitrary_hash_op(u: &mut Unstructured<'_>) -> Result<i32> {
+    u.choose(&HASH_OPS).copied()
+}
+
+/// Generates the `bytes::Bytes` fields, through `#[arbitrary(with = ...)]`.
+pub(crate) fn bytes(u: &mut Unstructured<'_>) -> Result<Bytes> {
+    Vec::<u8>::arbitrary(u).map(Bytes::from)
+}
+
+#[cfg(test)]
+mod test {
+    use prost::Message;
+
+    use super::*;
+    use crate::ibc::core::channel::v1::Packet;
+
+    /// Returns the pseudo-random bytes to generate the values of the given seed from.
+    fn data(seed: u64) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+        (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merkle_proof() {
+        for seed in 0..64 {
+            let data = data(seed);
+            let proof = MerkleProof::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+            assert!((1..=MAX_PROOFS_LEN).contains(&proof.proofs.len()));
+            for proof in &proof.proofs {
+                match proof.proof.as_ref().unwrap() {
+                    Proof::Exist(exist) => assert!(exist.path.len() <= MAX_PATH_LEN),
+                    Proof::Nonexist(nonexist) => {
+                        assert!(nonexist.left.is_some() || nonexist.right.is_some())
+                    }
+                    proof => panic!("unexpected proof: {proof:?}"),
+                }
+            }
+            assert_eq!(MerkleProof::decode(&*proof.encode_to_vec()).unwrap(), proof);
+        }
+    }
+
+    #[test]
+    fn test_packet() {
+        for seed in 0..64 {
+            let data = data(seed);
+            let packet = Packet::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+            assert_eq!(Packet::decode(&*packet.encode_to_vec()).unwrap(), packet);
+        }
+    }
+}
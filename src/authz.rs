@@ -0,0 +1,157 @@
+//! Helpers to build the messages of the `cosmos.authz.v1beta1` module.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use prost::Message;
+
+use crate::cosmos::authz::v1beta1::{GenericAuthorization, Grant, MsgExec};
+use crate::google::protobuf::{Any, Timestamp};
+use crate::protobuf::{erased, IntoConversionError, Name, Protobuf};
+
+impl GenericAuthorization {
+    /// Authorizes the execution of the message with the given type URL.
+    pub fn new(msg_type_url: impl Into<String>) -> Self {
+        Self {
+            msg: msg_type_url.into(),
+        }
+    }
+
+    /// Authorizes the execution of messages of type `M`.
+    pub fn for_msg<M: Name>() -> Self {
+        Self::new(M::type_url())
+    }
+}
+
+impl Grant {
+    /// Creates a grant of the given authorization, packed into a `google.protobuf.Any`.
+    ///
+    /// Without an `expiration`, the grant only ends when it is revoked or when
+    /// the authorization itself becomes invalid.
+    pub fn new<A>(authorization: &A, expiration: Option<Timestamp>) -> Self
+    where
+        A: Message + Name,
+    {
+        Self {
+            authorization: Some(Any {
+                type_url: A::type_url(),
+                value: authorization.encode_to_vec(),
+            }),
+            expiration,
+        }
+    }
+}
+
+impl MsgExec {
+    /// Creates a `MsgExec` executing the given, already packed, messages on
+    /// behalf of their signers.
+    pub fn new(grantee: impl Into<String>, msgs: impl IntoIterator<Item = Any>) -> Self {
+        Self {
+            grantee: grantee.into(),
+            msgs: msgs.into_iter().collect(),
+        }
+    }
+
+    /// Packs the given domain message into a `google.protobuf.Any` and appends it
+    /// to the messages to execute.
+    pub fn push<T, Raw>(&mut self, msg: &T)
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.msgs.push(msg.to_any());
+    }
+
+    /// Same as [`MsgExec::push`], but consumes and returns `self`.
+    pub fn with_msg<T, Raw>(mut self, msg: &T) -> Self
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.push(msg);
+        self
+    }
+
+    /// Returns the type URLs of the messages to execute.
+    pub fn msg_type_urls(&self) -> Vec<&str> {
+        self.msgs.iter().map(|msg| msg.type_url.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+    use crate::coin::CoinError;
+    use crate::cosmos::base::v1beta1::Coin;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Amount(Coin);
+
+    impl Protobuf<Coin> for Amount {}
+
+    impl TryFrom<Coin> for Amount {
+        type Error = CoinError;
+
+        fn try_from(coin: Coin) -> Result<Self, Self::Error> {
+            coin.validate_amount()?;
+            Ok(Self(coin))
+        }
+    }
+
+    impl From<Amount> for Coin {
+        fn from(amount: Amount) -> Self {
+            amount.0
+        }
+    }
+
+    #[test]
+    fn test_generic_authorization() {
+        let authorization = GenericAuthorization::for_msg::<Coin>();
+        assert_eq!(authorization.msg, "/cosmos.base.v1beta1.Coin");
+        assert_eq!(authorization, GenericAuthorization::new(Coin::type_url()));
+    }
+
+    #[test]
+    fn test_grant() {
+        let authorization = GenericAuthorization::for_msg::<Coin>();
+        let expiration = Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 0,
+        };
+        let grant = Grant::new(&authorization, Some(expiration.clone()));
+
+        let any = grant.authorization.unwrap();
+        assert_eq!(any.type_url, GenericAuthorization::type_url());
+        assert_eq!(
+            GenericAuthorization::decode(&*any.value).unwrap(),
+            authorization
+        );
+        assert_eq!(grant.expiration, Some(expiration));
+
+        assert_eq!(Grant::new(&authorization, None).expiration, None);
+    }
+
+    #[test]
+    fn test_msg_exec() {
+        let packed = Amount(Coin::new(1, "uatom")).to_any();
+        let mut msg = MsgExec::new("grantee", [packed.clone()]);
+        msg.push(&Amount(Coin::new(2, "stake")));
+        let msg = msg.with_msg(&Amount(Coin::new(3, "uosmo")));
+
+        assert_eq!(msg.grantee, "grantee");
+        assert_eq!(msg.msgs[0], packed);
+        assert_eq!(msg.msg_type_urls(), vec![Coin::type_url(); 3]);
+        assert_eq!(
+            Amount::from_any(&msg.msgs[1]).unwrap(),
+            Amount(Coin::new(2, "stake"))
+        );
+        assert_eq!(
+            Amount::from_any(&msg.msgs[2]).unwrap(),
+            Amount(Coin::new(3, "uosmo"))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Expansion of the `ics23.CompressedBatchProof`, whose existence proofs give the inner
+//! nodes of their path as indices into the `lookup_inners` table shared by all the
+//! entries, rather than repeating the nodes which are common to several paths.
+//!
+//! The compressed proofs are expanded into the equivalent `ics23.BatchProof`, either
+//! whole with [`decompress_batch`], or one entry at a time with [`find_compressed`],
+//! and the entries of a batch proof are looked up by key with [`find`], so as to verify
+//! each of them as a single `ics23.CommitmentProof`.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use ics23::commitment_proof::Proof;
+use ics23::{
+    batch_entry, compressed_batch_entry, BatchEntry, BatchProof, CommitmentProof,
+    CompressedBatchEntry, CompressedBatchProof, CompressedExistenceProof,
+    CompressedNonExistenceProof, ExistenceProof, InnerOp, NonExistenceProof,
+};
+
+/// Expands a compressed commitment proof into a batch proof, leaving the other kinds of
+/// proofs as they are.
+pub fn decompress(proof: &CommitmentProof) -> Result<CommitmentProof, DecompressError> {
+    match &proof.proof {
+        Some(Proof::Compressed(compressed)) => Ok(CommitmentProof {
+            proof: Some(Proof::Batch(decompress_batch(compressed)?)),
+        }),
+        _ => Ok(proof.clone()),
+    }
+}
+
+/// Expands all the entries of a compressed batch proof, in order.
+pub fn decompress_batch(proof: &CompressedBatchProof) -> Result<BatchProof, DecompressError> {
+    let entries = proof
+        .entries
+        .iter()
+        .map(|entry| decompress_entry(entry, &proof.lookup_inners))
+        .collect::<Result<_, _>>()?;
+
+    Ok(BatchProof { entries })
+}
+
+/// Expands an entry of a compressed batch proof, given the lookup table of the proof.
+pub fn decompress_entry(
+    entry: &CompressedBatchEntry,
+    lookup: &[InnerOp],
+) -> Result<BatchEntry, DecompressError> {
+    let proof = match &entry.proof {
+        Some(compressed_batch_entry::Proof::Exist(existence)) => {
+            batch_entry::Proof::Exist(decompress_existence(existence, lookup)?)
+        }
+        Some(compressed_batch_entry::Proof::Nonexist(non_existence)) => {
+            batch_entry::Proof::Nonexist(decompress_non_existence(non_existence, lookup)?)
+        }
+        None => return Ok(BatchEntry { proof: None }),
+    };
+
+    Ok(BatchEntry { proof: Some(proof) })
+}
+
+/// Expands a compressed existence proof, resolving the inner nodes of its path in the
+/// lookup table.
+pub fn decompress_existence(
+    proof: &CompressedExistenceProof,
+    lookup: &[InnerOp],
+) -> Result<ExistenceProof, DecompressError> {
+    let path = proof
+        .path
+        .iter()
+        .map(|&index| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|i| lookup.get(i))
+                .cloned()
+                .ok_or(DecompressError::InvalidLookupIndex {
+                    index,
+                    len: lookup.len(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ExistenceProof {
+        key: proof.key.clone(),
+        value: proof.value.clone(),
+        leaf: proof.leaf.clone(),
+        path,
+    })
+}
+
+fn decompress_non_existence(
+    proof: &CompressedNonExistenceProof,
+    lookup: &[InnerOp],
+) -> Result<NonExistenceProof, DecompressError> {
+    let decompress = |existence: &Option<CompressedExistenceProof>| {
+        existence
+            .as_ref()
+            .map(|existence| decompress_existence(existence, lookup))
+            .transpose()
+    };
+
+    Ok(NonExistenceProof {
+        key: proof.key.clone(),
+        left: decompress(&proof.left)?,
+        right: decompress(&proof.right)?,
+    })
+}
+
+/// Returns the entry of a batch proof proving the existence, or the non-existence, of
+/// the given key.
+pub fn find<'a>(proof: &'a BatchProof, key: &[u8]) -> Option<&'a BatchEntry> {
+    proof.entries.iter().find(|entry| match &entry.proof {
+        Some(batch_entry::Proof::Exist(existence)) => existence.key == key,
+        Some(batch_entry::Proof::Nonexist(non_existence)) => non_existence.key == key,
+        None => false,
+    })
+}
+
+/// Returns the entry of a compressed batch proof proving the existence, or the
+/// non-existence, of the given key, expanding only this entry.
+pub fn find_compressed(
+    proof: &CompressedBatchProof,
+    key: &[u8],
+) -> Result<Option<BatchEntry>, DecompressError> {
+    proof
+        .entries
+        .iter()
+        .find(|entry| match &entry.proof {
+            Some(compressed_batch_entry::Proof::Exist(existence)) => existence.key == key,
+            Some(compressed_batch_entry::Proof::Nonexist(non_existence)) => {
+                non_existence.key == key
+            }
+            None => false,
+        })
+        .map(|entry| decompress_entry(entry, &proof.lookup_inners))
+        .transpose()
+}
+
+/// Wraps an entry of a batch proof into a commitment proof of its own, eg. to verify it
+/// with `ics23::verify_membership`.
+pub fn entry_to_commitment_proof(entry: &BatchEntry) -> Option<CommitmentProof> {
+    let proof = match entry.proof.as_ref()? {
+        batch_entry::Proof::Exist(existence) => Proof::Exist(existence.clone()),
+        batch_entry::Proof::Nonexist(non_existence) => Proof::Nonexist(non_existence.clone()),
+    };
+
+    Some(CommitmentProof { proof: Some(proof) })
+}
+
+/// The errors of the expansion of a compressed batch proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecompressError {
+    /// An inner node of a path is not within the lookup table of the proof.
+    InvalidLookupIndex { index: i32, len: usize },
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecompressError::InvalidLookupIndex { index, len } => write!(
+                f,
+                "inner node index {index} is out of the lookup table of {len} nodes"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecompressError {}
@@ -0,0 +1,180 @@
+//! A `tonic` codec encoding the messages with the proto3 JSON mapping, in place of the
+//! Protobuf encoding of the generated clients and servers, eg. to call the services of
+//! a proxy only transcoding JSON payloads.
+//!
+//! The codec is given to the `tonic::client::Grpc` or `tonic::server::Grpc` driving the
+//! calls, along with the path of the method, eg. for a query of a client state:
+//!
+//! ```ignore
+//! let mut grpc = tonic::client::Grpc::new(channel);
+//! grpc.ready().await?;
+//! let path = PathAndQuery::from_static("/ibc.core.client.v1.Query/ClientState");
+//! let response: Response<QueryClientStateResponse> =
+//!     grpc.unary(request.into_request(), path, JsonCodec::default()).await?;
+//! ```
+//!
+//! The generated clients and servers use the codec when the code is regenerated with the
+//! `compile-protos` feature and `IBC_PROTO_CODEC=crate::codec::JsonCodec`, see `build.rs`.
+
+use core::marker::PhantomData;
+
+use bytes::{Buf, BufMut};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::Status;
+
+/// A codec encoding messages of type `T` and decoding messages of type `U` as JSON.
+#[derive(Debug)]
+pub struct JsonCodec<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> Default for JsonCodec<T, U> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T, U> Codec for JsonCodec<T, U>
+where
+    T: Serialize + Send + 'static,
+    U: DeserializeOwned + Send + 'static,
+{
+    type Encode = T;
+    type Decode = U;
+    type Encoder = JsonEncoder<T>;
+    type Decoder = JsonDecoder<U>;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        JsonEncoder(PhantomData)
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        JsonDecoder(PhantomData)
+    }
+}
+
+/// Encodes the messages of type `T` as JSON.
+#[derive(Debug)]
+pub struct JsonEncoder<T>(PhantomData<T>);
+
+impl<T: Serialize> Encoder for JsonEncoder<T> {
+    type Item = T;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        serde_json::to_writer(dst.writer(), &item)
+            .map_err(|e| Status::internal(format!("failed to encode message as JSON: {e}")))
+    }
+}
+
+/// Decodes the messages of type `U` from JSON.
+#[derive(Debug)]
+pub struct JsonDecoder<U>(PhantomData<U>);
+
+impl<U: DeserializeOwned> Decoder for JsonDecoder<U> {
+    type Item = U;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        serde_json::from_reader(src.reader())
+            .map(Some)
+            .map_err(|e| Status::internal(format!("failed to decode message from JSON: {e}")))
+    }
+}
+
+#[cfg(all(test, feature = "server"))]
+mod test {
+    use core::future::{ready, Future, Ready};
+    use core::pin::{pin, Pin};
+    use core::task::{Context, Poll, Waker};
+
+    use tonic::codegen::{http, Body, Bytes};
+    use tonic::server::{Grpc, UnaryService};
+    use tonic::{Request, Response};
+
+    use super::*;
+    use crate::cosmos::base::v1beta1::Coin;
+
+    /// The body of a request, made of a single frame.
+    struct Frame(Option<Bytes>);
+
+    impl Body for Frame {
+        type Data = Bytes;
+        type Error = Status;
+
+        fn poll_data(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+            Poll::Ready(self.get_mut().0.take().map(Ok))
+        }
+
+        fn poll_trailers(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+            Poll::Ready(Ok(None))
+        }
+    }
+
+    /// Doubles the amount of the coin it is given.
+    struct Double;
+
+    impl UnaryService<Coin> for Double {
+        type Response = Coin;
+        type Future = Ready<Result<Response<Coin>, Status>>;
+
+        fn call(&mut self, request: Request<Coin>) -> Self::Future {
+            let mut coin = request.into_inner();
+            coin.amount = (coin.amount.parse::<u64>().unwrap() * 2).to_string();
+            ready(Ok(Response::new(coin)))
+        }
+    }
+
+    /// Polls a future which is expected to be ready.
+    fn now<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future is not ready"),
+        }
+    }
+
+    fn frame(payload: &[u8]) -> Bytes {
+        let mut frame = vec![0];
+        frame.extend((payload.len() as u32).to_be_bytes());
+        frame.extend(payload);
+        frame.into()
+    }
+
+    #[test]
+    fn test_json_codec_roundtrip() {
+        let request = http::Request::builder()
+            .header("content-type", "application/grpc")
+            .body(Frame(Some(frame(br#"{"denom":"uatom","amount":"21"}"#))))
+            .unwrap();
+
+        let mut grpc = Grpc::new(JsonCodec::<Coin, Coin>::default());
+        let response = now(grpc.unary(Double, request));
+        assert_eq!(response.status(), http::StatusCode::OK);
+
+        let mut body = response.into_body();
+        let data = now(body.data()).unwrap().unwrap();
+        assert_eq!(data, frame(br#"{"denom":"uatom","amount":"42"}"#));
+    }
+
+    #[test]
+    fn test_json_codec_invalid_request() {
+        let request = http::Request::builder()
+            .header("content-type", "application/grpc")
+            .body(Frame(Some(frame(b"not json"))))
+            .unwrap();
+
+        let mut grpc = Grpc::new(JsonCodec::<Coin, Coin>::default());
+        let response = now(grpc.unary(Double, request));
+        let status = Status::from_header_map(response.headers()).unwrap();
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert!(status
+            .message()
+            .starts_with("failed to decode message from JSON"));
+    }
+}
@@ -0,0 +1,427 @@
+//! Helpers to parse, format and add up the `cosmos.base.v1beta1.Coin` of the fees and
+//! the transferred amounts.
+//!
+//! The amounts are the decimal strings of the `Int` of the Cosmos SDK, which is why the
+//! arithmetic is carried out on the decimal digits, without any bound on the amounts.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::cosmos::base::v1beta1::Coin;
+
+impl Coin {
+    /// Creates a coin of the given amount and denomination.
+    pub fn new(amount: impl ToString, denom: impl Into<String>) -> Self {
+        Self {
+            denom: denom.into(),
+            amount: amount.to_string(),
+        }
+    }
+
+    /// Checks that the amount of the coin is a non-negative decimal integer, as
+    /// accepted by the Cosmos SDK.
+    pub fn validate_amount(&self) -> Result<(), CoinError> {
+        validate_amount(&self.amount)
+    }
+
+    /// Adds two coins of the same denomination.
+    pub fn checked_add(&self, other: &Coin) -> Result<Coin, CoinError> {
+        self.check_denom(other)?;
+        validate_amount(&self.amount)?;
+        validate_amount(&other.amount)?;
+
+        Ok(Coin::new(
+            add(&self.amount, &other.amount),
+            self.denom.clone(),
+        ))
+    }
+
+    /// Subtracts a coin of the same denomination from this one, which must hold at
+    /// least its amount.
+    pub fn checked_sub(&self, other: &Coin) -> Result<Coin, CoinError> {
+        self.check_denom(other)?;
+        validate_amount(&self.amount)?;
+        validate_amount(&other.amount)?;
+
+        let amount =
+            sub(&self.amount, &other.amount).ok_or_else(|| CoinError::InsufficientAmount {
+                denom: self.denom.clone(),
+            })?;
+
+        Ok(Coin::new(amount, self.denom.clone()))
+    }
+
+    fn check_denom(&self, other: &Coin) -> Result<(), CoinError> {
+        if self.denom == other.denom {
+            Ok(())
+        } else {
+            Err(CoinError::DenomMismatch {
+                left: self.denom.clone(),
+                right: other.denom.clone(),
+            })
+        }
+    }
+}
+
+/// Formats the coin as its amount followed by its denomination, eg. `1000uatom`.
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+/// Parses a coin from its amount followed by its denomination, eg. `1000uatom` or
+/// `1000ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2`.
+impl FromStr for Coin {
+    type Err = CoinError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| CoinError::InvalidCoin(s.to_string()))?;
+        let (amount, denom) = s.split_at(split);
+
+        if amount.is_empty() {
+            return Err(CoinError::InvalidCoin(s.to_string()));
+        }
+        validate_denom(denom.trim_start())?;
+
+        Ok(Coin::new(amount, denom.trim_start()))
+    }
+}
+
+/// Adds up two lists of coins, by denomination.
+///
+/// The coins of the result are sorted by denomination, as required by the Cosmos SDK,
+/// and those of a zero amount are left out.
+pub fn checked_add_coins(lhs: &[Coin], rhs: &[Coin]) -> Result<Vec<Coin>, CoinError> {
+    let mut sums = amounts(lhs)?;
+    for coin in rhs {
+        validate_amount(&coin.amount)?;
+        let sum = match sums.get(coin.denom.as_str()) {
+            Some(amount) => add(amount, &coin.amount),
+            None => coin.amount.clone(),
+        };
+        sums.insert(&coin.denom, sum);
+    }
+
+    Ok(into_coins(sums))
+}
+
+/// Subtracts a list of coins from another one, by denomination, failing if the latter
+/// does not hold enough of one of the denominations.
+///
+/// The coins of the result are sorted by denomination, as required by the Cosmos SDK,
+/// and those of a zero amount are left out.
+pub fn checked_sub_coins(lhs: &[Coin], rhs: &[Coin]) -> Result<Vec<Coin>, CoinError> {
+    let mut differences = amounts(lhs)?;
+    for coin in rhs {
+        validate_amount(&coin.amount)?;
+        let amount = differences
+            .get(coin.denom.as_str())
+            .map_or("0", String::as_str);
+        let difference =
+            sub(amount, &coin.amount).ok_or_else(|| CoinError::InsufficientAmount {
+                denom: coin.denom.clone(),
+            })?;
+        differences.insert(&coin.denom, difference);
+    }
+
+    Ok(into_coins(differences))
+}
+
+fn amounts(coins: &[Coin]) -> Result<BTreeMap<&str, String>, CoinError> {
+    let mut amounts = BTreeMap::<&str, String>::new();
+    for coin in coins {
+        validate_amount(&coin.amount)?;
+        let amount = match amounts.get(coin.denom.as_str()) {
+            Some(amount) => add(amount, &coin.amount),
+            None => coin.amount.clone(),
+        };
+        amounts.insert(coin.denom.as_str(), amount);
+    }
+
+    Ok(amounts)
+}
+
+fn into_coins(amounts: BTreeMap<&str, String>) -> Vec<Coin> {
+    amounts
+        .into_iter()
+        .filter(|(_, amount)| !is_zero(amount))
+        .map(|(denom, amount)| Coin::new(trim_zeros(&amount), denom))
+        .collect()
+}
+
+/// Checks that the given amount is a non-negative decimal integer.
+pub fn validate_amount(amount: &str) -> Result<(), CoinError> {
+    if !amount.is_empty() && amount.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(CoinError::InvalidAmount(amount.to_string()))
+    }
+}
+
+/// Checks the denomination against the format of the Cosmos SDK, ie. a letter followed
+/// by 2 to 127 letters, digits or one of `/:._-`.
+fn validate_denom(denom: &str) -> Result<(), CoinError> {
+    let mut chars = denom.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && (3..=128).contains(&denom.len())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CoinError::InvalidDenom(denom.to_string()))
+    }
+}
+
+fn trim_zeros(amount: &str) -> &str {
+    let trimmed = amount.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0"
+    } else {
+        trimmed
+    }
+}
+
+fn is_zero(amount: &str) -> bool {
+    amount.bytes().all(|b| b == b'0')
+}
+
+fn compare(lhs: &str, rhs: &str) -> Ordering {
+    let (lhs, rhs) = (trim_zeros(lhs), trim_zeros(rhs));
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+}
+
+/// Returns the `i`-th decimal digit of an amount, starting from the units.
+fn digit(amount: &[u8], i: usize) -> u8 {
+    amount
+        .len()
+        .checked_sub(i + 1)
+        .map_or(0, |i| amount[i] - b'0')
+}
+
+/// Adds two amounts, given as decimal digits, without leading zeros in the sum.
+fn add(lhs: &str, rhs: &str) -> String {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    let mut digits = Vec::with_capacity(lhs.len().max(rhs.len()) + 1);
+    let mut carry = 0;
+    for i in 0..lhs.len().max(rhs.len()) {
+        let sum = digit(lhs, i) + digit(rhs, i) + carry;
+        digits.push(b'0' + sum % 10);
+        carry = sum / 10;
+    }
+    if carry > 0 {
+        digits.push(b'0' + carry);
+    }
+
+    let sum = digits
+        .iter()
+        .rev()
+        .map(|&d| char::from(d))
+        .collect::<String>();
+    trim_zeros(&sum).to_string()
+}
+
+/// Subtracts two amounts, given as decimal digits, or returns `None` if `rhs` is
+/// greater than `lhs`.
+fn sub(lhs: &str, rhs: &str) -> Option<String> {
+    if compare(lhs, rhs) == Ordering::Less {
+        return None;
+    }
+
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    let mut digits = Vec::with_capacity(lhs.len());
+    let mut borrow = 0;
+    for i in 0..lhs.len() {
+        let (lhs, rhs) = (digit(lhs, i), digit(rhs, i) + borrow);
+        if lhs >= rhs {
+            digits.push(b'0' + lhs - rhs);
+            borrow = 0;
+        } else {
+            digits.push(b'0' + lhs + 10 - rhs);
+            borrow = 1;
+        }
+    }
+
+    let difference = digits
+        .iter()
+        .rev()
+        .map(|&d| char::from(d))
+        .collect::<String>();
+    Some(trim_zeros(&difference).to_string())
+}
+
+/// The errors of the parsing and the arithmetic of coins.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CoinError {
+    /// The string is not an amount followed by a denomination.
+    InvalidCoin(String),
+    /// The amount is not a non-negative decimal integer.
+    InvalidAmount(String),
+    /// The denomination does not follow the format of the Cosmos SDK.
+    InvalidDenom(String),
+    /// The coins to add or subtract are not of the same denomination.
+    DenomMismatch { left: String, right: String },
+    /// The result of a subtraction would be negative.
+    InsufficientAmount { denom: String },
+}
+
+impl fmt::Display for CoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoinError::InvalidCoin(coin) => {
+                write!(f, "`{coin}` is not an amount followed by a denomination")
+            }
+            CoinError::InvalidAmount(amount) => {
+                write!(f, "`{amount}` is not a non-negative decimal integer")
+            }
+            CoinError::InvalidDenom(denom) => write!(f, "`{denom}` is not a valid denomination"),
+            CoinError::DenomMismatch { left, right } => {
+                write!(
+                    f,
+                    "cannot combine coins of denominations `{left}` and `{right}`"
+                )
+            }
+            CoinError::InsufficientAmount { denom } => {
+                write!(f, "insufficient amount of `{denom}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoinError {}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_coin() {
+        let coin = "1000uatom".parse::<Coin>().unwrap();
+        assert_eq!(coin, Coin::new(1000, "uatom"));
+        assert_eq!(coin.to_string(), "1000uatom");
+
+        let denom = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+        let coin = format!("42 {denom}").parse::<Coin>().unwrap();
+        assert_eq!(coin, Coin::new(42, denom));
+
+        assert_eq!(
+            "uatom".parse::<Coin>(),
+            Err(CoinError::InvalidCoin("uatom".to_string()))
+        );
+        assert_eq!(
+            "1000".parse::<Coin>(),
+            Err(CoinError::InvalidCoin("1000".to_string()))
+        );
+        assert!("-1uatom".parse::<Coin>().is_err());
+    }
+
+    #[test]
+    fn test_validate_denom() {
+        for denom in [
+            "uatom",
+            "ibc/ABC",
+            "gamm/pool/1",
+            "a:b.c_d-e",
+            &"a".repeat(128),
+        ] {
+            assert_eq!(validate_denom(denom), Ok(()), "{denom}");
+        }
+
+        for denom in [
+            "",
+            "ab",
+            "1atom",
+            "/atom",
+            "u atom",
+            "uatom!",
+            &"a".repeat(129),
+        ] {
+            assert_eq!(
+                validate_denom(denom),
+                Err(CoinError::InvalidDenom(denom.to_string())),
+                "{denom}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let sum = Coin::new(999, "uatom").checked_add(&Coin::new(1, "uatom"));
+        assert_eq!(sum, Ok(Coin::new(1000, "uatom")));
+
+        let sum = Coin::new("0100", "uatom").checked_add(&Coin::new(5, "uatom"));
+        assert_eq!(sum, Ok(Coin::new(105, "uatom")));
+
+        let sum = Coin::new(u128::MAX, "uatom").checked_add(&Coin::new(1, "uatom"));
+        assert_eq!(
+            sum,
+            Ok(Coin::new(
+                "340282366920938463463374607431768211456",
+                "uatom"
+            ))
+        );
+
+        assert_eq!(
+            Coin::new(1, "uatom").checked_add(&Coin::new(1, "uosmo")),
+            Err(CoinError::DenomMismatch {
+                left: "uatom".to_string(),
+                right: "uosmo".to_string(),
+            })
+        );
+        assert_eq!(
+            Coin::new("1.5", "uatom").checked_add(&Coin::new(1, "uatom")),
+            Err(CoinError::InvalidAmount("1.5".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let difference = Coin::new(1000, "uatom").checked_sub(&Coin::new(1, "uatom"));
+        assert_eq!(difference, Ok(Coin::new(999, "uatom")));
+
+        let difference = Coin::new("0100", "uatom").checked_sub(&Coin::new(100, "uatom"));
+        assert_eq!(difference, Ok(Coin::new(0, "uatom")));
+
+        assert_eq!(
+            Coin::new(1, "uatom").checked_sub(&Coin::new(2, "uatom")),
+            Err(CoinError::InsufficientAmount {
+                denom: "uatom".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_add_and_sub_coins() {
+        let lhs = [
+            Coin::new(10, "uosmo"),
+            Coin::new(5, "uatom"),
+            Coin::new(1, "uatom"),
+        ];
+        let rhs = [Coin::new(4, "uatom"), Coin::new(0, "ujuno")];
+
+        let sum = checked_add_coins(&lhs, &rhs).unwrap();
+        assert_eq!(sum, [Coin::new(10, "uatom"), Coin::new(10, "uosmo")]);
+
+        let difference = checked_sub_coins(&sum, &[Coin::new(10, "uatom")]).unwrap();
+        assert_eq!(difference, [Coin::new(10, "uosmo")]);
+
+        assert_eq!(
+            checked_sub_coins(&sum, &[Coin::new(1, "ujuno")]),
+            Err(CoinError::InsufficientAmount {
+                denom: "ujuno".to_string(),
+            })
+        );
+    }
+}
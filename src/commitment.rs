@@ -0,0 +1,498 @@
+//! Helpers to build the `ibc.core.commitment.v1.MerklePath` of the values to prove, ie.
+//! the paths of ICS-24 under the `MerklePrefix` of the IBC store of a chain.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ibc::core::client::v1::Height;
+use crate::ibc::core::commitment::v1::{MerklePath, MerklePrefix};
+
+impl MerklePrefix {
+    /// Creates the prefix of the keys of a store, eg. `ibc` for the IBC store.
+    pub fn new(key_prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key_prefix: key_prefix.into(),
+        }
+    }
+}
+
+impl MerklePath {
+    /// Creates a path from its keys, from the root down to the value.
+    pub fn new(key_path: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            key_path: key_path.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Prefixes the path of a value within a store, with the prefix of the store, as
+    /// `ApplyPrefix` in ibc-go. The prefix is read as UTF-8, replacing any invalid
+    /// sequence with `U+FFFD`.
+    pub fn apply_prefix(prefix: &MerklePrefix, path: impl Into<String>) -> Self {
+        Self {
+            key_path: vec![
+                String::from_utf8_lossy(&prefix.key_prefix).into_owned(),
+                path.into(),
+            ],
+        }
+    }
+
+    /// The path of the state of a client, ie. `clients/{client_id}/clientState`.
+    pub fn client_state(prefix: &MerklePrefix, client_id: &str) -> Self {
+        Self::apply_prefix(prefix, format!("clients/{client_id}/clientState"))
+    }
+
+    /// The path of the consensus state of a client at the given height, ie.
+    /// `clients/{client_id}/consensusStates/{height}`.
+    pub fn consensus_state(prefix: &MerklePrefix, client_id: &str, height: &Height) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("clients/{client_id}/consensusStates/{height}"),
+        )
+    }
+
+    /// The path of a connection end, ie. `connections/{connection_id}`.
+    pub fn connection(prefix: &MerklePrefix, connection_id: &str) -> Self {
+        Self::apply_prefix(prefix, format!("connections/{connection_id}"))
+    }
+
+    /// The path of a channel end, ie. `channelEnds/ports/{port_id}/channels/{channel_id}`.
+    pub fn channel(prefix: &MerklePrefix, port_id: &str, channel_id: &str) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("channelEnds/ports/{port_id}/channels/{channel_id}"),
+        )
+    }
+
+    /// The path of the next sequence to receive on an ordered channel, ie.
+    /// `nextSequenceRecv/ports/{port_id}/channels/{channel_id}`.
+    pub fn next_sequence_recv(prefix: &MerklePrefix, port_id: &str, channel_id: &str) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("nextSequenceRecv/ports/{port_id}/channels/{channel_id}"),
+        )
+    }
+
+    /// The path of the commitment of a sent packet, ie.
+    /// `commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}`.
+    pub fn packet_commitment(
+        prefix: &MerklePrefix,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}"),
+        )
+    }
+
+    /// The path of the acknowledgement of a received packet, ie.
+    /// `acks/ports/{port_id}/channels/{channel_id}/sequences/{sequence}`.
+    pub fn packet_acknowledgement(
+        prefix: &MerklePrefix,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("acks/ports/{port_id}/channels/{channel_id}/sequences/{sequence}"),
+        )
+    }
+
+    /// The path of the receipt of a packet received on an unordered channel, ie.
+    /// `receipts/ports/{port_id}/channels/{channel_id}/sequences/{sequence}`.
+    pub fn packet_receipt(
+        prefix: &MerklePrefix,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Self {
+        Self::apply_prefix(
+            prefix,
+            format!("receipts/ports/{port_id}/channels/{channel_id}/sequences/{sequence}"),
+        )
+    }
+}
+
+#[cfg(feature = "proof-verification")]
+pub use self::verification::{sdk_specs, VerificationError};
+
+#[cfg(feature = "proof-verification")]
+mod verification {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    use ics23::commitment_proof::Proof;
+    use ics23::{
+        calculate_existence_root, verify_membership, verify_non_membership, ExistenceProof,
+        HostFunctionsManager, ProofSpec,
+    };
+
+    use crate::ibc::core::commitment::v1::{MerklePath, MerkleProof, MerkleRoot};
+
+    /// The proof specs of the stores of the Cosmos SDK chains, ie. of the IAVL tree of
+    /// the IBC store, then of the Tendermint simple Merkle tree of the store roots, as
+    /// `GetSDKSpecs` in ibc-go.
+    pub fn sdk_specs() -> Vec<ProofSpec> {
+        vec![ics23::iavl_spec(), ics23::tendermint_spec()]
+    }
+
+    impl MerkleProof {
+        /// Verifies that the given value is stored at `path` under the given root,
+        /// with a proof per key of the path, from the innermost store up to the
+        /// root, as `VerifyMembership` in ibc-go.
+        ///
+        /// The path must include the prefix of the store, see
+        /// [`MerklePath::apply_prefix`], and come with a proof spec per key, eg.
+        /// [`sdk_specs`].
+        pub fn verify_membership(
+            &self,
+            specs: &[ProofSpec],
+            root: &MerkleRoot,
+            path: &MerklePath,
+            value: &[u8],
+        ) -> Result<(), VerificationError> {
+            self.check_lengths(specs, path)?;
+            self.verify_chained_membership(specs, root, path, value.to_vec(), 0)
+        }
+
+        /// Verifies that no value is stored at `path` under the given root, with the
+        /// non-existence proof of the last key in the innermost store, followed by the
+        /// existence proofs of the roots of the stores, as `VerifyNonMembership` in
+        /// ibc-go.
+        pub fn verify_non_membership(
+            &self,
+            specs: &[ProofSpec],
+            root: &MerkleRoot,
+            path: &MerklePath,
+        ) -> Result<(), VerificationError> {
+            self.check_lengths(specs, path)?;
+
+            let proof = &self.proofs[0];
+            let subroot = match &proof.proof {
+                Some(Proof::Nonexist(non_existence)) => non_existence
+                    .left
+                    .as_ref()
+                    .or(non_existence.right.as_ref())
+                    .ok_or(VerificationError::InvalidProof { index: 0 })
+                    .and_then(|existence| calculate_root(existence, 0))?,
+                _ => return Err(VerificationError::UnexpectedProof { index: 0 }),
+            };
+
+            let key = path.key_path[path.key_path.len() - 1].as_bytes();
+            if !verify_non_membership::<HostFunctionsManager>(proof, &specs[0], &subroot, key) {
+                return Err(VerificationError::InvalidProof { index: 0 });
+            }
+
+            self.verify_chained_membership(specs, root, path, subroot, 1)
+        }
+
+        fn check_lengths(
+            &self,
+            specs: &[ProofSpec],
+            path: &MerklePath,
+        ) -> Result<(), VerificationError> {
+            let proofs = self.proofs.len();
+            if proofs == 0 || proofs != specs.len() || proofs != path.key_path.len() {
+                return Err(VerificationError::LengthMismatch {
+                    proofs,
+                    specs: specs.len(),
+                    keys: path.key_path.len(),
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Verifies the existence proofs from the `start`-th one, each proving the
+        /// root computed from the previous one, up to the given root.
+        fn verify_chained_membership(
+            &self,
+            specs: &[ProofSpec],
+            root: &MerkleRoot,
+            path: &MerklePath,
+            mut value: Vec<u8>,
+            start: usize,
+        ) -> Result<(), VerificationError> {
+            let mut subroot = value.clone();
+            for (index, (proof, spec)) in self.proofs.iter().zip(specs).enumerate().skip(start) {
+                subroot = match &proof.proof {
+                    Some(Proof::Exist(existence)) => calculate_root(existence, index)?,
+                    _ => return Err(VerificationError::UnexpectedProof { index }),
+                };
+
+                // The keys are given from the root down, and the proofs the other way.
+                let key = path.key_path[path.key_path.len() - 1 - index].as_bytes();
+                if !verify_membership::<HostFunctionsManager>(proof, spec, &subroot, key, &value) {
+                    return Err(VerificationError::InvalidProof { index });
+                }
+
+                value = subroot.clone();
+            }
+
+            if subroot == root.hash {
+                Ok(())
+            } else {
+                Err(VerificationError::RootMismatch)
+            }
+        }
+    }
+
+    fn calculate_root(
+        existence: &ExistenceProof,
+        index: usize,
+    ) -> Result<Vec<u8>, VerificationError> {
+        calculate_existence_root::<HostFunctionsManager>(existence)
+            .map_err(|_| VerificationError::InvalidProof { index })
+    }
+
+    /// The errors of the verification of a [`MerkleProof`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum VerificationError {
+        /// The numbers of proofs, proof specs and keys of the path differ, or are zero.
+        LengthMismatch {
+            proofs: usize,
+            specs: usize,
+            keys: usize,
+        },
+        /// The proof at the given index is not of the expected kind, eg. a batch proof.
+        UnexpectedProof { index: usize },
+        /// The proof at the given index does not verify against its spec.
+        InvalidProof { index: usize },
+        /// The proofs do not verify up to the expected root.
+        RootMismatch,
+    }
+
+    impl fmt::Display for VerificationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                VerificationError::LengthMismatch {
+                    proofs,
+                    specs,
+                    keys,
+                } => write!(
+                    f,
+                    "expected as many proofs as proof specs and keys, got {proofs} proofs, \
+                     {specs} specs and {keys} keys"
+                ),
+                VerificationError::UnexpectedProof { index } => {
+                    write!(f, "unexpected kind of proof at index {index}")
+                }
+                VerificationError::InvalidProof { index } => {
+                    write!(f, "invalid proof at index {index}")
+                }
+                VerificationError::RootMismatch => {
+                    write!(f, "the proofs do not verify up to the expected root")
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for VerificationError {}
+}
+
+/// The bounds and operations of the `MerkleProof`s generated by both the `arbitrary`
+/// and the `proptest` features.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub(crate) mod generate {
+    use ics23::{HashOp, LengthOp};
+
+    /// The maximum number of commitment proofs in a generated Merkle proof,
+    /// eg. 2 for a proof against the IAVL store of a module and the multistore root.
+    pub(crate) const MAX_PROOFS_LEN: usize = 4;
+
+    /// The maximum number of inner nodes in a generated existence proof.
+    pub(crate) const MAX_PATH_LEN: usize = 8;
+
+    /// The hash operations of the generated leaf and inner nodes.
+    pub(crate) const HASH_OPS: [i32; 3] = [
+        HashOp::NoHash as i32,
+        HashOp::Sha256 as i32,
+        HashOp::Sha512 as i32,
+    ];
+
+    /// The length operations of the generated leaf nodes.
+    pub(crate) const LENGTH_OPS: [i32; 2] = [LengthOp::NoPrefix as i32, LengthOp::VarProto as i32];
+}
+
+#[cfg(all(test, feature = "proof-verification"))]
+mod test {
+    use super::*;
+    use crate::ibc::core::commitment::v1::{MerkleProof, MerkleRoot};
+    use ics23::commitment_proof::Proof;
+    use ics23::{
+        CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+    };
+    use subtle_encoding::hex;
+
+    // An IAVL tree of the `ibc` store holding `a` and `connections/connection-0` at
+    // version 1, under a multistore holding only this store.
+    const KEY: &str = "connections/connection-0";
+    const VALUE: &[u8] = b"connection end";
+    const IAVL_ROOT: &str = "b10d7e630a460b6b31c41997781eb9dac68653ea4d406245b537e2bfd770761a";
+    const ROOT: &str = "ca461fb89accc6f02eece85ae099ad73bb1d2a1fe1ded4b1d00a208cc8d42d11";
+
+    fn leaf(prefix: &str) -> Option<LeafOp> {
+        Some(LeafOp {
+            hash: HashOp::Sha256 as i32,
+            prehash_key: HashOp::NoHash as i32,
+            prehash_value: HashOp::Sha256 as i32,
+            length: LengthOp::VarProto as i32,
+            prefix: hex::decode(prefix).unwrap(),
+        })
+    }
+
+    fn inner(prefix: &str, suffix: &str) -> InnerOp {
+        InnerOp {
+            hash: HashOp::Sha256 as i32,
+            prefix: hex::decode(prefix).unwrap(),
+            suffix: hex::decode(suffix).unwrap(),
+        }
+    }
+
+    fn existence_a() -> ExistenceProof {
+        ExistenceProof {
+            key: b"a".to_vec(),
+            value: b"1".to_vec(),
+            leaf: leaf("000202"),
+            path: vec![inner(
+                "02040220",
+                "2007e30690572bd4408c9cfebaa5358d945c5563b3f6b6c77fb8bba07be5886654",
+            )],
+        }
+    }
+
+    fn existence_key() -> ExistenceProof {
+        ExistenceProof {
+            key: KEY.as_bytes().to_vec(),
+            value: VALUE.to_vec(),
+            leaf: leaf("000202"),
+            path: vec![inner(
+                "02040220bbe33cd0a785b97b9fb1f964aa71159dacd9e0ade84df7403dc0f9dc2481840420",
+                "",
+            )],
+        }
+    }
+
+    fn store_proof() -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(Proof::Exist(ExistenceProof {
+                key: b"ibc".to_vec(),
+                value: hex::decode(IAVL_ROOT).unwrap(),
+                leaf: leaf("00"),
+                path: vec![],
+            })),
+        }
+    }
+
+    fn root() -> MerkleRoot {
+        MerkleRoot {
+            hash: hex::decode(ROOT).unwrap(),
+        }
+    }
+
+    fn prefix() -> MerklePrefix {
+        MerklePrefix::new("ibc")
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(existence_key())),
+                },
+                store_proof(),
+            ],
+        };
+        let path = MerklePath::apply_prefix(&prefix(), KEY);
+
+        assert_eq!(
+            proof.verify_membership(&sdk_specs(), &root(), &path, VALUE),
+            Ok(())
+        );
+        assert_eq!(
+            proof.verify_membership(&sdk_specs(), &root(), &path, b"other value"),
+            Err(VerificationError::InvalidProof { index: 0 })
+        );
+        assert_eq!(
+            proof.verify_membership(
+                &sdk_specs(),
+                &root(),
+                &MerklePath::apply_prefix(&prefix(), "a"),
+                VALUE
+            ),
+            Err(VerificationError::InvalidProof { index: 0 })
+        );
+        assert_eq!(
+            proof.verify_membership(
+                &sdk_specs(),
+                &MerkleRoot {
+                    hash: hex::decode(IAVL_ROOT).unwrap()
+                },
+                &path,
+                VALUE
+            ),
+            Err(VerificationError::RootMismatch)
+        );
+        assert_eq!(
+            proof.verify_membership(&sdk_specs()[..1], &root(), &path, VALUE),
+            Err(VerificationError::LengthMismatch {
+                proofs: 2,
+                specs: 1,
+                keys: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_verify_non_membership() {
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Nonexist(NonExistenceProof {
+                        key: b"b".to_vec(),
+                        left: Some(existence_a()),
+                        right: Some(existence_key()),
+                    })),
+                },
+                store_proof(),
+            ],
+        };
+
+        assert_eq!(
+            proof.verify_non_membership(
+                &sdk_specs(),
+                &root(),
+                &MerklePath::apply_prefix(&prefix(), "b")
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            proof.verify_non_membership(
+                &sdk_specs(),
+                &root(),
+                &MerklePath::apply_prefix(&prefix(), KEY)
+            ),
+            Err(VerificationError::InvalidProof { index: 0 })
+        );
+
+        let proof = MerkleProof {
+            proofs: vec![store_proof(), store_proof()],
+        };
+        assert_eq!(
+            proof.verify_non_membership(
+                &sdk_specs(),
+                &root(),
+                &MerklePath::apply_prefix(&prefix(), "b")
+            ),
+            Err(VerificationError::UnexpectedProof { index: 0 })
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Helpers to pack and unpack the public keys of the Cosmos SDK, found as a
+//! `google.protobuf.Any` in the accounts and the signer infos of the transactions.
+//!
+//! The keys are displayed as by the Cosmos SDK, ie. the name of their type followed by
+//! their bytes in upper-case hexadecimal, eg. `PubKeySecp256k1{02A1633C...}`.
+//!
+//! The signatures of a multisig key are assembled with [`MultiSignature::new`], along
+//! with the [`CompactBitArray`] of the keys which signed, packed as by the Cosmos SDK.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use prost::{DecodeError, Message};
+
+use crate::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use crate::cosmos::crypto::multisig::LegacyAminoPubKey;
+use crate::cosmos::crypto::{ed25519, secp256k1, secp256r1};
+use crate::google::protobuf::Any;
+use crate::protobuf::Name;
+
+/// A public key of any of the types supported by the Cosmos SDK.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PublicKey {
+    Secp256k1(secp256k1::PubKey),
+    Ed25519(ed25519::PubKey),
+    Secp256r1(secp256r1::PubKey),
+    Multisig(LegacyAminoPubKey),
+}
+
+impl PublicKey {
+    /// Unpacks a public key from a `google.protobuf.Any`, according to its type URL.
+    pub fn from_any(any: &Any) -> Result<Self, PublicKeyError> {
+        let value = any.value.as_slice();
+        if any.type_url == secp256k1::PubKey::type_url() {
+            decode(value).map(Self::Secp256k1)
+        } else if any.type_url == ed25519::PubKey::type_url() {
+            decode(value).map(Self::Ed25519)
+        } else if any.type_url == secp256r1::PubKey::type_url() {
+            decode(value).map(Self::Secp256r1)
+        } else if any.type_url == LegacyAminoPubKey::type_url() {
+            decode(value).map(Self::Multisig)
+        } else {
+            Err(PublicKeyError::UnknownTypeUrl(any.type_url.clone()))
+        }
+    }
+
+    /// Packs the public key into a `google.protobuf.Any`.
+    pub fn to_any(&self) -> Any {
+        match self {
+            Self::Secp256k1(key) => encode(key),
+            Self::Ed25519(key) => encode(key),
+            Self::Secp256r1(key) => encode(key),
+            Self::Multisig(key) => encode(key),
+        }
+    }
+
+    /// Returns the bytes of the key, or `None` for a multisig key, which is made of
+    /// other keys.
+    pub fn key_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Secp256k1(key) => Some(&key.key),
+            Self::Ed25519(key) => Some(&key.key),
+            Self::Secp256r1(key) => Some(&key.key),
+            Self::Multisig(_) => None,
+        }
+    }
+}
+
+fn decode<M: Message + Default>(value: &[u8]) -> Result<M, PublicKeyError> {
+    M::decode(value).map_err(PublicKeyError::Decode)
+}
+
+fn encode<M: Message + Name>(key: &M) -> Any {
+    Any {
+        type_url: M::type_url(),
+        value: key.encode_to_vec(),
+    }
+}
+
+impl TryFrom<&Any> for PublicKey {
+    type Error = PublicKeyError;
+
+    fn try_from(any: &Any) -> Result<Self, Self::Error> {
+        Self::from_any(any)
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(key: PublicKey) -> Self {
+        key.to_any()
+    }
+}
+
+impl From<secp256k1::PubKey> for PublicKey {
+    fn from(key: secp256k1::PubKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl From<ed25519::PubKey> for PublicKey {
+    fn from(key: ed25519::PubKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+impl From<secp256r1::PubKey> for PublicKey {
+    fn from(key: secp256r1::PubKey) -> Self {
+        Self::Secp256r1(key)
+    }
+}
+
+impl From<LegacyAminoPubKey> for PublicKey {
+    fn from(key: LegacyAminoPubKey) -> Self {
+        Self::Multisig(key)
+    }
+}
+
+impl LegacyAminoPubKey {
+    /// Creates a multisig key requiring the signatures of `threshold` of the given keys.
+    pub fn new(threshold: u32, keys: impl IntoIterator<Item = PublicKey>) -> Self {
+        Self {
+            threshold,
+            public_keys: keys.into_iter().map(|key| key.to_any()).collect(),
+        }
+    }
+
+    /// Unpacks the keys making up the multisig key.
+    pub fn keys(&self) -> Result<Vec<PublicKey>, PublicKeyError> {
+        self.public_keys.iter().map(PublicKey::from_any).collect()
+    }
+}
+
+impl CompactBitArray {
+    /// Creates an array of `bits` bits, all unset.
+    pub fn new(bits: usize) -> Self {
+        Self {
+            extra_bits_stored: (bits % 8) as u32,
+            elems: vec![0; bits.div_ceil(8)],
+        }
+    }
+
+    /// Returns the number of bits of the array.
+    ///
+    /// The length of a malformed array, eg. decoded from untrusted bytes with more
+    /// extra bits than held by its last byte, is capped to the bits it holds.
+    pub fn len(&self) -> usize {
+        let len = match self.extra_bits_stored {
+            0 => self.elems.len() * 8,
+            extra => (self.elems.len().saturating_sub(1)) * 8 + extra as usize,
+        };
+        len.min(self.elems.len() * 8)
+    }
+
+    /// Returns whether the array holds no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bit at the given index, or `false` if out of bounds.
+    pub fn get(&self, i: usize) -> bool {
+        i < self.len() && self.elems[i >> 3] & mask(i) != 0
+    }
+
+    /// Sets the bit at the given index, returning `false` if out of bounds.
+    pub fn set(&mut self, i: usize, value: bool) -> bool {
+        if i >= self.len() {
+            return false;
+        }
+        if value {
+            self.elems[i >> 3] |= mask(i);
+        } else {
+            self.elems[i >> 3] &= !mask(i);
+        }
+        true
+    }
+
+    /// Returns the bits of the array, in order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(move |i| self.get(i))
+    }
+
+    /// Returns the number of bits set before the given index, ie. the position of the
+    /// signature of the `i`-th key among the signatures of a [`MultiSignature`].
+    pub fn num_true_bits_before(&self, i: usize) -> usize {
+        self.iter().take(i).filter(|bit| *bit).count()
+    }
+}
+
+/// The bits are stored from the most significant bit of each byte, as by the Cosmos SDK.
+fn mask(i: usize) -> u8 {
+    1 << (7 - i % 8)
+}
+
+impl MultiSignature {
+    /// Assembles the signatures of a multisig key, given in the order of its keys, with
+    /// `None` for the keys which did not sign, and returns them along with the bit array
+    /// of the keys which signed, as expected in the `ModeInfo` of the signer.
+    pub fn new(signatures: impl IntoIterator<Item = Option<Vec<u8>>>) -> (Self, CompactBitArray) {
+        let signatures: Vec<_> = signatures.into_iter().collect();
+        let mut bit_array = CompactBitArray::new(signatures.len());
+        let mut multi_signature = Self::default();
+        for (i, signature) in signatures.into_iter().enumerate() {
+            if let Some(signature) = signature {
+                bit_array.set(i, true);
+                multi_signature.signatures.push(signature);
+            }
+        }
+
+        (multi_signature, bit_array)
+    }
+}
+
+fn write_key(f: &mut fmt::Formatter<'_>, name: &str, key: &[u8]) -> fmt::Result {
+    write!(f, "{name}{{")?;
+    for byte in key {
+        write!(f, "{byte:02X}")?;
+    }
+    write!(f, "}}")
+}
+
+impl fmt::Display for secp256k1::PubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_key(f, "PubKeySecp256k1", &self.key)
+    }
+}
+
+impl fmt::Display for ed25519::PubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_key(f, "PubKeyEd25519", &self.key)
+    }
+}
+
+impl fmt::Display for secp256r1::PubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_key(f, "secp256r1", &self.key)
+    }
+}
+
+/// Formats the multisig key as its threshold followed by its keys, eg.
+/// `LegacyAminoPubKey{2: [PubKeySecp256k1{...}, PubKeySecp256k1{...}]}`, with the type
+/// URL of the keys which cannot be unpacked.
+impl fmt::Display for LegacyAminoPubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LegacyAminoPubKey{{{}: [", self.threshold)?;
+        for (i, any) in self.public_keys.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match PublicKey::from_any(any) {
+                Ok(key) => write!(f, "{key}")?,
+                Err(_) => write!(f, "{}", any.type_url)?,
+            }
+        }
+        write!(f, "]}}")
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Secp256k1(key) => key.fmt(f),
+            Self::Ed25519(key) => key.fmt(f),
+            Self::Secp256r1(key) => key.fmt(f),
+            Self::Multisig(key) => key.fmt(f),
+        }
+    }
+}
+
+/// The errors of the unpacking of public keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PublicKeyError {
+    /// The type URL is not the one of a public key.
+    UnknownTypeUrl(String),
+    /// The key could not be decoded.
+    Decode(DecodeError),
+}
+
+impl fmt::Display for PublicKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublicKeyError::UnknownTypeUrl(type_url) => {
+                write!(f, "`{type_url}` is not the type URL of a public key")
+            }
+            PublicKeyError::Decode(e) => write!(f, "error decoding public key: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PublicKeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PublicKeyError::UnknownTypeUrl(_) => None,
+            PublicKeyError::Decode(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn secp256k1_key(byte: u8) -> PublicKey {
+        PublicKey::Secp256k1(secp256k1::PubKey {
+            key: vec![byte; 33],
+        })
+    }
+
+    #[test]
+    fn test_public_key_any_roundtrip() {
+        let keys = [
+            secp256k1_key(2),
+            PublicKey::Ed25519(ed25519::PubKey { key: vec![1; 32] }),
+            PublicKey::Secp256r1(secp256r1::PubKey { key: vec![3; 33] }),
+            PublicKey::Multisig(LegacyAminoPubKey::new(
+                2,
+                [secp256k1_key(2), secp256k1_key(3)],
+            )),
+        ];
+        for key in keys {
+            let any = key.to_any();
+            assert_eq!(PublicKey::from_any(&any).unwrap(), key);
+            assert_eq!(PublicKey::try_from(&Any::from(key.clone())).unwrap(), key);
+        }
+
+        let any = secp256k1_key(2).to_any();
+        assert_eq!(any.type_url, "/cosmos.crypto.secp256k1.PubKey");
+        assert_eq!(secp256k1_key(2).key_bytes(), Some([2; 33].as_slice()));
+    }
+
+    #[test]
+    fn test_public_key_from_any_errors() {
+        let any = Any {
+            type_url: "/cosmos.crypto.sr25519.PubKey".into(),
+            value: vec![],
+        };
+        assert_eq!(
+            PublicKey::from_any(&any),
+            Err(PublicKeyError::UnknownTypeUrl(any.type_url.clone()))
+        );
+
+        let any = Any {
+            type_url: secp256k1::PubKey::type_url(),
+            value: vec![0x0a, 0x05],
+        };
+        assert!(matches!(
+            PublicKey::from_any(&any),
+            Err(PublicKeyError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_multisig_keys() {
+        let keys = [secp256k1_key(2), secp256k1_key(3), secp256k1_key(4)];
+        let multisig = LegacyAminoPubKey::new(2, keys.clone());
+        assert_eq!(multisig.threshold, 2);
+        assert_eq!(multisig.keys().unwrap(), keys);
+        assert_eq!(PublicKey::from(multisig.clone()).key_bytes(), None);
+
+        let mut multisig = multisig;
+        multisig.public_keys[1].type_url = "/unknown.Key".into();
+        assert!(multisig.keys().is_err());
+    }
+
+    #[test]
+    fn test_public_key_display() {
+        let key = PublicKey::Secp256k1(secp256k1::PubKey {
+            key: vec![0x02, 0xa1],
+        });
+        assert_eq!(key.to_string(), "PubKeySecp256k1{02A1}");
+        let key = PublicKey::Ed25519(ed25519::PubKey { key: vec![0xff] });
+        assert_eq!(key.to_string(), "PubKeyEd25519{FF}");
+
+        let mut multisig = LegacyAminoPubKey::new(1, [secp256k1_key(1)]);
+        multisig.public_keys.push(Any {
+            type_url: "/unknown.Key".into(),
+            value: vec![],
+        });
+        assert_eq!(
+            multisig.to_string(),
+            format!(
+                "LegacyAminoPubKey{{1: [PubKeySecp256k1{{{}}}, /unknown.Key]}}",
+                "01".repeat(33)
+            )
+        );
+    }
+
+    #[test]
+    fn test_compact_bit_array() {
+        let mut bit_array = CompactBitArray::new(10);
+        assert_eq!(bit_array.len(), 10);
+        assert_eq!(bit_array.elems.len(), 2);
+        assert!(bit_array.set(0, true));
+        assert!(bit_array.set(9, true));
+        assert!(bit_array.set(3, true));
+        assert!(bit_array.set(3, false));
+        assert_eq!(bit_array.elems, [0b1000_0000, 0b0100_0000]);
+        assert!(bit_array.get(0));
+        assert!(!bit_array.get(3));
+        assert!(bit_array.get(9));
+        assert_eq!(bit_array.num_true_bits_before(9), 1);
+        assert_eq!(bit_array.num_true_bits_before(10), 2);
+
+        // Out of bounds.
+        assert!(!bit_array.set(10, true));
+        assert!(!bit_array.get(10));
+        assert_eq!(bit_array.elems, [0b1000_0000, 0b0100_0000]);
+
+        let decoded = CompactBitArray::decode(bit_array.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, bit_array);
+        assert_eq!(
+            decoded.iter().collect::<Vec<_>>(),
+            bit_array.iter().collect::<Vec<_>>()
+        );
+
+        assert!(CompactBitArray::new(0).is_empty());
+        assert_eq!(CompactBitArray::new(16).len(), 16);
+    }
+
+    #[test]
+    fn test_compact_bit_array_malformed() {
+        // More extra bits than a byte holds.
+        let mut bit_array = CompactBitArray {
+            extra_bits_stored: 12,
+            elems: vec![0xff],
+        };
+        assert_eq!(bit_array.len(), 8);
+        assert!(bit_array.get(7));
+        assert!(!bit_array.get(8));
+        assert!(!bit_array.set(11, true));
+
+        // Extra bits without any byte.
+        let bit_array = CompactBitArray {
+            extra_bits_stored: 3,
+            elems: vec![],
+        };
+        assert!(bit_array.is_empty());
+        assert!(!bit_array.get(0));
+        assert_eq!(bit_array.num_true_bits_before(3), 0);
+
+        let bit_array = CompactBitArray {
+            extra_bits_stored: u32::MAX,
+            elems: vec![0; 2],
+        };
+        assert_eq!(bit_array.len(), 16);
+    }
+
+    #[test]
+    fn test_multi_signature() {
+        let (multi_signature, bit_array) =
+            MultiSignature::new([Some(vec![1]), None, Some(vec![3])]);
+        assert_eq!(multi_signature.signatures, [vec![1], vec![3]]);
+        assert_eq!(bit_array.len(), 3);
+        assert_eq!(bit_array.iter().collect::<Vec<_>>(), [true, false, true]);
+        assert_eq!(bit_array.num_true_bits_before(2), 1);
+    }
+}
@@ -0,0 +1,346 @@
+//! Decoding of the messages of any type known to this crate at runtime, with
+//! `prost-reflect`, eg. for a block explorer to display the messages of the transactions
+//! without matching on their type URL:
+//!
+//! ```ignore
+//! let pool = dynamic::descriptor_pool()?;
+//! for msg in &tx_body.messages {
+//!     println!("{}", dynamic::any_to_json(&pool, msg)?);
+//! }
+//! ```
+//!
+//! The descriptors are the ones of the packages of the enabled features, and the JSON is
+//! the proto3 canonical JSON mapping, where the `Any` nested in the messages are expanded
+//! as well.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use prost::{DecodeError, Message};
+use prost_reflect::{DescriptorError, DescriptorPool, DynamicMessage, ReflectMessage};
+use serde_json::Value;
+
+use crate::google::protobuf::Any;
+
+/// Builds the pool of the descriptors of the packages of the enabled features and of
+/// their dependencies, which is best built once and shared.
+pub fn descriptor_pool() -> Result<DescriptorPool, DescriptorError> {
+    let mut pool = DescriptorPool::new();
+    for set in file_descriptor_sets() {
+        pool.decode_file_descriptor_set(set)?;
+    }
+
+    Ok(pool)
+}
+
+/// Decodes the message packed into a `google.protobuf.Any`, whose type must be found in
+/// the given pool.
+pub fn decode_any(pool: &DescriptorPool, any: &Any) -> Result<DynamicMessage, DynamicError> {
+    let name = any.type_url.rsplit('/').next().unwrap_or_default();
+    let descriptor = pool
+        .get_message_by_name(name)
+        .ok_or_else(|| DynamicError::UnknownTypeUrl(any.type_url.clone()))?;
+
+    DynamicMessage::decode(descriptor, any.value.as_slice()).map_err(DynamicError::Decode)
+}
+
+/// Decodes the message packed into a `google.protobuf.Any` and renders it as JSON, with
+/// its type URL in an `@type` field, as in the Cosmos SDK.
+///
+/// The type URLs of the `Any` nested in the message are rendered relative, eg.
+/// `/cosmos.bank.v1beta1.MsgSend`, whatever their form on the wire.
+pub fn any_to_json(pool: &DescriptorPool, any: &Any) -> Result<Value, DynamicError> {
+    let mut message = decode_any(pool, any)?;
+    qualify_type_urls(pool, &mut message)?;
+    let mut json = serde_json::to_value(&message).map_err(|e| DynamicError::Json(e.to_string()))?;
+    unqualify_type_urls(&mut json);
+
+    let mut fields = match json {
+        Value::Object(fields) => fields,
+        json => {
+            let mut fields = serde_json::Map::new();
+            fields.insert("value".to_string(), json);
+            fields
+        }
+    };
+    fields.insert("@type".to_string(), Value::String(any.type_url.clone()));
+
+    Ok(Value::Object(fields))
+}
+
+/// The prefix of the type URLs that `prost-reflect` resolves in the descriptor pool to
+/// render an `Any` as JSON, whereas those of Cosmos are relative.
+const TYPE_URL_PREFIX: &str = "type.googleapis.com";
+
+/// Prefixes the relative type URLs of the `Any` nested in a message, down to those nested
+/// in their own values, so that `prost-reflect` can render them as JSON.
+fn qualify_type_urls(
+    pool: &DescriptorPool,
+    message: &mut DynamicMessage,
+) -> Result<(), DynamicError> {
+    if message.descriptor().full_name() == "google.protobuf.Any" {
+        let mut any: Any = message.transcode_to().map_err(DynamicError::Decode)?;
+        if any.type_url.starts_with('/') {
+            let mut value = decode_any(pool, &any)?;
+            qualify_type_urls(pool, &mut value)?;
+            any.type_url = format!("{TYPE_URL_PREFIX}{}", any.type_url);
+            any.value = value.encode_to_vec();
+            message.transcode_from(&any).map_err(DynamicError::Decode)?;
+        }
+        return Ok(());
+    }
+
+    for (_, value) in message.fields_mut() {
+        qualify_value(pool, value)?;
+    }
+
+    Ok(())
+}
+
+fn qualify_value(
+    pool: &DescriptorPool,
+    value: &mut prost_reflect::Value,
+) -> Result<(), DynamicError> {
+    match value {
+        prost_reflect::Value::Message(message) => qualify_type_urls(pool, message),
+        prost_reflect::Value::List(values) => values
+            .iter_mut()
+            .try_for_each(|value| qualify_value(pool, value)),
+        prost_reflect::Value::Map(entries) => entries
+            .values_mut()
+            .try_for_each(|value| qualify_value(pool, value)),
+        _ => Ok(()),
+    }
+}
+
+/// Gives back their relative form to the type URLs of the rendered `Any`.
+fn unqualify_type_urls(json: &mut Value) {
+    match json {
+        Value::Object(fields) => {
+            for (name, value) in fields.iter_mut() {
+                match value {
+                    Value::String(type_url) if name == "@type" => {
+                        if let Some(relative) = type_url.strip_prefix(TYPE_URL_PREFIX) {
+                            *type_url = relative.to_string();
+                        }
+                    }
+                    value => unqualify_type_urls(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(unqualify_type_urls),
+        _ => {}
+    }
+}
+
+/// Returns the encoded descriptor sets of the packages of the enabled features.
+fn file_descriptor_sets() -> Vec<&'static [u8]> {
+    let mut sets = vec![crate::google::protobuf::FILE_DESCRIPTOR_SET];
+    #[cfg(feature = "cosmos-auth")]
+    sets.push(crate::cosmos::auth::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-authz")]
+    sets.push(crate::cosmos::authz::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-autocli")]
+    sets.push(crate::cosmos::autocli::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-bank")]
+    sets.push(crate::cosmos::bank::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::abci::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::kv::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::node::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::query::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::reflection::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::reflection::v2alpha1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::snapshots::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::store::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::tendermint::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-consensus")]
+    sets.push(crate::cosmos::consensus::v1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::crypto::ed25519::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::crypto::multisig::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::crypto::multisig::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::crypto::secp256k1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::crypto::secp256r1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-distribution")]
+    sets.push(crate::cosmos::distribution::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-evidence")]
+    sets.push(crate::cosmos::evidence::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-feegrant")]
+    sets.push(crate::cosmos::feegrant::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-gov")]
+    {
+        sets.push(crate::cosmos::gov::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::cosmos::gov::v1beta1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "cosmos-group")]
+    sets.push(crate::cosmos::group::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-mint")]
+    sets.push(crate::cosmos::mint::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-nft")]
+    sets.push(crate::cosmos::nft::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-slashing")]
+    sets.push(crate::cosmos::slashing::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-staking")]
+    sets.push(crate::cosmos::staking::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-tx")]
+    {
+        sets.push(crate::cosmos::tx::signing::v1beta1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::cosmos::tx::v1beta1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "cosmos-upgrade")]
+    sets.push(crate::cosmos::upgrade::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-vesting")]
+    sets.push(crate::cosmos::vesting::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-fee")]
+    sets.push(crate::ibc::applications::fee::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-interchain-accounts")]
+    {
+        sets.push(
+            crate::ibc::applications::interchain_accounts::controller::v1::FILE_DESCRIPTOR_SET,
+        );
+        sets.push(crate::ibc::applications::interchain_accounts::genesis::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::applications::interchain_accounts::host::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::applications::interchain_accounts::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-nft-transfer")]
+    sets.push(crate::ibc::applications::nft_transfer::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-transfer")]
+    {
+        sets.push(crate::ibc::applications::transfer::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::applications::transfer::v2::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-core")]
+    {
+        sets.push(crate::ibc::core::channel::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::client::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::commitment::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::connection::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::types::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-lightclients")]
+    {
+        sets.push(crate::ibc::lightclients::localhost::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::lightclients::localhost::v2::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::lightclients::solomachine::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::lightclients::solomachine::v3::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::lightclients::tendermint::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::lightclients::wasm::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-core")]
+    sets.push(crate::ibc::mock::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "icq")]
+    sets.push(crate::icq::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "interchain-security")]
+    {
+        sets.push(crate::interchain_security::ccv::consumer::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::interchain_security::ccv::provider::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::interchain_security::ccv::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "packetforward")]
+    sets.push(crate::packetforward::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ratelimit")]
+    sets.push(crate::ratelimit::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "stride")]
+    sets.push(crate::stride::interchainquery::v1::FILE_DESCRIPTOR_SET);
+
+    sets
+}
+
+/// The errors of the decoding of the messages packed into a `google.protobuf.Any`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynamicError {
+    /// The type URL is not the one of a message found in the descriptor pool.
+    UnknownTypeUrl(String),
+    /// The message could not be decoded.
+    Decode(DecodeError),
+    /// The message could not be rendered as JSON.
+    Json(String),
+}
+
+impl fmt::Display for DynamicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicError::UnknownTypeUrl(type_url) => {
+                write!(f, "no descriptor for the type URL `{type_url}`")
+            }
+            DynamicError::Decode(e) => write!(f, "error decoding message: {e}"),
+            DynamicError::Json(e) => write!(f, "error rendering message as JSON: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for DynamicError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DynamicError::Decode(e) => Some(e),
+            DynamicError::UnknownTypeUrl(_) | DynamicError::Json(_) => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ibc-core"))]
+mod test {
+    use super::*;
+    use crate::ibc::core::client::v1::{Height, MsgUpdateClient};
+    use crate::protobuf::Name;
+
+    fn pack<M: Message + Name>(msg: &M) -> Any {
+        Any {
+            type_url: M::type_url(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn msg_update_client(header: Any) -> Any {
+        pack(&MsgUpdateClient {
+            client_id: "07-tendermint-0".to_string(),
+            header: Some(header),
+            signer: "cosmos1signer".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_any_to_json_nested_any() {
+        let pool = descriptor_pool().unwrap();
+        let height = Height {
+            revision_number: 1,
+            revision_height: 2,
+        };
+
+        assert_eq!(
+            any_to_json(&pool, &msg_update_client(pack(&height))).unwrap(),
+            serde_json::json!({
+                "@type": "/ibc.core.client.v1.MsgUpdateClient",
+                "clientId": "07-tendermint-0",
+                "header": {
+                    "@type": "/ibc.core.client.v1.Height",
+                    "revisionNumber": "1",
+                    "revisionHeight": "2",
+                },
+                "signer": "cosmos1signer",
+            })
+        );
+    }
+
+    #[test]
+    fn test_any_to_json_unknown_type_url() {
+        let pool = descriptor_pool().unwrap();
+        let unknown = Any {
+            type_url: "/foo.v1.Bar".to_string(),
+            value: vec![],
+        };
+
+        assert_eq!(
+            any_to_json(&pool, &unknown),
+            Err(DynamicError::UnknownTypeUrl("/foo.v1.Bar".to_string()))
+        );
+        assert_eq!(
+            any_to_json(&pool, &msg_update_client(unknown)),
+            Err(DynamicError::UnknownTypeUrl("/foo.v1.Bar".to_string()))
+        );
+    }
+}
@@ -0,0 +1,465 @@
+//! Parsing of the IBC events emitted by the chains, ie. the `tendermint.abci.Event` of
+//! the results of the transactions and blocks, into typed events, eg. to relay the
+//! packets sent on a channel.
+//!
+//! The events are parsed from their standard attributes, as emitted by ibc-go, eg.
+//! `packet_sequence` or `connection_id`, either from the ABCI events of any version of
+//! CometBFT, with `TryFrom`, or from their type and key-value attributes, with
+//! [`IbcEvent::parse`]. The packet data and acknowledgements are read from their hex
+//! attributes, or from their deprecated plain attributes if these are missing.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use subtle_encoding::hex;
+
+use crate::ibc::core::channel::v1::{Order, Packet};
+use crate::ibc::core::client::v1::Height;
+
+/// An IBC event, of the connection or channel handshakes, or of the lifecycle of the
+/// packets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IbcEvent {
+    ConnectionOpenInit(ConnectionEvent),
+    ConnectionOpenTry(ConnectionEvent),
+    ConnectionOpenAck(ConnectionEvent),
+    ConnectionOpenConfirm(ConnectionEvent),
+    ChannelOpenInit(ChannelEvent),
+    ChannelOpenTry(ChannelEvent),
+    ChannelOpenAck(ChannelEvent),
+    ChannelOpenConfirm(ChannelEvent),
+    ChannelCloseInit(ChannelEvent),
+    ChannelCloseConfirm(ChannelEvent),
+    SendPacket(PacketEvent),
+    RecvPacket(PacketEvent),
+    WriteAcknowledgement(PacketEvent),
+    AcknowledgePacket(PacketEvent),
+    TimeoutPacket(PacketEvent),
+}
+
+/// The attributes of the events of the connection handshake, whose counterparty
+/// connection is left empty until the counterparty has opened its end.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionEvent {
+    pub connection_id: String,
+    pub client_id: String,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: String,
+}
+
+/// The attributes of the events of the channel handshakes, whose counterparty channel
+/// is left empty until the counterparty has opened its end, and whose version is only
+/// given by the `INIT` and `TRYOPEN` events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelEvent {
+    pub port_id: String,
+    pub channel_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: String,
+    pub connection_id: String,
+    pub version: String,
+}
+
+/// The attributes of the events of the packets, whose data is only given by the
+/// `send_packet`, `recv_packet` and `write_acknowledgement` events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketEvent {
+    pub packet: Packet,
+    /// The ordering of the channel, which is not given by `write_acknowledgement`.
+    pub ordering: Option<Order>,
+    pub connection_id: String,
+    /// The acknowledgement written for the packet, only given by
+    /// `write_acknowledgement`.
+    pub acknowledgement: Option<Vec<u8>>,
+}
+
+impl IbcEvent {
+    /// Parses an event from its type, eg. `send_packet`, and its attributes.
+    ///
+    /// If an attribute is given several times, the first one is used.
+    pub fn parse<'a>(
+        kind: &str,
+        attributes: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, EventError> {
+        let mut attrs = Attributes(BTreeMap::new());
+        for (key, value) in attributes {
+            attrs.0.entry(key).or_insert(value);
+        }
+
+        let event = match kind {
+            "connection_open_init" => Self::ConnectionOpenInit(attrs.connection()?),
+            "connection_open_try" => Self::ConnectionOpenTry(attrs.connection()?),
+            "connection_open_ack" => Self::ConnectionOpenAck(attrs.connection()?),
+            "connection_open_confirm" => Self::ConnectionOpenConfirm(attrs.connection()?),
+            "channel_open_init" => Self::ChannelOpenInit(attrs.channel()?),
+            "channel_open_try" => Self::ChannelOpenTry(attrs.channel()?),
+            "channel_open_ack" => Self::ChannelOpenAck(attrs.channel()?),
+            "channel_open_confirm" => Self::ChannelOpenConfirm(attrs.channel()?),
+            "channel_close_init" => Self::ChannelCloseInit(attrs.channel()?),
+            "channel_close_confirm" => Self::ChannelCloseConfirm(attrs.channel()?),
+            "send_packet" => Self::SendPacket(attrs.packet()?),
+            "recv_packet" => Self::RecvPacket(attrs.packet()?),
+            "write_acknowledgement" => Self::WriteAcknowledgement(attrs.packet()?),
+            "acknowledge_packet" => Self::AcknowledgePacket(attrs.packet()?),
+            "timeout_packet" => Self::TimeoutPacket(attrs.packet()?),
+            _ => return Err(EventError::UnknownType(kind.to_string())),
+        };
+
+        Ok(event)
+    }
+
+    /// Returns the type of the event, eg. `send_packet`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ConnectionOpenInit(_) => "connection_open_init",
+            Self::ConnectionOpenTry(_) => "connection_open_try",
+            Self::ConnectionOpenAck(_) => "connection_open_ack",
+            Self::ConnectionOpenConfirm(_) => "connection_open_confirm",
+            Self::ChannelOpenInit(_) => "channel_open_init",
+            Self::ChannelOpenTry(_) => "channel_open_try",
+            Self::ChannelOpenAck(_) => "channel_open_ack",
+            Self::ChannelOpenConfirm(_) => "channel_open_confirm",
+            Self::ChannelCloseInit(_) => "channel_close_init",
+            Self::ChannelCloseConfirm(_) => "channel_close_confirm",
+            Self::SendPacket(_) => "send_packet",
+            Self::RecvPacket(_) => "recv_packet",
+            Self::WriteAcknowledgement(_) => "write_acknowledgement",
+            Self::AcknowledgePacket(_) => "acknowledge_packet",
+            Self::TimeoutPacket(_) => "timeout_packet",
+        }
+    }
+
+    /// Returns the packet of the event, if it is an event of the packets.
+    pub fn packet(&self) -> Option<&Packet> {
+        match self {
+            Self::SendPacket(event)
+            | Self::RecvPacket(event)
+            | Self::WriteAcknowledgement(event)
+            | Self::AcknowledgePacket(event)
+            | Self::TimeoutPacket(event) => Some(&event.packet),
+            _ => None,
+        }
+    }
+}
+
+/// The attributes of an event, by key.
+struct Attributes<'a>(BTreeMap<&'a str, &'a str>);
+
+impl<'a> Attributes<'a> {
+    fn required(&self, key: &'static str) -> Result<&'a str, EventError> {
+        self.0
+            .get(key)
+            .copied()
+            .ok_or(EventError::MissingAttribute { key })
+    }
+
+    fn optional(&self, key: &'static str) -> String {
+        self.0.get(key).copied().unwrap_or_default().to_string()
+    }
+
+    fn parse<T: FromStr>(&self, key: &'static str) -> Result<Option<T>, EventError> {
+        self.0
+            .get(key)
+            .map(|value| value.parse().map_err(|_| invalid(key, value)))
+            .transpose()
+    }
+
+    /// Reads the bytes of the hex attribute, or of the deprecated plain attribute.
+    fn bytes(&self, hex_key: &'static str, key: &'static str) -> Result<Vec<u8>, EventError> {
+        match (self.0.get(hex_key), self.0.get(key)) {
+            (Some(value), _) => hex::decode(value).map_err(|_| invalid(hex_key, value)),
+            (None, Some(value)) => Ok(value.as_bytes().to_vec()),
+            (None, None) => Err(EventError::MissingAttribute { key: hex_key }),
+        }
+    }
+
+    fn connection(&self) -> Result<ConnectionEvent, EventError> {
+        Ok(ConnectionEvent {
+            connection_id: self.required("connection_id")?.to_string(),
+            client_id: self.required("client_id")?.to_string(),
+            counterparty_client_id: self.optional("counterparty_client_id"),
+            counterparty_connection_id: self.optional("counterparty_connection_id"),
+        })
+    }
+
+    fn channel(&self) -> Result<ChannelEvent, EventError> {
+        Ok(ChannelEvent {
+            port_id: self.required("port_id")?.to_string(),
+            channel_id: self.required("channel_id")?.to_string(),
+            counterparty_port_id: self.optional("counterparty_port_id"),
+            counterparty_channel_id: self.optional("counterparty_channel_id"),
+            connection_id: self.required("connection_id")?.to_string(),
+            version: self.optional("version"),
+        })
+    }
+
+    fn packet(&self) -> Result<PacketEvent, EventError> {
+        let sequence = self.required("packet_sequence")?;
+        let data = match self.bytes("packet_data_hex", "packet_data") {
+            Err(EventError::MissingAttribute { .. }) => Vec::new(),
+            data => data?,
+        };
+        let timeout_height = self
+            .parse::<Height>("packet_timeout_height")?
+            .filter(|height| !height.is_zero());
+
+        let packet = Packet {
+            sequence: sequence
+                .parse()
+                .map_err(|_| invalid("packet_sequence", sequence))?,
+            source_port: self.required("packet_src_port")?.to_string(),
+            source_channel: self.required("packet_src_channel")?.to_string(),
+            destination_port: self.required("packet_dst_port")?.to_string(),
+            destination_channel: self.required("packet_dst_channel")?.to_string(),
+            data,
+            timeout_height,
+            timeout_timestamp: self.parse("packet_timeout_timestamp")?.unwrap_or_default(),
+        };
+
+        // `packet_connection` is the deprecated attribute of the connection.
+        let connection_id = match self.0.get("connection_id") {
+            Some(connection_id) => connection_id.to_string(),
+            None => self.optional("packet_connection"),
+        };
+        let acknowledgement = match self.bytes("packet_ack_hex", "packet_ack") {
+            Err(EventError::MissingAttribute { .. }) => None,
+            acknowledgement => Some(acknowledgement?),
+        };
+
+        Ok(PacketEvent {
+            packet,
+            ordering: self.parse("packet_channel_ordering")?,
+            connection_id,
+            acknowledgement,
+        })
+    }
+}
+
+fn invalid(key: &'static str, value: &str) -> EventError {
+    EventError::InvalidAttribute {
+        key,
+        value: value.to_string(),
+    }
+}
+
+/// Parses the events of all the versions of CometBFT, whose attributes are bytes up to
+/// CometBFT 0.34, and strings afterwards.
+macro_rules! impl_try_from_abci_event {
+    ($($event:ty),* $(,)?) => {
+        $(
+            impl TryFrom<&$event> for IbcEvent {
+                type Error = EventError;
+
+                fn try_from(event: &$event) -> Result<Self, Self::Error> {
+                    let attributes = event
+                        .attributes
+                        .iter()
+                        .map(|attribute| {
+                            Ok((utf8(attribute.key.as_ref())?, utf8(attribute.value.as_ref())?))
+                        })
+                        .collect::<Result<Vec<_>, EventError>>()?;
+
+                    Self::parse(&event.r#type, attributes)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_abci_event!(
+    tendermint_proto::v0_34::abci::Event,
+    tendermint_proto::v0_37::abci::Event,
+);
+
+fn utf8(bytes: &[u8]) -> Result<&str, EventError> {
+    core::str::from_utf8(bytes).map_err(|_| EventError::InvalidUtf8)
+}
+
+/// The errors of the parsing of the IBC events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventError {
+    /// The type of the event is not the one of an IBC event.
+    UnknownType(String),
+    /// The event is missing the attribute with the given key.
+    MissingAttribute { key: &'static str },
+    /// The value of the attribute with the given key is not valid, eg. a packet
+    /// sequence which is not a number.
+    InvalidAttribute { key: &'static str, value: String },
+    /// A key or value of the attributes of a CometBFT 0.34 event is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::UnknownType(kind) => write!(f, "`{kind}` is not an IBC event"),
+            EventError::MissingAttribute { key } => write!(f, "missing attribute `{key}`"),
+            EventError::InvalidAttribute { key, value } => {
+                write!(f, "invalid value `{value}` of attribute `{key}`")
+            }
+            EventError::InvalidUtf8 => write!(f, "event attribute is not valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EventError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tendermint_proto::v0_37::abci::{Event, EventAttribute};
+
+    const PACKET_DATA: &str =
+        r#"{"amount":"100","denom":"uatom","receiver":"osmo1receiver","sender":"cosmos1sender"}"#;
+    const PACKET_DATA_HEX: &str = "7b22616d6f756e74223a22313030222c2264656e6f6d223a227561746f6d222c227265636569766572223a226f736d6f317265636569766572222c2273656e646572223a22636f736d6f733173656e646572227d";
+
+    fn event(kind: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            r#type: kind.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| EventAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    index: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn packet() -> Packet {
+        Packet {
+            sequence: 7,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-141".to_string(),
+            data: PACKET_DATA.as_bytes().to_vec(),
+            timeout_height: None,
+            timeout_timestamp: 1681992000000000000,
+        }
+    }
+
+    #[test]
+    fn test_send_packet() {
+        // As emitted by ibc-go v7.
+        let event = event(
+            "send_packet",
+            &[
+                ("packet_data", PACKET_DATA),
+                ("packet_data_hex", PACKET_DATA_HEX),
+                ("packet_timeout_height", "0-0"),
+                ("packet_timeout_timestamp", "1681992000000000000"),
+                ("packet_sequence", "7"),
+                ("packet_src_port", "transfer"),
+                ("packet_src_channel", "channel-0"),
+                ("packet_dst_port", "transfer"),
+                ("packet_dst_channel", "channel-141"),
+                ("packet_channel_ordering", "ORDER_UNORDERED"),
+                ("packet_connection", "connection-0"),
+                ("connection_id", "connection-0"),
+            ],
+        );
+
+        let event = IbcEvent::try_from(&event).unwrap();
+
+        assert_eq!(event.kind(), "send_packet");
+        assert_eq!(
+            event,
+            IbcEvent::SendPacket(PacketEvent {
+                packet: packet(),
+                ordering: Some(Order::Unordered),
+                connection_id: "connection-0".to_string(),
+                acknowledgement: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_write_acknowledgement_deprecated_attributes() {
+        let event = IbcEvent::parse(
+            "write_acknowledgement",
+            [
+                ("packet_data", PACKET_DATA),
+                ("packet_timeout_height", "1-1000"),
+                ("packet_timeout_timestamp", "1681992000000000000"),
+                ("packet_sequence", "7"),
+                ("packet_src_port", "transfer"),
+                ("packet_src_channel", "channel-0"),
+                ("packet_dst_port", "transfer"),
+                ("packet_dst_channel", "channel-141"),
+                ("packet_ack", r#"{"result":"AQ=="}"#),
+                ("packet_connection", "connection-1"),
+            ],
+        )
+        .unwrap();
+
+        let mut packet = packet();
+        packet.timeout_height = Some(Height::new(1, 1000));
+
+        assert_eq!(event.packet(), Some(&packet));
+        assert_eq!(
+            event,
+            IbcEvent::WriteAcknowledgement(PacketEvent {
+                packet,
+                ordering: None,
+                connection_id: "connection-1".to_string(),
+                acknowledgement: Some(br#"{"result":"AQ=="}"#.to_vec()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_channel_open_init() {
+        let event = IbcEvent::parse(
+            "channel_open_init",
+            [
+                ("port_id", "transfer"),
+                ("channel_id", "channel-0"),
+                ("counterparty_port_id", "transfer"),
+                ("counterparty_channel_id", ""),
+                ("connection_id", "connection-0"),
+                ("version", "ics20-1"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            event,
+            IbcEvent::ChannelOpenInit(ChannelEvent {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                counterparty_port_id: "transfer".to_string(),
+                counterparty_channel_id: String::new(),
+                connection_id: "connection-0".to_string(),
+                version: "ics20-1".to_string(),
+            })
+        );
+        assert_eq!(event.packet(), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            IbcEvent::parse("transfer", []),
+            Err(EventError::UnknownType("transfer".to_string()))
+        );
+        assert_eq!(
+            IbcEvent::parse("connection_open_init", [("connection_id", "connection-0")]),
+            Err(EventError::MissingAttribute { key: "client_id" })
+        );
+        assert_eq!(
+            IbcEvent::parse("timeout_packet", [("packet_sequence", "seven")]),
+            Err(EventError::InvalidAttribute {
+                key: "packet_sequence",
+                value: "seven".to_string(),
+            })
+        );
+    }
+}
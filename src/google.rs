@@ -1,6 +1,8 @@
 pub mod protobuf {
     use crate::include_proto;
     include_proto!("google.protobuf.rs");
+    #[cfg(feature = "json")]
+    include_proto!("google.protobuf.serde.rs");
 
     // source: https://github.com/tokio-rs/prost/blob/master/prost-types/src/lib.rs
     use core::convert::TryFrom;
@@ -15,7 +17,50 @@ pub mod protobuf {
     const NANOS_PER_SECOND: i32 = 1_000_000_000;
     const NANOS_MAX: i32 = NANOS_PER_SECOND - 1;
 
+    /// Seconds from the Unix epoch to `0001-01-01T00:00:00Z`.
+    const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+    /// Seconds from the Unix epoch to `9999-12-31T23:59:59Z`.
+    const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+    /// Seconds in approximately 10,000 years, the maximum allowed by the specification.
+    const MAX_DURATION_SECONDS: u64 = 315_576_000_000;
+
     impl Duration {
+        /// Returns whether the duration is valid, ie. of at most 315,576,000,000 seconds
+        /// (about 10,000 years) either way, with `nanos` between -999,999,999 and
+        /// 999,999,999 and of the same sign as `seconds`.
+        pub fn is_valid(&self) -> bool {
+            self.seconds.unsigned_abs() <= MAX_DURATION_SECONDS
+                && (-NANOS_MAX..=NANOS_MAX).contains(&self.nanos)
+                && (self.seconds == 0 || self.nanos == 0 || (self.seconds < 0) == (self.nanos < 0))
+        }
+
+        /// Converts a `core::time::Duration` to a `Duration`, failing if it is out of
+        /// the valid range, unlike the `From` conversion which saturates.
+        pub fn try_from_std(duration: time::Duration) -> Result<Self, DurationConversionError> {
+            if duration.as_secs() > MAX_DURATION_SECONDS {
+                return Err(DurationConversionError::OutOfRange(duration));
+            }
+
+            Ok(Duration {
+                seconds: duration.as_secs() as i64,
+                nanos: duration.subsec_nanos() as i32,
+            })
+        }
+
+        /// Converts to a `core::time::Duration`, failing if the duration is negative
+        /// or not valid, see [`Duration::is_valid`], unlike the `TryFrom` conversion
+        /// which normalizes it first.
+        pub fn try_into_std(self) -> Result<time::Duration, DurationConversionError> {
+            if !self.is_valid() {
+                return Err(DurationConversionError::Invalid(self));
+            }
+            if self.seconds < 0 || self.nanos < 0 {
+                return Err(DurationConversionError::Negative(self));
+            }
+
+            Ok(time::Duration::new(self.seconds as u64, self.nanos as u32))
+        }
+
         /// Normalizes the duration to a canonical format.
         pub fn normalize(&mut self) {
             // Make sure nanos is in the range.
@@ -60,6 +105,35 @@ pub mod protobuf {
         }
     }
 
+    /// Indicates that a [`Duration`] could not be converted to or from a
+    /// `core::time::Duration`, see [`Duration::try_from_std`] and [`Duration::try_into_std`].
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum DurationConversionError {
+        /// The duration is negative.
+        Negative(Duration),
+        /// The duration is not valid, see [`Duration::is_valid`].
+        Invalid(Duration),
+        /// The `core::time::Duration` exceeds the range of valid durations.
+        OutOfRange(time::Duration),
+    }
+
+    impl core::fmt::Display for DurationConversionError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::Negative(duration) => write!(f, "{duration:?} is negative"),
+                Self::Invalid(duration) => write!(f, "{duration:?} is not a valid duration"),
+                Self::OutOfRange(duration) => write!(
+                    f,
+                    "{duration:?} exceeds the maximum duration of {MAX_DURATION_SECONDS} seconds"
+                ),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for DurationConversionError {}
+
     /// Converts a `std::time::Duration` to a `Duration`.
     impl From<time::Duration> for Duration {
         fn from(duration: time::Duration) -> Duration {
@@ -103,6 +177,13 @@ pub mod protobuf {
     }
 
     impl Timestamp {
+        /// Returns whether the timestamp is valid, ie. between `0001-01-01T00:00:00Z` and
+        /// `9999-12-31T23:59:59.999999999Z`, with `nanos` between 0 and 999,999,999.
+        pub fn is_valid(&self) -> bool {
+            (MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&self.seconds)
+                && (0..=NANOS_MAX).contains(&self.nanos)
+        }
+
         /// Normalizes the timestamp to a canonical format.
         #[cfg(feature = "std")]
         pub fn normalize(&mut self) {
@@ -141,13 +222,11 @@ pub mod protobuf {
 
     /// Implements the unstable/naive version of `Eq`: a basic equality check on the internal fields of the `Timestamp`.
     /// This implies that `normalized_ts != non_normalized_ts` even if `normalized_ts == non_normalized_ts.normalized()`.
-    #[cfg(feature = "std")]
     impl Eq for Timestamp {}
 
-    #[cfg(feature = "std")]
     #[allow(clippy::derived_hash_with_manual_eq)] // Derived logic is correct: comparing the 2 fields for equality
-    impl std::hash::Hash for Timestamp {
-        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    impl core::hash::Hash for Timestamp {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
             self.seconds.hash(state);
             self.nanos.hash(state);
         }
@@ -229,6 +308,1219 @@ pub mod protobuf {
         }
     }
 
+    /// Indicates that a [`Timestamp`] is not valid, see [`Timestamp::is_valid`], and
+    /// thus could not be converted to or from a date and time, eg. a
+    /// `time::OffsetDateTime`.
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub struct InvalidTimestampError {
+        pub timestamp: Timestamp,
+    }
+
+    impl core::fmt::Display for InvalidTimestampError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(
+                f,
+                "{:?} is not a valid timestamp, it must be between 0001-01-01T00:00:00Z \
+                 and 9999-12-31T23:59:59.999999999Z",
+                self.timestamp
+            )
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for InvalidTimestampError {}
+
+    #[cfg(feature = "time")]
+    impl TryFrom<Timestamp> for ::time::OffsetDateTime {
+        type Error = InvalidTimestampError;
+
+        fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+            if !timestamp.is_valid() {
+                return Err(InvalidTimestampError { timestamp });
+            }
+
+            ::time::OffsetDateTime::from_unix_timestamp(timestamp.seconds)
+                .and_then(|date_time| date_time.replace_nanosecond(timestamp.nanos as u32))
+                .map_err(|_| InvalidTimestampError { timestamp })
+        }
+    }
+
+    #[cfg(feature = "time")]
+    impl TryFrom<::time::OffsetDateTime> for Timestamp {
+        type Error = InvalidTimestampError;
+
+        fn try_from(date_time: ::time::OffsetDateTime) -> Result<Self, Self::Error> {
+            let timestamp = Timestamp {
+                seconds: date_time.unix_timestamp(),
+                nanos: date_time.nanosecond() as i32,
+            };
+
+            if timestamp.is_valid() {
+                Ok(timestamp)
+            } else {
+                Err(InvalidTimestampError { timestamp })
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    impl TryFrom<Timestamp> for ::chrono::DateTime<::chrono::Utc> {
+        type Error = InvalidTimestampError;
+
+        fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+            use ::chrono::TimeZone;
+
+            if !timestamp.is_valid() {
+                return Err(InvalidTimestampError { timestamp });
+            }
+
+            ::chrono::Utc
+                .timestamp_opt(timestamp.seconds, timestamp.nanos as u32)
+                .single()
+                .ok_or(InvalidTimestampError { timestamp })
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    impl TryFrom<::chrono::DateTime<::chrono::Utc>> for Timestamp {
+        type Error = InvalidTimestampError;
+
+        fn try_from(date_time: ::chrono::DateTime<::chrono::Utc>) -> Result<Self, Self::Error> {
+            // The nanoseconds exceed 999,999,999 during leap seconds, which are rejected.
+            let timestamp = Timestamp {
+                seconds: date_time.timestamp(),
+                nanos: date_time.timestamp_subsec_nanos() as i32,
+            };
+
+            if timestamp.is_valid() {
+                Ok(timestamp)
+            } else {
+                Err(InvalidTimestampError { timestamp })
+            }
+        }
+    }
+
+    // The Tendermint types of the messages, eg. `tendermint.types.SignedHeader`, are those
+    // of `tendermint_proto`, which has its own copies of the well-known types however.
+    // Conversions are thus defined in both directions, so as not to go through the encoding.
+
+    impl From<tendermint_proto::google::protobuf::Timestamp> for Timestamp {
+        fn from(timestamp: tendermint_proto::google::protobuf::Timestamp) -> Self {
+            Timestamp {
+                seconds: timestamp.seconds,
+                nanos: timestamp.nanos,
+            }
+        }
+    }
+
+    impl From<Timestamp> for tendermint_proto::google::protobuf::Timestamp {
+        fn from(timestamp: Timestamp) -> Self {
+            tendermint_proto::google::protobuf::Timestamp {
+                seconds: timestamp.seconds,
+                nanos: timestamp.nanos,
+            }
+        }
+    }
+
+    impl From<tendermint_proto::google::protobuf::Duration> for Duration {
+        fn from(duration: tendermint_proto::google::protobuf::Duration) -> Self {
+            Duration {
+                seconds: duration.seconds,
+                nanos: duration.nanos,
+            }
+        }
+    }
+
+    impl From<Duration> for tendermint_proto::google::protobuf::Duration {
+        fn from(duration: Duration) -> Self {
+            tendermint_proto::google::protobuf::Duration {
+                seconds: duration.seconds,
+                nanos: duration.nanos,
+            }
+        }
+    }
+
+    /// Converts the well-known types to and from their `prost_types` equivalents, which
+    /// have the very same fields.
+    #[cfg(feature = "prost-types")]
+    mod prost_types_conversions {
+        use super::value::Kind;
+        use super::{Any, Duration, FieldMask, ListValue, Struct, Timestamp, Value};
+
+        impl From<::prost_types::Any> for Any {
+            fn from(any: ::prost_types::Any) -> Self {
+                Any {
+                    type_url: any.type_url,
+                    value: any.value,
+                }
+            }
+        }
+
+        impl From<Any> for ::prost_types::Any {
+            fn from(any: Any) -> Self {
+                ::prost_types::Any {
+                    type_url: any.type_url,
+                    value: any.value,
+                }
+            }
+        }
+
+        impl From<::prost_types::Timestamp> for Timestamp {
+            fn from(timestamp: ::prost_types::Timestamp) -> Self {
+                Timestamp {
+                    seconds: timestamp.seconds,
+                    nanos: timestamp.nanos,
+                }
+            }
+        }
+
+        impl From<Timestamp> for ::prost_types::Timestamp {
+            fn from(timestamp: Timestamp) -> Self {
+                ::prost_types::Timestamp {
+                    seconds: timestamp.seconds,
+                    nanos: timestamp.nanos,
+                }
+            }
+        }
+
+        impl From<::prost_types::Duration> for Duration {
+            fn from(duration: ::prost_types::Duration) -> Self {
+                Duration {
+                    seconds: duration.seconds,
+                    nanos: duration.nanos,
+                }
+            }
+        }
+
+        impl From<Duration> for ::prost_types::Duration {
+            fn from(duration: Duration) -> Self {
+                ::prost_types::Duration {
+                    seconds: duration.seconds,
+                    nanos: duration.nanos,
+                }
+            }
+        }
+
+        impl From<::prost_types::FieldMask> for FieldMask {
+            fn from(field_mask: ::prost_types::FieldMask) -> Self {
+                FieldMask {
+                    paths: field_mask.paths,
+                }
+            }
+        }
+
+        impl From<FieldMask> for ::prost_types::FieldMask {
+            fn from(field_mask: FieldMask) -> Self {
+                ::prost_types::FieldMask {
+                    paths: field_mask.paths,
+                }
+            }
+        }
+
+        impl From<::prost_types::Struct> for Struct {
+            fn from(value: ::prost_types::Struct) -> Self {
+                Struct {
+                    fields: value
+                        .fields
+                        .into_iter()
+                        .map(|(key, value)| (key, value.into()))
+                        .collect(),
+                }
+            }
+        }
+
+        impl From<Struct> for ::prost_types::Struct {
+            fn from(value: Struct) -> Self {
+                ::prost_types::Struct {
+                    fields: value
+                        .fields
+                        .into_iter()
+                        .map(|(key, value)| (key, value.into()))
+                        .collect(),
+                }
+            }
+        }
+
+        impl From<::prost_types::ListValue> for ListValue {
+            fn from(list: ::prost_types::ListValue) -> Self {
+                ListValue {
+                    values: list.values.into_iter().map(Into::into).collect(),
+                }
+            }
+        }
+
+        impl From<ListValue> for ::prost_types::ListValue {
+            fn from(list: ListValue) -> Self {
+                ::prost_types::ListValue {
+                    values: list.values.into_iter().map(Into::into).collect(),
+                }
+            }
+        }
+
+        impl From<::prost_types::Value> for Value {
+            fn from(value: ::prost_types::Value) -> Self {
+                use ::prost_types::value::Kind as Other;
+
+                let kind = value.kind.map(|kind| match kind {
+                    Other::NullValue(value) => Kind::NullValue(value),
+                    Other::NumberValue(value) => Kind::NumberValue(value),
+                    Other::StringValue(value) => Kind::StringValue(value),
+                    Other::BoolValue(value) => Kind::BoolValue(value),
+                    Other::StructValue(value) => Kind::StructValue(value.into()),
+                    Other::ListValue(value) => Kind::ListValue(value.into()),
+                });
+
+                Value { kind }
+            }
+        }
+
+        impl From<Value> for ::prost_types::Value {
+            fn from(value: Value) -> Self {
+                use ::prost_types::value::Kind as Other;
+
+                let kind = value.kind.map(|kind| match kind {
+                    Kind::NullValue(value) => Other::NullValue(value),
+                    Kind::NumberValue(value) => Other::NumberValue(value),
+                    Kind::StringValue(value) => Other::StringValue(value),
+                    Kind::BoolValue(value) => Other::BoolValue(value),
+                    Kind::StructValue(value) => Other::StructValue(value.into()),
+                    Kind::ListValue(value) => Other::ListValue(value.into()),
+                });
+
+                ::prost_types::Value { kind }
+            }
+        }
+    }
+
+    /// Proto3 canonical JSON representation of `Timestamp` and `Duration`, which are
+    /// respectively (de)serialized as an RFC 3339 string, eg. `"1972-01-01T10:00:20.021Z"`,
+    /// and as a number of seconds suffixed with `s`, eg. `"1.000340012s"`.
+    #[cfg(feature = "json")]
+    mod json {
+        use super::{
+            Duration, Timestamp, MAX_DURATION_SECONDS, MAX_TIMESTAMP_SECONDS, MIN_TIMESTAMP_SECONDS,
+        };
+
+        use alloc::format;
+        use alloc::string::String;
+
+        use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+        const SECONDS_PER_DAY: i64 = 86_400;
+
+        impl Serialize for Timestamp {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut timestamp = self.clone();
+                timestamp.normalize();
+
+                if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&timestamp.seconds) {
+                    return Err(ser::Error::custom(format!(
+                        "{timestamp:?} is out of the range of RFC 3339 timestamps"
+                    )));
+                }
+
+                let (year, month, day) =
+                    civil_from_days(timestamp.seconds.div_euclid(SECONDS_PER_DAY));
+                let seconds = timestamp.seconds.rem_euclid(SECONDS_PER_DAY);
+
+                serializer.serialize_str(&format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+                    year,
+                    month,
+                    day,
+                    seconds / 3600,
+                    seconds / 60 % 60,
+                    seconds % 60,
+                    format_nanos(timestamp.nanos.unsigned_abs()),
+                ))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Timestamp {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+
+                parse_timestamp(&value).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(&value), &"an RFC 3339 timestamp")
+                })
+            }
+        }
+
+        impl Serialize for Duration {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut duration = self.clone();
+                duration.normalize();
+
+                if duration.seconds.unsigned_abs() > MAX_DURATION_SECONDS {
+                    return Err(ser::Error::custom(format!(
+                        "{duration:?} is out of the range of JSON durations"
+                    )));
+                }
+
+                let sign = if duration.seconds < 0 || duration.nanos < 0 {
+                    "-"
+                } else {
+                    ""
+                };
+
+                serializer.serialize_str(&format!(
+                    "{}{}{}s",
+                    sign,
+                    duration.seconds.unsigned_abs(),
+                    format_nanos(duration.nanos.unsigned_abs()),
+                ))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Duration {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+
+                parse_duration(&value).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(&value), &"a duration in seconds")
+                })
+            }
+        }
+
+        /// Formats the fractional part of a second with 0, 3, 6 or 9 digits, as
+        /// recommended by the specification.
+        fn format_nanos(nanos: u32) -> String {
+            if nanos == 0 {
+                String::new()
+            } else if nanos.is_multiple_of(1_000_000) {
+                format!(".{:03}", nanos / 1_000_000)
+            } else if nanos.is_multiple_of(1_000) {
+                format!(".{:06}", nanos / 1_000)
+            } else {
+                format!(".{nanos:09}")
+            }
+        }
+
+        /// Parses the fractional part of a second, made of 1 to 9 digits.
+        fn parse_nanos(digits: &str) -> Option<i32> {
+            if digits.is_empty() || digits.len() > 9 {
+                return None;
+            }
+
+            let value = parse_digits(digits)?;
+            Some(value as i32 * 10_i32.pow(9 - digits.len() as u32))
+        }
+
+        fn parse_digits(digits: &str) -> Option<u32> {
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+
+            digits.parse().ok()
+        }
+
+        fn parse_timestamp(value: &str) -> Option<Timestamp> {
+            let bytes = value.as_bytes();
+            if bytes.len() < 20
+                || bytes[4] != b'-'
+                || bytes[7] != b'-'
+                || !matches!(bytes[10], b'T' | b't')
+                || bytes[13] != b':'
+                || bytes[16] != b':'
+            {
+                return None;
+            }
+
+            let field = |range: core::ops::Range<usize>| value.get(range).and_then(parse_digits);
+            let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+            let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+
+            if !(1..=12).contains(&month)
+                || day == 0
+                || day > days_in_month(year, month)
+                || hour > 23
+                || minute > 59
+                || second > 59
+            {
+                return None;
+            }
+
+            let mut rest = &value[19..];
+            let mut nanos = 0;
+            if let Some(fraction) = rest.strip_prefix('.') {
+                let end = fraction
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(fraction.len());
+                nanos = parse_nanos(&fraction[..end])?;
+                rest = &fraction[end..];
+            }
+
+            let offset = match rest {
+                "Z" | "z" => 0,
+                _ => {
+                    let sign = match rest.as_bytes().first()? {
+                        b'+' => 1,
+                        b'-' => -1,
+                        _ => return None,
+                    };
+                    if rest.len() != 6 || rest.as_bytes()[3] != b':' {
+                        return None;
+                    }
+                    let hours = rest.get(1..3).and_then(parse_digits)?;
+                    let minutes = rest.get(4..6).and_then(parse_digits)?;
+                    if hours > 23 || minutes > 59 {
+                        return None;
+                    }
+                    sign * (i64::from(hours) * 3600 + i64::from(minutes) * 60)
+                }
+            };
+
+            let seconds = days_from_civil(i64::from(year), month, day) * SECONDS_PER_DAY
+                + i64::from(hour) * 3600
+                + i64::from(minute) * 60
+                + i64::from(second)
+                - offset;
+
+            if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&seconds) {
+                return None;
+            }
+
+            Some(Timestamp { seconds, nanos })
+        }
+
+        fn parse_duration(value: &str) -> Option<Duration> {
+            let value = value.strip_suffix('s')?;
+            let (negative, value) = match value.strip_prefix('-') {
+                Some(value) => (true, value),
+                None => (false, value),
+            };
+            let (seconds, nanos) = match value.split_once('.') {
+                Some((seconds, fraction)) => (seconds, parse_nanos(fraction)?),
+                None => (value, 0),
+            };
+
+            let seconds: u64 = if seconds.bytes().all(|b| b.is_ascii_digit()) {
+                seconds.parse().ok()?
+            } else {
+                return None;
+            };
+            if seconds > MAX_DURATION_SECONDS {
+                return None;
+            }
+
+            let seconds = seconds as i64;
+            Some(if negative {
+                Duration {
+                    seconds: -seconds,
+                    nanos: -nanos,
+                }
+            } else {
+                Duration { seconds, nanos }
+            })
+        }
+
+        fn days_in_month(year: u32, month: u32) -> u32 {
+            let leap_year =
+                year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+            match month {
+                2 if leap_year => 29,
+                2 => 28,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            }
+        }
+
+        // Conversions between days since the Unix epoch and dates of the proleptic Gregorian
+        // calendar, source: https://howardhinnant.github.io/date_algorithms.html
+        fn civil_from_days(days: i64) -> (i64, i64, i64) {
+            let z = days + 719_468;
+            let era = z.div_euclid(146_097);
+            let doe = z.rem_euclid(146_097);
+            let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let mp = (5 * doy + 2) / 153;
+            let day = doy - (153 * mp + 2) / 5 + 1;
+            let month = if mp < 10 { mp + 3 } else { mp - 9 };
+            let year = yoe + era * 400 + i64::from(month <= 2);
+            (year, month, day)
+        }
+
+        fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+            let year = if month <= 2 { year - 1 } else { year };
+            let era = year.div_euclid(400);
+            let yoe = year.rem_euclid(400);
+            let month = i64::from(month);
+            let mp = if month > 2 { month - 3 } else { month + 9 };
+            let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+            let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+            era * 146_097 + doe - 719_468
+        }
+
+        #[cfg(test)]
+        mod test {
+            use super::*;
+
+            fn timestamp(seconds: i64, nanos: i32) -> Timestamp {
+                Timestamp { seconds, nanos }
+            }
+
+            fn to_json<T: Serialize>(value: &T) -> String {
+                serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+            }
+
+            #[test]
+            fn test_civil_from_days() {
+                assert_eq!(civil_from_days(0), (1970, 1, 1));
+                assert_eq!(civil_from_days(-1), (1969, 12, 31));
+                assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+                assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+                assert_eq!(civil_from_days(-719_162), (1, 1, 1));
+                assert_eq!(civil_from_days(2_932_896), (9999, 12, 31));
+
+                for days in -719_162..=2_932_896 {
+                    let (year, month, day) = civil_from_days(days);
+                    assert!(day as u32 <= days_in_month(year as u32, month as u32));
+                    assert_eq!(days_from_civil(year, month as u32, day as u32), days);
+                }
+            }
+
+            #[test]
+            fn test_days_in_month() {
+                assert_eq!(days_in_month(2000, 2), 29);
+                assert_eq!(days_in_month(2024, 2), 29);
+                assert_eq!(days_in_month(1900, 2), 28);
+                assert_eq!(days_in_month(2023, 2), 28);
+                assert_eq!(days_in_month(2023, 4), 30);
+                assert_eq!(days_in_month(2023, 12), 31);
+            }
+
+            #[test]
+            fn test_timestamp_json() {
+                assert_eq!(to_json(&timestamp(0, 0)), "1970-01-01T00:00:00Z");
+                assert_eq!(to_json(&timestamp(951_825_600, 0)), "2000-02-29T12:00:00Z");
+                assert_eq!(to_json(&timestamp(-1, 0)), "1969-12-31T23:59:59Z");
+                assert_eq!(
+                    to_json(&timestamp(MIN_TIMESTAMP_SECONDS, 0)),
+                    "0001-01-01T00:00:00Z"
+                );
+                assert_eq!(
+                    to_json(&timestamp(MAX_TIMESTAMP_SECONDS, 999_999_999)),
+                    "9999-12-31T23:59:59.999999999Z"
+                );
+
+                // The fractional part is given with 3, 6 or 9 digits.
+                assert_eq!(
+                    to_json(&timestamp(0, 500_000_000)),
+                    "1970-01-01T00:00:00.500Z"
+                );
+                assert_eq!(to_json(&timestamp(0, 1_000)), "1970-01-01T00:00:00.000001Z");
+                assert_eq!(to_json(&timestamp(0, 1)), "1970-01-01T00:00:00.000000001Z");
+
+                // The timestamp is normalized first.
+                assert_eq!(
+                    to_json(&timestamp(1, -500_000_000)),
+                    "1970-01-01T00:00:00.500Z"
+                );
+
+                assert!(serde_json::to_string(&timestamp(MIN_TIMESTAMP_SECONDS - 1, 0)).is_err());
+                assert!(serde_json::to_string(&timestamp(MAX_TIMESTAMP_SECONDS + 1, 0)).is_err());
+
+                for seconds in [
+                    MIN_TIMESTAMP_SECONDS,
+                    -1,
+                    0,
+                    951_825_600,
+                    MAX_TIMESTAMP_SECONDS,
+                ] {
+                    let value = timestamp(seconds, 123_456_789);
+                    let json = serde_json::to_string(&value).unwrap();
+                    assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), value);
+                }
+            }
+
+            #[test]
+            fn test_parse_timestamp() {
+                assert_eq!(
+                    parse_timestamp("1970-01-01T00:00:00Z"),
+                    Some(timestamp(0, 0))
+                );
+                assert_eq!(
+                    parse_timestamp("1970-01-01t00:00:00z"),
+                    Some(timestamp(0, 0))
+                );
+
+                // The end of February.
+                assert_eq!(
+                    parse_timestamp("2000-02-29T12:00:00Z"),
+                    Some(timestamp(951_825_600, 0))
+                );
+                assert_eq!(
+                    parse_timestamp("2024-02-29T00:00:00Z"),
+                    Some(timestamp(1_709_164_800, 0))
+                );
+                assert_eq!(parse_timestamp("2023-02-29T00:00:00Z"), None);
+                assert_eq!(parse_timestamp("1900-02-29T00:00:00Z"), None);
+                assert_eq!(
+                    parse_timestamp("2023-02-28T23:59:59Z").unwrap().seconds + 1,
+                    parse_timestamp("2023-03-01T00:00:00Z").unwrap().seconds
+                );
+
+                // The offsets.
+                assert_eq!(
+                    parse_timestamp("1970-01-01T01:30:00+01:30"),
+                    Some(timestamp(0, 0))
+                );
+                assert_eq!(
+                    parse_timestamp("1969-12-31T22:00:00-02:00"),
+                    Some(timestamp(0, 0))
+                );
+                assert_eq!(
+                    parse_timestamp("1970-01-01T00:00:00+00:00"),
+                    Some(timestamp(0, 0))
+                );
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00+24:00"), None);
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00+01:60"), None);
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00+0100"), None);
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00"), None);
+
+                // The fractional part, of 1 to 9 digits.
+                let fractions = [
+                    ("1", 100_000_000),
+                    ("12", 120_000_000),
+                    ("123", 123_000_000),
+                    ("1234", 123_400_000),
+                    ("12345", 123_450_000),
+                    ("123456", 123_456_000),
+                    ("1234567", 123_456_700),
+                    ("12345678", 123_456_780),
+                    ("123456789", 123_456_789),
+                ];
+                for (digits, nanos) in fractions {
+                    assert_eq!(
+                        parse_timestamp(&format!("1970-01-01T00:00:00.{digits}Z")),
+                        Some(timestamp(0, nanos))
+                    );
+                }
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00.1234567890Z"), None);
+                assert_eq!(parse_timestamp("1970-01-01T00:00:00.Z"), None);
+
+                // The bounds.
+                assert_eq!(
+                    parse_timestamp("0001-01-01T00:00:00Z"),
+                    Some(timestamp(MIN_TIMESTAMP_SECONDS, 0))
+                );
+                assert_eq!(
+                    parse_timestamp("9999-12-31T23:59:59.999999999Z"),
+                    Some(timestamp(MAX_TIMESTAMP_SECONDS, 999_999_999))
+                );
+                assert_eq!(parse_timestamp("0001-01-01T00:00:00+00:01"), None);
+                assert_eq!(parse_timestamp("9999-12-31T23:59:59-00:01"), None);
+                assert_eq!(parse_timestamp("0000-12-31T23:59:59Z"), None);
+
+                // The out-of-range fields.
+                for value in [
+                    "1970-00-01T00:00:00Z",
+                    "1970-13-01T00:00:00Z",
+                    "1970-01-00T00:00:00Z",
+                    "1970-01-32T00:00:00Z",
+                    "1970-04-31T00:00:00Z",
+                    "1970-01-01T24:00:00Z",
+                    "1970-01-01T00:60:00Z",
+                    "1970-01-01T00:00:60Z",
+                    "1970-01-01 00:00:00Z",
+                    "+970-01-01T00:00:00Z",
+                    "1970-1-01T00:00:00Z",
+                    "",
+                ] {
+                    assert_eq!(parse_timestamp(value), None, "{value}");
+                    let json = serde_json::to_string(value).unwrap();
+                    assert!(serde_json::from_str::<Timestamp>(&json).is_err());
+                }
+            }
+
+            #[test]
+            fn test_duration_json() {
+                let duration = |seconds, nanos| Duration { seconds, nanos };
+
+                assert_eq!(to_json(&duration(0, 0)), "0s");
+                assert_eq!(to_json(&duration(1, 500_000_000)), "1.500s");
+                assert_eq!(to_json(&duration(-1, -500_000_000)), "-1.500s");
+                assert_eq!(to_json(&duration(0, -500_000_000)), "-0.500s");
+                assert_eq!(to_json(&duration(0, 1_000)), "0.000001s");
+                assert_eq!(to_json(&duration(0, 1)), "0.000000001s");
+                assert_eq!(to_json(&duration(1, -500_000_000)), "0.500s");
+                assert_eq!(
+                    to_json(&duration(-(MAX_DURATION_SECONDS as i64), 0)),
+                    "-315576000000s"
+                );
+                assert!(
+                    serde_json::to_string(&duration(MAX_DURATION_SECONDS as i64 + 1, 0)).is_err()
+                );
+
+                for value in [
+                    duration(0, 0),
+                    duration(1, 1),
+                    duration(-1, -1),
+                    duration(0, -999_999_999),
+                    duration(MAX_DURATION_SECONDS as i64, 999_999_999),
+                ] {
+                    let json = serde_json::to_string(&value).unwrap();
+                    assert_eq!(serde_json::from_str::<Duration>(&json).unwrap(), value);
+                }
+            }
+
+            #[test]
+            fn test_parse_duration() {
+                let duration = |seconds, nanos| Some(Duration { seconds, nanos });
+
+                assert_eq!(parse_duration("0s"), duration(0, 0));
+                assert_eq!(parse_duration("1.5s"), duration(1, 500_000_000));
+                assert_eq!(parse_duration("-1.5s"), duration(-1, -500_000_000));
+                assert_eq!(parse_duration("-0.5s"), duration(0, -500_000_000));
+                assert_eq!(parse_duration("1.000000001s"), duration(1, 1));
+                assert_eq!(parse_duration("0.1s"), duration(0, 100_000_000));
+                assert_eq!(
+                    parse_duration("315576000000.999999999s"),
+                    duration(315_576_000_000, 999_999_999)
+                );
+                assert_eq!(
+                    parse_duration("-315576000000s"),
+                    duration(-315_576_000_000, 0)
+                );
+
+                for value in [
+                    "315576000001s",
+                    "-315576000001s",
+                    "1.0000000001s",
+                    "1.s",
+                    ".5s",
+                    "1",
+                    "+1s",
+                    "--1s",
+                    "1.5.5s",
+                    "1e3s",
+                    "s",
+                    "",
+                ] {
+                    assert_eq!(parse_duration(value), None, "{value}");
+                }
+            }
+        }
+    }
+
+    /// Proto3 canonical JSON representation of the other well-known types: `Struct`,
+    /// `ListValue` and `Value` as the JSON objects, arrays and values they hold, `NullValue`
+    /// as `null`, `Empty` as `{}`, `FieldMask` as its comma-separated paths in lower camel
+    /// case, eg. `"user.displayName,photo"`, and the wrappers as the value they wrap, eg.
+    /// `"1"` for an `Int64Value`, whose 64-bit integer is given as a string.
+    #[cfg(feature = "json")]
+    mod json_values {
+        use super::value::Kind;
+        use super::{
+            BoolValue, BytesValue, DoubleValue, Empty, FieldMask, FloatValue, Int32Value,
+            Int64Value, ListValue, NullValue, StringValue, Struct, UInt32Value, UInt64Value, Value,
+        };
+
+        use alloc::collections::BTreeMap;
+        use alloc::format;
+        use alloc::string::{String, ToString};
+        use alloc::vec::Vec;
+        use core::fmt::{self, Display};
+        use core::str::FromStr;
+
+        use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+        use serde::ser::{self, SerializeMap};
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        impl Serialize for Struct {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.fields.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Struct {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self {
+                    fields: BTreeMap::deserialize(deserializer)?,
+                })
+            }
+        }
+
+        impl Serialize for ListValue {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.values.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for ListValue {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self {
+                    values: Vec::deserialize(deserializer)?,
+                })
+            }
+        }
+
+        impl Serialize for Value {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match &self.kind {
+                    None | Some(Kind::NullValue(_)) => serializer.serialize_unit(),
+                    Some(Kind::NumberValue(number)) if !number.is_finite() => Err(
+                        ser::Error::custom(format!("{number} cannot be represented in JSON")),
+                    ),
+                    Some(Kind::NumberValue(number)) => serializer.serialize_f64(*number),
+                    Some(Kind::StringValue(string)) => serializer.serialize_str(string),
+                    Some(Kind::BoolValue(value)) => serializer.serialize_bool(*value),
+                    Some(Kind::StructValue(value)) => value.serialize(serializer),
+                    Some(Kind::ListValue(value)) => value.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Value {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(ValueVisitor)
+            }
+        }
+
+        struct ValueVisitor;
+
+        impl ValueVisitor {
+            fn value(kind: Kind) -> Value {
+                Value { kind: Some(kind) }
+            }
+        }
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a JSON value")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+                Ok(Self::value(Kind::NullValue(NullValue::NullValue as i32)))
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+                self.visit_unit()
+            }
+
+            fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+                Value::deserialize(deserializer)
+            }
+
+            fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+                Ok(Self::value(Kind::BoolValue(value)))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+                Ok(Self::value(Kind::NumberValue(value as f64)))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+                Ok(Self::value(Kind::NumberValue(value as f64)))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+                Ok(Self::value(Kind::NumberValue(value)))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+                self.visit_string(value.to_string())
+            }
+
+            fn visit_string<E: de::Error>(self, value: String) -> Result<Value, E> {
+                Ok(Self::value(Kind::StringValue(value)))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+                let mut values = Vec::new();
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+
+                Ok(Self::value(Kind::ListValue(ListValue { values })))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+                let mut fields = BTreeMap::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    fields.insert(key, value);
+                }
+
+                Ok(Self::value(Kind::StructValue(Struct { fields })))
+            }
+        }
+
+        impl Serialize for NullValue {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_unit()
+            }
+        }
+
+        /// Deserializes `null`, or the `NULL_VALUE` name of the value.
+        impl<'de> Deserialize<'de> for NullValue {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                match Option::<String>::deserialize(deserializer)? {
+                    None => Ok(NullValue::NullValue),
+                    Some(name) => NullValue::from_str_name(&name).ok_or_else(|| {
+                        de::Error::invalid_value(de::Unexpected::Str(&name), &"null")
+                    }),
+                }
+            }
+        }
+
+        impl Serialize for Empty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_map(Some(0))?.end()
+            }
+        }
+
+        /// Deserializes any JSON object, ignoring its fields as the other messages ignore
+        /// their unknown fields.
+        impl<'de> Deserialize<'de> for Empty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                BTreeMap::<String, IgnoredAny>::deserialize(deserializer)?;
+                Ok(Empty {})
+            }
+        }
+
+        impl Serialize for FieldMask {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut paths = Vec::with_capacity(self.paths.len());
+                for path in &self.paths {
+                    if path.contains(|c: char| c.is_ascii_uppercase() || c == ',') {
+                        return Err(ser::Error::custom(format!(
+                            "field mask path `{path}` cannot be represented in JSON"
+                        )));
+                    }
+                    paths.push(to_lower_camel_case(path));
+                }
+
+                serializer.serialize_str(&paths.join(","))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for FieldMask {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                let paths = value
+                    .split(',')
+                    .filter(|path| !path.is_empty())
+                    .map(to_snake_case)
+                    .collect();
+
+                Ok(FieldMask { paths })
+            }
+        }
+
+        /// Converts the names of the fields of a path to lower camel case, eg.
+        /// `user.display_name` to `user.displayName`.
+        fn to_lower_camel_case(path: &str) -> String {
+            let mut camel = String::with_capacity(path.len());
+            let mut upper = false;
+            for c in path.chars() {
+                match c {
+                    '_' => upper = true,
+                    c if upper => {
+                        camel.push(c.to_ascii_uppercase());
+                        upper = false;
+                    }
+                    c => camel.push(c),
+                }
+            }
+            camel
+        }
+
+        /// Converts the names of the fields of a path to snake case, eg. `user.displayName`
+        /// to `user.display_name`.
+        fn to_snake_case(path: &str) -> String {
+            let mut snake = String::with_capacity(path.len());
+            for c in path.chars() {
+                if c.is_ascii_uppercase() {
+                    snake.push('_');
+                }
+                snake.push(c.to_ascii_lowercase());
+            }
+            snake
+        }
+
+        /// Implements the JSON representation of the wrappers as the one of their value,
+        /// serialized and deserialized with the given functions.
+        macro_rules! impl_wrapper {
+            ($($ty:ident => $serialize:path, $deserialize:path;)*) => {
+                $(
+                    impl Serialize for $ty {
+                        fn serialize<S: Serializer>(
+                            &self,
+                            serializer: S,
+                        ) -> Result<S::Ok, S::Error> {
+                            $serialize(&self.value, serializer)
+                        }
+                    }
+
+                    impl<'de> Deserialize<'de> for $ty {
+                        fn deserialize<D: Deserializer<'de>>(
+                            deserializer: D,
+                        ) -> Result<Self, D::Error> {
+                            Ok(Self {
+                                value: $deserialize(deserializer)?,
+                            })
+                        }
+                    }
+                )*
+            };
+        }
+
+        impl_wrapper! {
+            DoubleValue => serialize_f64, deserialize_number;
+            FloatValue => serialize_f32, deserialize_number;
+            Int64Value => serialize_string, deserialize_number;
+            UInt64Value => serialize_string, deserialize_number;
+            Int32Value => Serialize::serialize, deserialize_number;
+            UInt32Value => Serialize::serialize, deserialize_number;
+            BoolValue => Serialize::serialize, Deserialize::deserialize;
+            StringValue => Serialize::serialize, Deserialize::deserialize;
+            BytesValue => serialize_base64, deserialize_base64;
+        }
+
+        /// Serializes the non-finite numbers as the `NaN`, `Infinity` and `-Infinity`
+        /// strings, as the specification requires.
+        fn serialize_f64<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+            match non_finite(*value) {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_f64(*value),
+            }
+        }
+
+        fn serialize_f32<S: Serializer>(value: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+            match non_finite(f64::from(*value)) {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_f32(*value),
+            }
+        }
+
+        fn non_finite(value: f64) -> Option<&'static str> {
+            if value.is_nan() {
+                Some("NaN")
+            } else if value == f64::INFINITY {
+                Some("Infinity")
+            } else if value == f64::NEG_INFINITY {
+                Some("-Infinity")
+            } else {
+                None
+            }
+        }
+
+        fn serialize_string<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Display,
+            S: Serializer,
+        {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        fn serialize_base64<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&pbjson::private::base64::encode(value))
+        }
+
+        /// Deserializes a number, given either as a JSON number or as a string.
+        fn deserialize_number<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: FromStr + Deserialize<'de>,
+            <T as FromStr>::Err: std::error::Error,
+            D: Deserializer<'de>,
+        {
+            let number = pbjson::private::NumberDeserialize::<T>::deserialize(deserializer)?;
+            Ok(number.0)
+        }
+
+        /// Deserializes bytes given as a string in standard or URL-safe base64.
+        fn deserialize_base64<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            let bytes = pbjson::private::BytesDeserialize::<Vec<u8>>::deserialize(deserializer)?;
+            Ok(bytes.0)
+        }
+
+        #[cfg(test)]
+        mod test {
+            use super::*;
+
+            use alloc::vec;
+
+            #[test]
+            fn test_int64_value_json_round_trip() {
+                let value = Int64Value { value: -42 };
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(json, r#""-42""#);
+                assert_eq!(serde_json::from_str::<Int64Value>(&json).unwrap(), value);
+                assert_eq!(serde_json::from_str::<Int64Value>("-42").unwrap(), value);
+
+                let value = UInt64Value { value: u64::MAX };
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(json, r#""18446744073709551615""#);
+                assert_eq!(serde_json::from_str::<UInt64Value>(&json).unwrap(), value);
+
+                assert!(serde_json::from_str::<Int64Value>(r#""1.5""#).is_err());
+                assert!(serde_json::from_str::<UInt64Value>(r#""-1""#).is_err());
+            }
+
+            #[test]
+            fn test_int32_and_float_values_json_round_trip() {
+                let value = Int32Value { value: 7 };
+                assert_eq!(serde_json::to_string(&value).unwrap(), "7");
+                assert_eq!(serde_json::from_str::<Int32Value>(r#""7""#).unwrap(), value);
+
+                let value = DoubleValue { value: 1.5 };
+                assert_eq!(serde_json::to_string(&value).unwrap(), "1.5");
+                assert_eq!(serde_json::from_str::<DoubleValue>("1.5").unwrap(), value);
+
+                let value = DoubleValue {
+                    value: f64::NEG_INFINITY,
+                };
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(json, r#""-Infinity""#);
+                assert_eq!(serde_json::from_str::<DoubleValue>(&json).unwrap(), value);
+
+                let json = serde_json::to_string(&FloatValue { value: f32::NAN }).unwrap();
+                assert_eq!(json, r#""NaN""#);
+                assert!(serde_json::from_str::<FloatValue>(&json)
+                    .unwrap()
+                    .value
+                    .is_nan());
+            }
+
+            #[test]
+            fn test_bytes_value_json_round_trip() {
+                let value = BytesValue {
+                    value: vec![0x01, 0x02, 0xfb, 0xff],
+                };
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(json, r#""AQL7/w==""#);
+                assert_eq!(serde_json::from_str::<BytesValue>(&json).unwrap(), value);
+                assert_eq!(
+                    serde_json::from_str::<BytesValue>(r#""AQL7_w==""#).unwrap(),
+                    value
+                );
+
+                assert!(serde_json::from_str::<BytesValue>(r#""not base64!""#).is_err());
+            }
+
+            #[test]
+            fn test_bool_and_string_values_json_round_trip() {
+                let value = BoolValue { value: true };
+                assert_eq!(serde_json::to_string(&value).unwrap(), "true");
+                assert_eq!(serde_json::from_str::<BoolValue>("true").unwrap(), value);
+
+                let value = StringValue {
+                    value: "ibc".to_string(),
+                };
+                assert_eq!(serde_json::to_string(&value).unwrap(), r#""ibc""#);
+                assert_eq!(
+                    serde_json::from_str::<StringValue>(r#""ibc""#).unwrap(),
+                    value
+                );
+            }
+        }
+    }
+
     #[cfg(any(feature = "borsh", feature = "parity-scale-codec"))]
     mod sealed {
         use super::Any;
@@ -321,4 +1613,305 @@ pub mod protobuf {
             }
         }
     }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_duration_is_valid() {
+            let duration = |seconds, nanos| Duration { seconds, nanos };
+
+            assert!(duration(0, 0).is_valid());
+            assert!(duration(1, 999_999_999).is_valid());
+            assert!(duration(-1, -999_999_999).is_valid());
+            assert!(duration(0, -1).is_valid());
+            assert!(duration(MAX_DURATION_SECONDS as i64, NANOS_MAX).is_valid());
+            assert!(duration(-(MAX_DURATION_SECONDS as i64), -NANOS_MAX).is_valid());
+
+            assert!(!duration(MAX_DURATION_SECONDS as i64 + 1, 0).is_valid());
+            assert!(!duration(-(MAX_DURATION_SECONDS as i64) - 1, 0).is_valid());
+            assert!(!duration(0, NANOS_PER_SECOND).is_valid());
+            assert!(!duration(0, -NANOS_PER_SECOND).is_valid());
+            assert!(!duration(1, -1).is_valid());
+            assert!(!duration(-1, 1).is_valid());
+            assert!(!duration(i64::MIN, 0).is_valid());
+        }
+
+        #[test]
+        fn test_duration_std_conversions() {
+            let std_duration = time::Duration::new(1, 500_000_000);
+            let duration = Duration::try_from_std(std_duration).unwrap();
+            assert_eq!(
+                duration,
+                Duration {
+                    seconds: 1,
+                    nanos: 500_000_000
+                }
+            );
+            assert_eq!(duration.try_into_std().unwrap(), std_duration);
+
+            let max = time::Duration::new(MAX_DURATION_SECONDS, NANOS_MAX as u32);
+            assert_eq!(
+                Duration::try_from_std(max).unwrap().try_into_std().unwrap(),
+                max
+            );
+            assert!(matches!(
+                Duration::try_from_std(time::Duration::from_secs(MAX_DURATION_SECONDS + 1)),
+                Err(DurationConversionError::OutOfRange(_))
+            ));
+            assert!(matches!(
+                Duration::try_from_std(time::Duration::MAX),
+                Err(DurationConversionError::OutOfRange(_))
+            ));
+
+            assert!(matches!(
+                Duration {
+                    seconds: -1,
+                    nanos: 0
+                }
+                .try_into_std(),
+                Err(DurationConversionError::Negative(_))
+            ));
+            assert!(matches!(
+                Duration {
+                    seconds: 0,
+                    nanos: -1
+                }
+                .try_into_std(),
+                Err(DurationConversionError::Negative(_))
+            ));
+            // Not normalized, unlike with the `TryFrom` conversion.
+            let duration = Duration {
+                seconds: 0,
+                nanos: NANOS_PER_SECOND,
+            };
+            assert!(matches!(
+                duration.clone().try_into_std(),
+                Err(DurationConversionError::Invalid(_))
+            ));
+            assert_eq!(
+                time::Duration::try_from(duration).unwrap(),
+                time::Duration::from_secs(1)
+            );
+            assert!(matches!(
+                Duration {
+                    seconds: MAX_DURATION_SECONDS as i64 + 1,
+                    nanos: 0
+                }
+                .try_into_std(),
+                Err(DurationConversionError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn test_timestamp_is_valid() {
+            let timestamp = |seconds, nanos| Timestamp { seconds, nanos };
+
+            assert!(timestamp(0, 0).is_valid());
+            assert!(timestamp(MIN_TIMESTAMP_SECONDS, 0).is_valid());
+            assert!(timestamp(MAX_TIMESTAMP_SECONDS, NANOS_MAX).is_valid());
+            assert!(!timestamp(MIN_TIMESTAMP_SECONDS - 1, 0).is_valid());
+            assert!(!timestamp(MAX_TIMESTAMP_SECONDS + 1, 0).is_valid());
+            assert!(!timestamp(0, -1).is_valid());
+            assert!(!timestamp(0, NANOS_PER_SECOND).is_valid());
+        }
+
+        #[cfg(feature = "time")]
+        #[test]
+        fn test_timestamp_time_conversions() {
+            use ::time::{Date, Month, OffsetDateTime, UtcOffset};
+
+            let datetime = |year, month, day, (hour, minute, second, nanos), offset| {
+                Date::from_calendar_date(year, month, day)
+                    .unwrap()
+                    .with_hms_nano(hour, minute, second, nanos)
+                    .unwrap()
+                    .assume_offset(UtcOffset::from_hms(offset, 0, 0).unwrap())
+            };
+
+            let cases = [
+                (
+                    Timestamp {
+                        seconds: 0,
+                        nanos: 0,
+                    },
+                    OffsetDateTime::UNIX_EPOCH,
+                ),
+                (
+                    Timestamp {
+                        seconds: -1,
+                        nanos: 999_999_999,
+                    },
+                    datetime(1969, Month::December, 31, (23, 59, 59, 999_999_999), 0),
+                ),
+                (
+                    Timestamp {
+                        seconds: MIN_TIMESTAMP_SECONDS,
+                        nanos: 0,
+                    },
+                    datetime(1, Month::January, 1, (0, 0, 0, 0), 0),
+                ),
+                (
+                    Timestamp {
+                        seconds: MAX_TIMESTAMP_SECONDS,
+                        nanos: NANOS_MAX,
+                    },
+                    datetime(9999, Month::December, 31, (23, 59, 59, 999_999_999), 0),
+                ),
+            ];
+            for (timestamp, date_time) in cases {
+                assert_eq!(
+                    OffsetDateTime::try_from(timestamp.clone()).unwrap(),
+                    date_time
+                );
+                assert_eq!(Timestamp::try_from(date_time).unwrap(), timestamp);
+            }
+
+            for timestamp in [
+                Timestamp {
+                    seconds: MIN_TIMESTAMP_SECONDS - 1,
+                    nanos: 0,
+                },
+                Timestamp {
+                    seconds: MAX_TIMESTAMP_SECONDS + 1,
+                    nanos: 0,
+                },
+                Timestamp {
+                    seconds: 0,
+                    nanos: -1,
+                },
+            ] {
+                assert_eq!(
+                    OffsetDateTime::try_from(timestamp.clone())
+                        .unwrap_err()
+                        .timestamp,
+                    timestamp
+                );
+            }
+
+            // The offset is not kept, but the instant is.
+            let date_time = datetime(1, Month::January, 1, (0, 30, 0, 0), 1);
+            let err = Timestamp::try_from(date_time).unwrap_err();
+            assert_eq!(err.timestamp.seconds, MIN_TIMESTAMP_SECONDS - 1_800);
+            assert_eq!(
+                Timestamp::try_from(datetime(1970, Month::January, 1, (1, 0, 0, 0), 1)).unwrap(),
+                Timestamp::default()
+            );
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn test_timestamp_chrono_conversions() {
+            use ::chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+            let cases = [
+                (0, 0),
+                (-1, 999_999_999),
+                (MIN_TIMESTAMP_SECONDS, 0),
+                (MAX_TIMESTAMP_SECONDS, NANOS_MAX),
+            ];
+            for (seconds, nanos) in cases {
+                let timestamp = Timestamp { seconds, nanos };
+                let date_time = Utc.timestamp_opt(seconds, nanos as u32).unwrap();
+                assert_eq!(
+                    DateTime::<Utc>::try_from(timestamp.clone()).unwrap(),
+                    date_time
+                );
+                assert_eq!(Timestamp::try_from(date_time).unwrap(), timestamp);
+            }
+            assert_eq!(
+                DateTime::<Utc>::try_from(Timestamp {
+                    seconds: MIN_TIMESTAMP_SECONDS,
+                    nanos: 0,
+                })
+                .unwrap()
+                .date_naive(),
+                NaiveDate::from_ymd_opt(1, 1, 1).unwrap()
+            );
+
+            for timestamp in [
+                Timestamp {
+                    seconds: MIN_TIMESTAMP_SECONDS - 1,
+                    nanos: 0,
+                },
+                Timestamp {
+                    seconds: MAX_TIMESTAMP_SECONDS + 1,
+                    nanos: 0,
+                },
+                Timestamp {
+                    seconds: 0,
+                    nanos: NANOS_PER_SECOND,
+                },
+            ] {
+                assert!(DateTime::<Utc>::try_from(timestamp).is_err());
+            }
+
+            // Leap seconds and out-of-range dates are rejected.
+            let leap_second = Utc.timestamp_opt(1_483_228_799, 1_500_000_000).unwrap();
+            assert!(Timestamp::try_from(leap_second).is_err());
+            let date_time = Utc.timestamp_opt(MAX_TIMESTAMP_SECONDS + 1, 0).unwrap();
+            assert!(Timestamp::try_from(date_time).is_err());
+        }
+
+        #[test]
+        fn test_tendermint_timestamp_and_duration_conversions() {
+            let timestamp = Timestamp {
+                seconds: -62_135_596_800,
+                nanos: 999_999_999,
+            };
+            let tm_timestamp =
+                tendermint_proto::google::protobuf::Timestamp::from(timestamp.clone());
+            assert_eq!(tm_timestamp.seconds, timestamp.seconds);
+            assert_eq!(tm_timestamp.nanos, timestamp.nanos);
+            assert_eq!(Timestamp::from(tm_timestamp), timestamp);
+
+            let duration = Duration {
+                seconds: -1,
+                nanos: -500_000_000,
+            };
+            let tm_duration = tendermint_proto::google::protobuf::Duration::from(duration.clone());
+            assert_eq!(tm_duration.seconds, duration.seconds);
+            assert_eq!(tm_duration.nanos, duration.nanos);
+            assert_eq!(Duration::from(tm_duration), duration);
+        }
+
+        // The Tendermint types of the messages are those of `tendermint_proto`, which are
+        // then set without any conversion.
+        #[cfg(feature = "ibc-lightclients")]
+        #[test]
+        fn test_tendermint_types_are_those_of_tendermint_proto() {
+            use alloc::boxed::Box;
+
+            use prost::Message;
+
+            use crate::ibc::lightclients::tendermint::v1::Header;
+
+            let signed_header = tendermint_proto::types::SignedHeader {
+                header: Some(tendermint_proto::types::Header {
+                    chain_id: "chain-0".into(),
+                    height: 42,
+                    time: Some(
+                        Timestamp {
+                            seconds: 1,
+                            nanos: 2,
+                        }
+                        .into(),
+                    ),
+                    ..Default::default()
+                }),
+                commit: None,
+            };
+            let validator_set = tendermint_proto::types::ValidatorSet::default();
+            let header = Header {
+                signed_header: Some(Box::new(signed_header.clone())),
+                validator_set: Some(Box::new(validator_set.clone())),
+                trusted_height: None,
+                trusted_validators: Some(Box::new(validator_set)),
+            };
+
+            let decoded = Header::decode(header.encode_to_vec().as_slice()).unwrap();
+            assert_eq!(decoded.signed_header.as_deref(), Some(&signed_header));
+        }
+    }
 }
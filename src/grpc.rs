@@ -0,0 +1,385 @@
+//! Helpers for the `tonic` gRPC clients of the services,
+//! eg. `ibc::core::channel::v1::query_client::QueryClient`.
+//!
+//! The clients accept any [`Interceptor`] through their `with_interceptor` constructor,
+//! which is called on the metadata of every request, eg. to set an authentication token.
+//! [`QueryHeight`] is such an interceptor, which makes the queries at a given height
+//! of the chain rather than at its latest one:
+//!
+//! ```ignore
+//! let channel = Channel::from_static("http://localhost:9090").connect().await?;
+//! let mut client = QueryClient::with_interceptor(channel, QueryHeight(1035));
+//! ```
+//!
+//! With the `gzip` feature, the clients can also accept compressed responses, which
+//! shrinks those of the large queries, provided that the node supports it:
+//!
+//! ```ignore
+//! let mut client = QueryClient::new(channel).accept_compressed(CompressionEncoding::Gzip);
+//! ```
+//!
+//! The paginated queries are driven by [`paginate`], which yields the items of all the
+//! pages as a stream, eg. all the channels:
+//!
+//! ```ignore
+//! let channels = paginate(QueryChannelsRequest::default(), 100, |request| {
+//!     let mut client = client.clone();
+//!     async move { client.channels(request).await }
+//! });
+//! ```
+
+use alloc::vec::Vec;
+use core::future::Future;
+
+use futures_util::stream::{self, Stream, TryStreamExt};
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::{Request, Response, Status};
+
+use crate::cosmos::base::query::v1beta1::PageRequest;
+
+/// The metadata key of the height at which a query is made, as read by the gRPC
+/// server of the Cosmos SDK.
+pub const BLOCK_HEIGHT_METADATA_KEY: &str = "x-cosmos-block-height";
+
+/// An interceptor making the queries at the given height, rather than at the latest
+/// height of the chain. The height must not have been pruned by the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueryHeight(pub u64);
+
+impl Interceptor for QueryHeight {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        set_query_height(&mut request, self.0);
+        Ok(request)
+    }
+}
+
+/// Makes a single query at the given height, rather than at the latest height of the
+/// chain, eg. `client.client_state(at_height(request, 1035))`.
+pub fn at_height<T>(message: T, height: u64) -> Request<T> {
+    let mut request = Request::new(message);
+    set_query_height(&mut request, height);
+    request
+}
+
+fn set_query_height<T>(request: &mut Request<T>, height: u64) {
+    request
+        .metadata_mut()
+        .insert(BLOCK_HEIGHT_METADATA_KEY, MetadataValue::from(height));
+}
+
+/// A request of a paginated query, along with its response.
+pub trait PaginatedRequest: Clone {
+    /// The response to the query, holding a page of items.
+    type Response;
+    /// The items of the pages, eg. `IdentifiedChannel`.
+    type Item;
+
+    /// Sets the page to query.
+    fn set_pagination(&mut self, pagination: PageRequest);
+
+    /// Returns the items of the page, along with the key of the next page, which is
+    /// empty on the last page.
+    fn into_page(response: Self::Response) -> (Vec<Self::Item>, Vec<u8>);
+}
+
+/// Queries all the pages of a paginated query, of `limit` items each, and yields
+/// their items in order.
+///
+/// `query` is called with the given request for each page, with its pagination
+/// set, and stops on the first error, which is yielded as the last item. The pages
+/// also stop when the key of the next page is the one of the page just queried.
+pub fn paginate<R, F, Fut>(
+    request: R,
+    limit: u64,
+    query: F,
+) -> impl Stream<Item = Result<R::Item, Status>>
+where
+    R: PaginatedRequest,
+    F: FnMut(R) -> Fut,
+    Fut: Future<Output = Result<Response<R::Response>, Status>>,
+{
+    let pages = stream::try_unfold((query, Some(Vec::new())), move |(mut query, next_key)| {
+        let mut request = request.clone();
+        async move {
+            let key = match next_key {
+                Some(key) => key,
+                None => return Ok::<_, Status>(None),
+            };
+
+            request.set_pagination(PageRequest {
+                key: key.clone(),
+                limit,
+                ..Default::default()
+            });
+            let (items, next_key) = R::into_page(query(request).await?.into_inner());
+            // rather than querying the same page forever
+            let next_key = if next_key.is_empty() || next_key == key {
+                None
+            } else {
+                Some(next_key)
+            };
+
+            let items = stream::iter(items.into_iter().map(Ok::<_, Status>));
+            Ok(Some((items, (query, next_key))))
+        }
+    });
+
+    pages.try_flatten()
+}
+
+macro_rules! impl_paginated_request {
+    ($($(#[$meta:meta])* $request:path => $response:path, $items:ident: $item:path;)*) => {
+        $(
+            $(#[$meta])*
+            impl PaginatedRequest for $request {
+                type Response = $response;
+                type Item = $item;
+
+                fn set_pagination(&mut self, pagination: PageRequest) {
+                    self.pagination = Some(pagination);
+                }
+
+                fn into_page(response: Self::Response) -> (Vec<Self::Item>, Vec<u8>) {
+                    let next_key = response
+                        .pagination
+                        .map(|pagination| pagination.next_key)
+                        .unwrap_or_default();
+                    (response.$items, next_key)
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated_request! {
+    #[cfg(feature = "cosmos-auth")]
+    crate::cosmos::auth::v1beta1::QueryAccountsRequest
+        => crate::cosmos::auth::v1beta1::QueryAccountsResponse,
+        accounts: crate::google::protobuf::Any;
+    #[cfg(feature = "cosmos-bank")]
+    crate::cosmos::bank::v1beta1::QueryAllBalancesRequest
+        => crate::cosmos::bank::v1beta1::QueryAllBalancesResponse,
+        balances: crate::cosmos::base::v1beta1::Coin;
+    #[cfg(feature = "cosmos-bank")]
+    crate::cosmos::bank::v1beta1::QueryTotalSupplyRequest
+        => crate::cosmos::bank::v1beta1::QueryTotalSupplyResponse,
+        supply: crate::cosmos::base::v1beta1::Coin;
+    #[cfg(feature = "cosmos-bank")]
+    crate::cosmos::bank::v1beta1::QueryDenomsMetadataRequest
+        => crate::cosmos::bank::v1beta1::QueryDenomsMetadataResponse,
+        metadatas: crate::cosmos::bank::v1beta1::Metadata;
+    #[cfg(feature = "cosmos-gov")]
+    crate::cosmos::gov::v1::QueryProposalsRequest
+        => crate::cosmos::gov::v1::QueryProposalsResponse,
+        proposals: crate::cosmos::gov::v1::Proposal;
+    #[cfg(feature = "cosmos-staking")]
+    crate::cosmos::staking::v1beta1::QueryValidatorsRequest
+        => crate::cosmos::staking::v1beta1::QueryValidatorsResponse,
+        validators: crate::cosmos::staking::v1beta1::Validator;
+    #[cfg(feature = "cosmos-staking")]
+    crate::cosmos::staking::v1beta1::QueryValidatorDelegationsRequest
+        => crate::cosmos::staking::v1beta1::QueryValidatorDelegationsResponse,
+        delegation_responses: crate::cosmos::staking::v1beta1::DelegationResponse;
+    #[cfg(feature = "cosmos-staking")]
+    crate::cosmos::staking::v1beta1::QueryDelegatorDelegationsRequest
+        => crate::cosmos::staking::v1beta1::QueryDelegatorDelegationsResponse,
+        delegation_responses: crate::cosmos::staking::v1beta1::DelegationResponse;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::client::v1::QueryClientStatesRequest
+        => crate::ibc::core::client::v1::QueryClientStatesResponse,
+        client_states: crate::ibc::core::client::v1::IdentifiedClientState;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::client::v1::QueryConsensusStatesRequest
+        => crate::ibc::core::client::v1::QueryConsensusStatesResponse,
+        consensus_states: crate::ibc::core::client::v1::ConsensusStateWithHeight;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::client::v1::QueryConsensusStateHeightsRequest
+        => crate::ibc::core::client::v1::QueryConsensusStateHeightsResponse,
+        consensus_state_heights: crate::ibc::core::client::v1::Height;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::connection::v1::QueryConnectionsRequest
+        => crate::ibc::core::connection::v1::QueryConnectionsResponse,
+        connections: crate::ibc::core::connection::v1::IdentifiedConnection;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::channel::v1::QueryChannelsRequest
+        => crate::ibc::core::channel::v1::QueryChannelsResponse,
+        channels: crate::ibc::core::channel::v1::IdentifiedChannel;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::channel::v1::QueryConnectionChannelsRequest
+        => crate::ibc::core::channel::v1::QueryConnectionChannelsResponse,
+        channels: crate::ibc::core::channel::v1::IdentifiedChannel;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::channel::v1::QueryPacketCommitmentsRequest
+        => crate::ibc::core::channel::v1::QueryPacketCommitmentsResponse,
+        commitments: crate::ibc::core::channel::v1::PacketState;
+    #[cfg(feature = "ibc-core")]
+    crate::ibc::core::channel::v1::QueryPacketAcknowledgementsRequest
+        => crate::ibc::core::channel::v1::QueryPacketAcknowledgementsResponse,
+        acknowledgements: crate::ibc::core::channel::v1::PacketState;
+    #[cfg(feature = "ibc-transfer")]
+    crate::ibc::applications::transfer::v1::QueryDenomTracesRequest
+        => crate::ibc::applications::transfer::v1::QueryDenomTracesResponse,
+        denom_traces: crate::ibc::applications::transfer::v1::DenomTrace;
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::borrow::ToOwned;
+    use alloc::string::String;
+    use alloc::vec;
+    use core::future::ready;
+
+    use futures_util::{FutureExt, StreamExt};
+
+    use super::*;
+
+    #[derive(Clone, Debug, Default)]
+    struct FakeRequest {
+        pagination: Option<PageRequest>,
+    }
+
+    struct FakeResponse {
+        items: Vec<u32>,
+        next_key: Vec<u8>,
+    }
+
+    impl PaginatedRequest for FakeRequest {
+        type Response = FakeResponse;
+        type Item = u32;
+
+        fn set_pagination(&mut self, pagination: PageRequest) {
+            self.pagination = Some(pagination);
+        }
+
+        fn into_page(response: Self::Response) -> (Vec<Self::Item>, Vec<u8>) {
+            (response.items, response.next_key)
+        }
+    }
+
+    /// Answers the queries with the pages, given along with the key of the next page,
+    /// and returns the items, with the message of the errors, along with the keys of
+    /// the pages queried.
+    fn query_pages(
+        pages: &[(&[u8], Result<(Vec<u32>, &[u8]), Status>)],
+    ) -> (Vec<Result<u32, String>>, Vec<Vec<u8>>) {
+        let mut keys = Vec::new();
+        let items = paginate(FakeRequest::default(), 2, |request| {
+            let pagination = request.pagination.unwrap();
+            assert_eq!(pagination.limit, 2);
+            keys.push(pagination.key.clone());
+
+            let (_, page) = pages
+                .iter()
+                .find(|(key, _)| *key == pagination.key.as_slice())
+                .expect("unexpected page key");
+            ready(page.clone().map(|(items, next_key)| {
+                Response::new(FakeResponse {
+                    items,
+                    next_key: next_key.to_vec(),
+                })
+            }))
+        })
+        .map(|item| item.map_err(|status| status.message().to_owned()))
+        .collect::<Vec<_>>()
+        .now_or_never()
+        .unwrap();
+
+        (items, keys)
+    }
+
+    #[test]
+    fn test_query_height() {
+        let request = QueryHeight(1035).call(Request::new(())).unwrap();
+        assert_eq!(
+            request.metadata().get(BLOCK_HEIGHT_METADATA_KEY).unwrap(),
+            "1035"
+        );
+
+        // The height replaces the one already set.
+        let request = at_height((), 1);
+        let request = QueryHeight(u64::MAX).call(request).unwrap();
+        let heights: Vec<_> = request
+            .metadata()
+            .get_all("x-cosmos-block-height")
+            .iter()
+            .collect();
+        assert_eq!(heights, ["18446744073709551615"]);
+
+        let request = at_height(PageRequest::default(), 42);
+        assert_eq!(
+            request.metadata().get("x-cosmos-block-height").unwrap(),
+            "42"
+        );
+        assert_eq!(request.into_inner(), PageRequest::default());
+    }
+
+    #[test]
+    fn test_paginate() {
+        let (items, keys) = query_pages(&[
+            (b"", Ok((vec![1, 2], b"a"))),
+            (b"a", Ok((vec![3, 4], b"b"))),
+            (b"b", Ok((vec![5], b""))),
+        ]);
+        assert_eq!(items, [Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+        assert_eq!(keys, [b"".to_vec(), b"a".to_vec(), b"b".to_vec()]);
+    }
+
+    #[test]
+    fn test_paginate_repeated_key() {
+        // The page gives its own key as the key of the next page.
+        let (items, keys) = query_pages(&[
+            (b"", Ok((vec![1, 2], b"a"))),
+            (b"a", Ok((vec![3, 4], b"a"))),
+        ]);
+        assert_eq!(items, [Ok(1), Ok(2), Ok(3), Ok(4)]);
+        assert_eq!(keys, [b"".to_vec(), b"a".to_vec()]);
+    }
+
+    #[test]
+    fn test_paginate_error() {
+        let (items, keys) = query_pages(&[
+            (b"", Ok((vec![1, 2], b"a"))),
+            (b"a", Err(Status::unavailable("node is down"))),
+            (b"b", Ok((vec![5], b""))),
+        ]);
+        assert_eq!(items, [Ok(1), Ok(2), Err("node is down".to_owned())]);
+        assert_eq!(keys, [b"".to_vec(), b"a".to_vec()]);
+
+        let (items, _) = query_pages(&[(b"", Ok((vec![], b"")))]);
+        assert!(items.is_empty());
+    }
+
+    #[cfg(feature = "ibc-core")]
+    #[test]
+    fn test_paginated_request_impls() {
+        use crate::cosmos::base::query::v1beta1::PageResponse;
+        use crate::ibc::core::channel::v1::{
+            IdentifiedChannel, QueryChannelsRequest, QueryChannelsResponse,
+        };
+
+        let mut request = QueryChannelsRequest::default();
+        let pagination = PageRequest {
+            key: b"a".to_vec(),
+            limit: 10,
+            ..Default::default()
+        };
+        request.set_pagination(pagination.clone());
+        assert_eq!(request.pagination, Some(pagination));
+
+        let response = QueryChannelsResponse {
+            channels: vec![IdentifiedChannel::default()],
+            pagination: Some(PageResponse {
+                next_key: b"b".to_vec(),
+                total: 0,
+            }),
+            height: None,
+        };
+        let (channels, next_key) = QueryChannelsRequest::into_page(response);
+        assert_eq!(channels, [IdentifiedChannel::default()]);
+        assert_eq!(next_key, b"b");
+
+        let response = QueryChannelsResponse::default();
+        assert_eq!(QueryChannelsRequest::into_page(response), (vec![], vec![]));
+    }
+}
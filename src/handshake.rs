@@ -0,0 +1,532 @@
+//! Helpers to build the messages of the IBC handshakes, from the creation of the clients
+//! to the opening of the channels, and the messages relaying the packets.
+//!
+//! The constructors take the fields which the IBC core requires, leaving out the
+//! deprecated ones, and the optional fields are set with the `with_*` methods.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use prost::bytes::Bytes;
+
+use crate::google::protobuf::Any;
+use crate::ibc::core::channel::v1::{
+    self as channel, Channel, MsgAcknowledgement, MsgChannelOpenInit, MsgChannelOpenTry,
+    MsgRecvPacket, MsgTimeout, Order, Packet, State,
+};
+use crate::ibc::core::client::v1::{Height, MsgCreateClient};
+use crate::ibc::core::commitment::v1::MerklePrefix;
+use crate::ibc::core::connection::v1::{
+    self as connection, MsgConnectionOpenInit, MsgConnectionOpenTry, Version,
+};
+
+impl MsgCreateClient {
+    /// Creates a client from its initial client and consensus states, packed into a
+    /// `google.protobuf.Any`.
+    pub fn new(client_state: Any, consensus_state: Any, signer: impl Into<String>) -> Self {
+        Self {
+            client_state: Some(client_state),
+            consensus_state: Some(consensus_state),
+            signer: signer.into(),
+        }
+    }
+}
+
+impl Version {
+    /// Creates a connection version from its identifier and the channel orderings it
+    /// supports, eg. `ORDER_ORDERED`.
+    pub fn new(
+        identifier: impl Into<String>,
+        features: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            identifier: identifier.into(),
+            features: features.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The version `1` of the connections, supporting both ordered and unordered
+    /// channels, as `DefaultIBCVersion` in ibc-go.
+    pub fn ibc_v1() -> Self {
+        Self::new("1", ["ORDER_ORDERED", "ORDER_UNORDERED"])
+    }
+}
+
+impl connection::Counterparty {
+    /// Creates the counterparty of a connection, whose `connection_id` is left empty
+    /// while the counterparty has not opened its end.
+    pub fn new(
+        client_id: impl Into<String>,
+        connection_id: impl Into<String>,
+        prefix: MerklePrefix,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            connection_id: connection_id.into(),
+            prefix: Some(prefix),
+        }
+    }
+}
+
+impl MsgConnectionOpenInit {
+    /// Initializes a connection on top of the given client, without any delay period and
+    /// leaving the choice of the version to the chain.
+    pub fn new(
+        client_id: impl Into<String>,
+        counterparty: connection::Counterparty,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            counterparty: Some(counterparty),
+            signer: signer.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Proposes the given version of the connection to the counterparty.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the delay period, in nanoseconds, to wait for before verifying a packet
+    /// proof against a consensus state.
+    pub fn with_delay_period(mut self, delay_period: u64) -> Self {
+        self.delay_period = delay_period;
+        self
+    }
+}
+
+impl MsgConnectionOpenTry {
+    /// Answers the initialization of a connection by the counterparty, with the proof
+    /// of the `INIT` connection end at the given height.
+    pub fn new(
+        client_id: impl Into<String>,
+        counterparty: connection::Counterparty,
+        counterparty_versions: impl IntoIterator<Item = Version>,
+        proof_init: impl Into<Bytes>,
+        proof_height: Height,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            counterparty: Some(counterparty),
+            counterparty_versions: counterparty_versions.into_iter().collect(),
+            proof_init: proof_init.into(),
+            proof_height: Some(proof_height),
+            signer: signer.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the client of this chain held by the counterparty, along with the proofs of
+    /// this client and of its consensus state at `consensus_height`, for the chains
+    /// validating their own clients.
+    pub fn with_client_state(
+        mut self,
+        client_state: Any,
+        proof_client: impl Into<Bytes>,
+        proof_consensus: impl Into<Bytes>,
+        consensus_height: Height,
+    ) -> Self {
+        self.client_state = Some(client_state);
+        self.proof_client = proof_client.into();
+        self.proof_consensus = proof_consensus.into();
+        self.consensus_height = Some(consensus_height);
+        self
+    }
+
+    /// Sets the delay period, in nanoseconds, which must be the one of the connection
+    /// end of the counterparty.
+    pub fn with_delay_period(mut self, delay_period: u64) -> Self {
+        self.delay_period = delay_period;
+        self
+    }
+}
+
+impl channel::Counterparty {
+    /// Creates the counterparty of a channel, whose `channel_id` is left empty while the
+    /// counterparty has not opened its end.
+    pub fn new(port_id: impl Into<String>, channel_id: impl Into<String>) -> Self {
+        Self {
+            port_id: port_id.into(),
+            channel_id: channel_id.into(),
+        }
+    }
+}
+
+impl Channel {
+    /// Creates a channel end, on top of a single connection, which has never been
+    /// upgraded.
+    pub fn new(
+        state: State,
+        ordering: Order,
+        counterparty: channel::Counterparty,
+        connection_id: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            state: state as i32,
+            ordering: ordering as i32,
+            counterparty: Some(counterparty),
+            connection_hops: vec![connection_id.into()],
+            version: version.into(),
+            upgrade_sequence: 0,
+        }
+    }
+}
+
+impl MsgChannelOpenInit {
+    /// Initializes a channel, which must be in the `INIT` state, on the given port.
+    pub fn new(port_id: impl Into<String>, channel: Channel, signer: impl Into<String>) -> Self {
+        Self {
+            port_id: port_id.into(),
+            channel: Some(channel),
+            signer: signer.into(),
+        }
+    }
+}
+
+impl MsgChannelOpenTry {
+    /// Answers the initialization of a channel by the counterparty, with a channel which
+    /// must be in the `TRYOPEN` state, and the proof of the `INIT` channel end of the
+    /// counterparty at the given height.
+    pub fn new(
+        port_id: impl Into<String>,
+        channel: Channel,
+        counterparty_version: impl Into<String>,
+        proof_init: impl Into<Bytes>,
+        proof_height: Height,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            port_id: port_id.into(),
+            channel: Some(channel),
+            counterparty_version: counterparty_version.into(),
+            proof_init: proof_init.into(),
+            proof_height: Some(proof_height),
+            signer: signer.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Packet {
+    /// Creates a packet carrying the given data, without any timeout.
+    pub fn new(
+        sequence: u64,
+        source_port: impl Into<String>,
+        source_channel: impl Into<String>,
+        destination_port: impl Into<String>,
+        destination_channel: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            sequence,
+            source_port: source_port.into(),
+            source_channel: source_channel.into(),
+            destination_port: destination_port.into(),
+            destination_channel: destination_channel.into(),
+            data: data.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the block height of the destination chain after which the packet times out.
+    pub fn with_timeout_height(mut self, timeout_height: Height) -> Self {
+        self.timeout_height = Some(timeout_height);
+        self
+    }
+
+    /// Sets the block timestamp of the destination chain, in nanoseconds since the Unix
+    /// epoch, after which the packet times out.
+    pub fn with_timeout_timestamp(mut self, timeout_timestamp: u64) -> Self {
+        self.timeout_timestamp = timeout_timestamp;
+        self
+    }
+}
+
+impl MsgRecvPacket {
+    /// Relays a packet, with the proof of its commitment on the source chain at the
+    /// given height.
+    pub fn new(
+        packet: Packet,
+        proof_commitment: impl Into<Bytes>,
+        proof_height: Height,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            packet: Some(packet),
+            proof_commitment: proof_commitment.into(),
+            proof_height: Some(proof_height),
+            signer: signer.into(),
+        }
+    }
+}
+
+impl MsgAcknowledgement {
+    /// Relays the acknowledgement of a packet, with the proof of the acknowledgement on
+    /// the destination chain at the given height.
+    pub fn new(
+        packet: Packet,
+        acknowledgement: impl Into<Vec<u8>>,
+        proof_acked: impl Into<Bytes>,
+        proof_height: Height,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            packet: Some(packet),
+            acknowledgement: acknowledgement.into(),
+            proof_acked: proof_acked.into(),
+            proof_height: Some(proof_height),
+            signer: signer.into(),
+        }
+    }
+}
+
+impl MsgTimeout {
+    /// Relays the timeout of a packet, with the proof that the destination chain has
+    /// not received it at the given height, where it expects the packet of sequence
+    /// `next_sequence_recv`.
+    pub fn new(
+        packet: Packet,
+        next_sequence_recv: u64,
+        proof_unreceived: impl Into<Bytes>,
+        proof_height: Height,
+        signer: impl Into<String>,
+    ) -> Self {
+        Self {
+            packet: Some(packet),
+            proof_unreceived: proof_unreceived.into(),
+            proof_height: Some(proof_height),
+            next_sequence_recv,
+            signer: signer.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Debug;
+
+    use prost::Message;
+
+    use super::*;
+
+    fn roundtrip<M: Message + Default + PartialEq + Debug>(msg: &M) {
+        assert_eq!(&M::decode(&*msg.encode_to_vec()).unwrap(), msg);
+    }
+
+    fn any(type_url: &str) -> Any {
+        Any {
+            type_url: type_url.into(),
+            value: vec![1, 2, 3],
+        }
+    }
+
+    fn packet() -> Packet {
+        Packet::new(
+            1,
+            "transfer",
+            "channel-0",
+            "transfer",
+            "channel-1",
+            b"data".to_vec(),
+        )
+        .with_timeout_height(Height::new(1, 100))
+        .with_timeout_timestamp(1_700_000_000_000_000_000)
+    }
+
+    #[test]
+    fn test_msg_create_client() {
+        let msg = MsgCreateClient::new(any("/client"), any("/consensus"), "signer");
+        assert_eq!(msg.client_state, Some(any("/client")));
+        assert_eq!(msg.consensus_state, Some(any("/consensus")));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_version() {
+        let version = Version::ibc_v1();
+        assert_eq!(version.identifier, "1");
+        assert_eq!(version.features, ["ORDER_ORDERED", "ORDER_UNORDERED"]);
+        roundtrip(&version);
+    }
+
+    #[test]
+    fn test_msg_connection_open_init() {
+        let prefix = MerklePrefix {
+            key_prefix: b"ibc".to_vec(),
+        };
+        let counterparty = connection::Counterparty::new("07-tendermint-1", "", prefix);
+        let msg = MsgConnectionOpenInit::new("07-tendermint-0", counterparty.clone(), "signer")
+            .with_version(Version::ibc_v1())
+            .with_delay_period(10);
+        assert_eq!(msg.client_id, "07-tendermint-0");
+        assert_eq!(msg.counterparty, Some(counterparty));
+        assert_eq!(msg.version, Some(Version::ibc_v1()));
+        assert_eq!(msg.delay_period, 10);
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_msg_connection_open_try() {
+        let counterparty =
+            connection::Counterparty::new("07-tendermint-0", "connection-0", Default::default());
+        let msg = MsgConnectionOpenTry::new(
+            "07-tendermint-1",
+            counterparty.clone(),
+            [Version::ibc_v1()],
+            b"proof_init".to_vec(),
+            Height::new(1, 10),
+            "signer",
+        )
+        .with_client_state(
+            any("/client"),
+            b"proof_client".to_vec(),
+            b"proof_consensus".to_vec(),
+            Height::new(1, 9),
+        )
+        .with_delay_period(10);
+        assert_eq!(msg.client_id, "07-tendermint-1");
+        assert_eq!(msg.counterparty, Some(counterparty));
+        assert_eq!(msg.counterparty_versions, [Version::ibc_v1()]);
+        assert_eq!(msg.proof_init, b"proof_init"[..]);
+        assert_eq!(msg.proof_height, Some(Height::new(1, 10)));
+        assert_eq!(msg.client_state, Some(any("/client")));
+        assert_eq!(msg.proof_client, b"proof_client"[..]);
+        assert_eq!(msg.proof_consensus, b"proof_consensus"[..]);
+        assert_eq!(msg.consensus_height, Some(Height::new(1, 9)));
+        assert_eq!(msg.delay_period, 10);
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_channel() {
+        let counterparty = channel::Counterparty::new("transfer", "");
+        let channel = Channel::new(
+            State::Init,
+            Order::Unordered,
+            counterparty.clone(),
+            "connection-0",
+            "ics20-1",
+        );
+        assert_eq!(channel.state, State::Init as i32);
+        assert_eq!(channel.ordering, Order::Unordered as i32);
+        assert_eq!(channel.counterparty, Some(counterparty));
+        assert_eq!(channel.connection_hops, ["connection-0"]);
+        assert_eq!(channel.version, "ics20-1");
+        assert_eq!(channel.upgrade_sequence, 0);
+        roundtrip(&channel);
+    }
+
+    #[test]
+    fn test_msg_channel_open_init() {
+        let channel = Channel::new(
+            State::Init,
+            Order::Ordered,
+            channel::Counterparty::new("transfer", ""),
+            "connection-0",
+            "ics20-1",
+        );
+        let msg = MsgChannelOpenInit::new("transfer", channel.clone(), "signer");
+        assert_eq!(msg.port_id, "transfer");
+        assert_eq!(msg.channel, Some(channel));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_msg_channel_open_try() {
+        let channel = Channel::new(
+            State::Tryopen,
+            Order::Unordered,
+            channel::Counterparty::new("transfer", "channel-0"),
+            "connection-1",
+            "ics20-1",
+        );
+        let msg = MsgChannelOpenTry::new(
+            "transfer",
+            channel.clone(),
+            "ics20-1",
+            b"proof_init".to_vec(),
+            Height::new(1, 10),
+            "signer",
+        );
+        assert_eq!(msg.port_id, "transfer");
+        assert_eq!(msg.channel, Some(channel));
+        assert_eq!(msg.counterparty_version, "ics20-1");
+        assert_eq!(msg.proof_init, b"proof_init"[..]);
+        assert_eq!(msg.proof_height, Some(Height::new(1, 10)));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_packet() {
+        let packet = packet();
+        assert_eq!(packet.sequence, 1);
+        assert_eq!(packet.source_port, "transfer");
+        assert_eq!(packet.source_channel, "channel-0");
+        assert_eq!(packet.destination_port, "transfer");
+        assert_eq!(packet.destination_channel, "channel-1");
+        assert_eq!(packet.data, b"data");
+        assert_eq!(packet.timeout_height, Some(Height::new(1, 100)));
+        assert_eq!(packet.timeout_timestamp, 1_700_000_000_000_000_000);
+        roundtrip(&packet);
+    }
+
+    #[test]
+    fn test_msg_recv_packet() {
+        let msg = MsgRecvPacket::new(
+            packet(),
+            b"proof_commitment".to_vec(),
+            Height::new(1, 10),
+            "signer",
+        );
+        assert_eq!(msg.packet, Some(packet()));
+        assert_eq!(msg.proof_commitment, b"proof_commitment"[..]);
+        assert_eq!(msg.proof_height, Some(Height::new(1, 10)));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_msg_acknowledgement() {
+        let msg = MsgAcknowledgement::new(
+            packet(),
+            b"ack".to_vec(),
+            b"proof_acked".to_vec(),
+            Height::new(1, 10),
+            "signer",
+        );
+        assert_eq!(msg.packet, Some(packet()));
+        assert_eq!(msg.acknowledgement, b"ack");
+        assert_eq!(msg.proof_acked, b"proof_acked"[..]);
+        assert_eq!(msg.proof_height, Some(Height::new(1, 10)));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+
+    #[test]
+    fn test_msg_timeout() {
+        let msg = MsgTimeout::new(
+            packet(),
+            2,
+            b"proof_unreceived".to_vec(),
+            Height::new(1, 10),
+            "signer",
+        );
+        assert_eq!(msg.packet, Some(packet()));
+        assert_eq!(msg.next_sequence_recv, 2);
+        assert_eq!(msg.proof_unreceived, b"proof_unreceived"[..]);
+        assert_eq!(msg.proof_height, Some(Height::new(1, 10)));
+        assert_eq!(msg.signer, "signer");
+        roundtrip(&msg);
+    }
+}
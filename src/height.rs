@@ -0,0 +1,152 @@
+//! Helpers to compare, display and parse the `ibc.core.client.v1.Height` of the clients.
+//!
+//! Heights are ordered by revision number, then by height within the revision, as
+//! derived on [`Height`].
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::ibc::core::client::v1::Height;
+
+impl Height {
+    /// Creates the height `revision_height` within the revision `revision_number`.
+    pub const fn new(revision_number: u64, revision_height: u64) -> Self {
+        Self {
+            revision_number,
+            revision_height,
+        }
+    }
+
+    /// Returns whether this is the zero height, which the IBC core uses to denote the
+    /// absence of a height, eg. of a timeout height.
+    pub const fn is_zero(&self) -> bool {
+        self.revision_number == 0 && self.revision_height == 0
+    }
+
+    /// Returns the next height within the same revision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height is the last one of the revision, see
+    /// [`Height::checked_increment`].
+    pub const fn increment(&self) -> Self {
+        match self.checked_increment() {
+            Some(height) => height,
+            None => panic!("the height is the last one of its revision"),
+        }
+    }
+
+    /// Returns the next height within the same revision, or `None` if the height is the
+    /// last one of the revision.
+    pub const fn checked_increment(&self) -> Option<Self> {
+        match self.revision_height.checked_add(1) {
+            Some(revision_height) => Some(Self::new(self.revision_number, revision_height)),
+            None => None,
+        }
+    }
+
+    /// Creates the height `revision_height` of the chain with the given ID, within the
+    /// revision found at its end, eg. `4` for `osmosis-4`, or `0` for the chain IDs which
+    /// are not in the `{name}-{revision_number}` format, as `ParseChainID` in ibc-go.
+    pub fn from_chain_id(chain_id: &str, revision_height: u64) -> Self {
+        let revision_number = chain_id
+            .rsplit_once('-')
+            .filter(|(name, _)| !name.is_empty() && !name.ends_with('-'))
+            .filter(|(_, number)| !number.starts_with(['0', '+']))
+            .and_then(|(_, number)| number.parse().ok())
+            .unwrap_or(0);
+
+        Self::new(revision_number, revision_height)
+    }
+}
+
+/// Formats the height as `{revision_number}-{revision_height}`, eg. `2-1035`.
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.revision_number, self.revision_height)
+    }
+}
+
+/// Parses a height from the `{revision_number}-{revision_height}` format, eg. `2-1035`.
+impl FromStr for Height {
+    type Err = ParseHeightError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseHeightError {
+            height: s.to_string(),
+        };
+
+        let (revision_number, revision_height) = s.split_once('-').ok_or_else(error)?;
+
+        Ok(Self::new(
+            revision_number.parse().map_err(|_| error())?,
+            revision_height.parse().map_err(|_| error())?,
+        ))
+    }
+}
+
+/// The error returned when parsing a [`Height`] from a string which is not in the
+/// `{revision_number}-{revision_height}` format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseHeightError {
+    pub height: String,
+}
+
+impl fmt::Display for ParseHeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a height in the `{{revision_number}}-{{revision_height}}` format",
+            self.height
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseHeightError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_increment() {
+        assert_eq!(Height::new(1, 10).increment(), Height::new(1, 11));
+        assert_eq!(
+            Height::new(1, 10).checked_increment(),
+            Some(Height::new(1, 11))
+        );
+        assert_eq!(Height::new(1, u64::MAX).checked_increment(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_increment_overflow() {
+        Height::new(1, u64::MAX).increment();
+    }
+
+    #[test]
+    fn test_from_chain_id() {
+        assert_eq!(Height::from_chain_id("osmosis-4", 10), Height::new(4, 10));
+        assert_eq!(Height::from_chain_id("cosmoshub-4", 1), Height::new(4, 1));
+        assert_eq!(Height::from_chain_id("evmos_9001-2", 1), Height::new(2, 1));
+        assert_eq!(Height::from_chain_id("a-0", 10), Height::new(0, 10));
+        assert_eq!(Height::from_chain_id("a-01", 10), Height::new(0, 10));
+        assert_eq!(Height::from_chain_id("a-+1", 10), Height::new(0, 10));
+        assert_eq!(Height::from_chain_id("a--1", 10), Height::new(0, 10));
+        assert_eq!(Height::from_chain_id("-1", 10), Height::new(0, 10));
+        assert_eq!(Height::from_chain_id("chain", 10), Height::new(0, 10));
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        let height = Height::new(2, 1035);
+
+        assert_eq!(height.to_string(), "2-1035");
+        assert_eq!("2-1035".parse(), Ok(height));
+        assert!("2".parse::<Height>().is_err());
+        assert!("2-a".parse::<Height>().is_err());
+    }
+}
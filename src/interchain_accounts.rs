@@ -0,0 +1,248 @@
+//! Helpers to build the ICS-27 `InterchainAccountPacketData` executing messages on the
+//! host chain, and to encode it to and decode it from its JSON form, which is the data of
+//! the packets, rather than its Protobuf encoding, eg.
+//!
+//! ```json
+//! {"data":"<base64>","memo":"","type":"TYPE_EXECUTE_TX"}
+//! ```
+//!
+//! The messages are packed into `google.protobuf.Any`, which are collected into a
+//! `CosmosTx`, itself Protobuf-encoded as the `data` of the packet data:
+//!
+//! 1. the controller builds the `CosmosTx` with [`CosmosTx::new`] or
+//!    [`CosmosTx::with_msg`], then the packet data with
+//!    [`InterchainAccountPacketData::execute_tx`], sent as
+//!    [`InterchainAccountPacketData::to_json_bytes`];
+//! 2. the host decodes the packet data with
+//!    [`InterchainAccountPacketData::from_json_bytes`], then the `CosmosTx` with
+//!    [`InterchainAccountPacketData::cosmos_tx`], and unpacks each of its messages.
+//!
+//! The JSON is encoded as by ibc-go, so that the commitments of the packets match, ie.
+//! with the fields sorted by name and the empty ones kept, eg. the memo.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use ::base64::prelude::*;
+use prost::{DecodeError, Message};
+
+use crate::google::protobuf::Any;
+use crate::ibc::applications::interchain_accounts::v1::{
+    CosmosTx, InterchainAccountPacketData, Type,
+};
+use crate::protobuf::{erased, IntoConversionError, Name, Protobuf};
+use crate::wire_json;
+
+impl CosmosTx {
+    /// Creates a transaction executing the given, already packed, messages.
+    pub fn new(messages: impl IntoIterator<Item = Any>) -> Self {
+        Self {
+            messages: messages.into_iter().collect(),
+        }
+    }
+
+    /// Packs the given domain message into a `google.protobuf.Any` and appends it
+    /// to the messages to execute.
+    pub fn push<T, Raw>(&mut self, msg: &T)
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.messages.push(msg.to_any());
+    }
+
+    /// Same as [`CosmosTx::push`], but consumes and returns `self`.
+    pub fn with_msg<T, Raw>(mut self, msg: &T) -> Self
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.push(msg);
+        self
+    }
+}
+
+impl InterchainAccountPacketData {
+    /// Creates the packet data executing the given transaction on the host chain, whose
+    /// Protobuf encoding is the `data` of the packet data.
+    pub fn execute_tx(tx: &CosmosTx, memo: impl Into<String>) -> Self {
+        Self {
+            r#type: Type::ExecuteTx as i32,
+            data: tx.encode_to_vec(),
+            memo: memo.into(),
+        }
+    }
+
+    /// Decodes the transaction to execute on the host chain, from the `data` of a packet
+    /// data of type `TYPE_EXECUTE_TX`.
+    pub fn cosmos_tx(&self) -> Result<CosmosTx, PacketDataError> {
+        if self.r#type != Type::ExecuteTx as i32 {
+            return Err(PacketDataError::UnsupportedType(self.r#type));
+        }
+
+        CosmosTx::decode(self.data.as_slice()).map_err(PacketDataError::DecodeCosmosTx)
+    }
+
+    /// Encodes the packet data to its JSON form, as the data of the packets.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        let data = BASE64_STANDARD.encode(&self.data);
+        let r#type = Type::from_i32(self.r#type).unwrap_or_default();
+
+        wire_json::write_object([
+            ("data", data.as_str()),
+            ("memo", self.memo.as_str()),
+            ("type", r#type.as_str_name()),
+        ])
+    }
+
+    /// Decodes the packet data from its JSON form, whose fields may be in any order,
+    /// failing on the unknown fields as ibc-go.
+    ///
+    /// The type must be given by its name, eg. `TYPE_EXECUTE_TX`, as ibc-go encodes it.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, PacketDataError> {
+        let fields = wire_json::parse_object(bytes).ok_or(PacketDataError::InvalidJson)?;
+
+        let mut data = Self::default();
+        for (key, value) in fields {
+            match key.as_str() {
+                "type" => {
+                    let r#type = Type::from_str_name(&value).ok_or(PacketDataError::InvalidJson)?;
+                    data.r#type = r#type as i32;
+                }
+                "data" => {
+                    data.data = BASE64_STANDARD
+                        .decode(value)
+                        .map_err(|_| PacketDataError::InvalidJson)?;
+                }
+                "memo" => data.memo = value,
+                _ => return Err(PacketDataError::UnknownField(key)),
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// The errors of the decoding of an [`InterchainAccountPacketData`] from its JSON form,
+/// or of the `CosmosTx` it executes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PacketDataError {
+    /// The packet data is not a JSON object made of a known type, base64 data and a memo.
+    InvalidJson,
+    /// The field is not one of the packet data.
+    UnknownField(String),
+    /// The packet data does not execute a transaction, eg. its type is unspecified.
+    UnsupportedType(i32),
+    /// The data of the packet data is not a valid `CosmosTx`.
+    DecodeCosmosTx(DecodeError),
+}
+
+impl fmt::Display for PacketDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketDataError::InvalidJson => {
+                write!(
+                    f,
+                    "packet data is not a JSON object with a type, data and memo"
+                )
+            }
+            PacketDataError::UnknownField(key) => write!(f, "unknown packet data field `{key}`"),
+            PacketDataError::UnsupportedType(value) => {
+                write!(f, "unsupported packet data type {value}")
+            }
+            PacketDataError::DecodeCosmosTx(e) => {
+                write!(
+                    f,
+                    "error decoding the data of the packet into a `CosmosTx`: {e}"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PacketDataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PacketDataError::DecodeCosmosTx(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+
+    fn msg_send() -> Any {
+        Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_execute_tx_json() {
+        let tx = CosmosTx::new([msg_send()]);
+        let data = InterchainAccountPacketData::execute_tx(&tx, "");
+        let json = br#"{"data":"Ch4KHC9jb3Ntb3MuYmFuay52MWJldGExLk1zZ1NlbmQ=","memo":"","type":"TYPE_EXECUTE_TX"}"#;
+
+        assert_eq!(data.to_json_bytes(), json);
+        assert_eq!(
+            InterchainAccountPacketData::from_json_bytes(json),
+            Ok(data.clone())
+        );
+        assert_eq!(data.cosmos_tx(), Ok(tx));
+    }
+
+    #[test]
+    fn test_from_json_bytes_any_order() {
+        let json = br#"{"type":"TYPE_EXECUTE_TX","memo":"memo","data":""}"#;
+
+        assert_eq!(
+            InterchainAccountPacketData::from_json_bytes(json),
+            Ok(InterchainAccountPacketData::execute_tx(
+                &CosmosTx::default(),
+                "memo"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_packet_data_errors() {
+        assert_eq!(
+            InterchainAccountPacketData::from_json_bytes(br#"{"type":"TYPE_UNKNOWN"}"#),
+            Err(PacketDataError::InvalidJson)
+        );
+        assert_eq!(
+            InterchainAccountPacketData::from_json_bytes(br#"{"data":"not base64"}"#),
+            Err(PacketDataError::InvalidJson)
+        );
+        assert_eq!(
+            InterchainAccountPacketData::from_json_bytes(br#"{"fee":""}"#),
+            Err(PacketDataError::UnknownField("fee".to_string()))
+        );
+
+        let data = InterchainAccountPacketData::default();
+        assert_eq!(
+            data.cosmos_tx(),
+            Err(PacketDataError::UnsupportedType(Type::Unspecified as i32))
+        );
+
+        let data = InterchainAccountPacketData {
+            data: vec![0xff],
+            ..InterchainAccountPacketData::execute_tx(&CosmosTx::default(), "")
+        };
+        assert!(matches!(
+            data.cosmos_tx(),
+            Err(PacketDataError::DecodeCosmosTx(_))
+        ));
+    }
+}
@@ -1,4 +1,114 @@
 //! ibc-proto library gives the developer access to the Cosmos SDK IBC proto-defined structs.
+//!
+//! The crate is `no_std` compatible, with only `alloc` required: the `ibc` and `ics23`
+//! types, along with the `protobuf::Protobuf` trait and its error type, are available
+//! with `default-features = false`, which leaves out the features requiring the
+//! standard library, ie. `std` and all the features enabling it.
+//!
+//! ## Cargo features
+//!
+//! The features are orthogonal, each of them adding a layer on top of the message
+//! types, which are always available, along with the features it needs:
+//!
+//! - the packages compiled are chosen with `all` or the features it enables, eg.
+//!   `ibc-core` alone for the IBC core types;
+//! - `std` enables the standard library, and `json` switches their JSON representation;
+//! - `client` generates the gRPC clients, without choosing how they connect, which
+//!   `transport` or `grpc-web` do, while `server` generates the gRPC servers, which
+//!   need neither of them.
+//!
+//! The default features are `std`, `client`, `transport` and `all`, and `full`
+//! further enables `client-tls`, `server` and `gzip`, eg. for test harnesses both
+//! querying and mocking chains.
+//!
+//! - `std` (enabled by default): enables the standard library, as well as JSON
+//!   serialization of some of the types through `serde`. As with the REST endpoints of
+//!   the chains, 64-bit integers are encoded as strings and bytes as base64, but enums
+//!   are encoded as numbers and the well-known types as plain messages.
+//! - `client` (enabled by default): generates the `tonic` gRPC clients of every service,
+//!   eg. `ibc::core::channel::v1::query_client::QueryClient`, along with interceptors
+//!   to make the queries at a given height and a stream adapter driving the paginated
+//!   queries, see [`grpc`](crate::grpc). The clients are generic over the underlying
+//!   `tower` service, eg. `XClient::new(service)`.
+//! - `transport` (enabled by default): adds a `connect` constructor to the gRPC clients,
+//!   using the `tonic` transport, which is not available on `wasm32`.
+//! - `client-tls`: adds a `connect_tls` constructor to the gRPC clients, which connects
+//!   over TLS with `rustls`, trusting the Mozilla root certificates, eg. to query the
+//!   public `https://` endpoints of the chains.
+//! - `grpc-web`: adds a `new_grpc_web` constructor to the gRPC clients on `wasm32`, where
+//!   the `tonic` transport is not available, eg. to query the chains from a browser
+//!   through their gRPC-web endpoint.
+//! - `gzip`: enables the gzip compression of the `tonic` gRPC clients and servers, eg.
+//!   `client.accept_compressed(CompressionEncoding::Gzip)` for the responses of the
+//!   large queries, such as those of all the channels, to be compressed.
+//! - `server`: generates the `tonic` gRPC server traits and services of every service,
+//!   eg. the `ibc::core::channel::v1::query_server::Query` trait and its `QueryServer`,
+//!   which is useful to implement mock chains and test harnesses.
+//! - `server-reflection`: provides the gRPC server reflection service of
+//!   `tonic-reflection`, describing the services of the enabled features, eg. for
+//!   `grpcurl` to call the services of a mock chain, see [`reflection`](crate::reflection).
+//! - `json`: implements the proto3 canonical JSON mapping for all types, in place of the
+//!   `serde` implementations enabled by `std`, eg. to decode any response of the REST
+//!   endpoints of the chains. As in the Cosmos SDK, `google.protobuf.Any` is encoded
+//!   with its type URL in an `@type` field, alongside the fields of the message it holds
+//!   for the known types, eg. the IBC client states and the gov proposals, or with the
+//!   message encoded as base64 in a `value` field otherwise. Along with `client` or
+//!   `server`, it also provides a `tonic` codec encoding the gRPC messages as JSON, see
+//!   [`codec`](crate::codec).
+//! - `json-schema` (or its alias `schema`): derives `schemars::JsonSchema` for the IBC core,
+//!   transfer and fee types, as well as for the Cosmos SDK types they depend on.
+//! - `amino`: provides the `StdSignDoc` of `SIGN_MODE_LEGACY_AMINO_JSON`, along
+//!   with a registry of the Amino names of the messages, see [`amino`](crate::amino).
+//!   The messages are rendered from their descriptors, as with `prost-reflect`, so that
+//!   it is available along with the `json` feature.
+//! - `time`, `chrono`: implements the checked conversions of `google.protobuf.Timestamp`
+//!   to and from `time::OffsetDateTime` and `chrono::DateTime<Utc>` respectively.
+//! - `prost-types`: implements the conversions of `google.protobuf.Any`, `Timestamp`,
+//!   `Duration`, `FieldMask`, `Struct`, `ListValue` and `Value` to and from their
+//!   `prost_types` equivalents.
+//! - `prost-reflect`: provides the `prost_reflect::DescriptorPool` of the packages of
+//!   the enabled features, to decode the messages packed into a `google.protobuf.Any`
+//!   whatever their type and render them as JSON, see [`dynamic`](crate::dynamic).
+//! - `proof-verification`: implements the verification of the `MerkleProof` of the
+//!   values stored, or not, under a commitment root, eg. against the `sdk_specs` of the
+//!   IAVL and Tendermint trees, through the `ics23` crate whose types are those of the
+//!   proofs.
+//! - `derive`: provides the `#[derive(Protobuf)]` macro, see [`protobuf::Protobuf`].
+//! - `async`: provides `protobuf::decode_length_delimited_stream`, decoding the
+//!   length-delimited messages read from a `futures` `AsyncRead`.
+//! - `borsh`, `parity-scale-codec`: implements the corresponding encodings for some of
+//!   the types.
+//! - `proptest`: provides `proptest` strategies for some of the IBC types, see
+//!   [`proptest`](crate::proptest).
+//! - `arbitrary`: implements `arbitrary::Arbitrary` for the IBC client, connection and
+//!   channel types, as well as for `MerkleProof`, eg. to build fuzzing harnesses.
+//! - `tendermint`: converts the light blocks of `tendermint-rs` into the `Header` of the
+//!   Tendermint clients, eg. to update the clients from the blocks verified by its light
+//!   client.
+//! - `all` (enabled by default): enables all the features below which gate a part of the
+//!   module tree, so as to only compile the packages which are needed, eg. `ibc-transfer`
+//!   for the ICS-20 types. The dependencies between packages are taken care of, eg.
+//!   `ibc-transfer` enables `ibc-core`, which in turn enables `cosmos-upgrade` and `ics23`.
+//!   - Cosmos SDK modules: `cosmos-auth`, `cosmos-authz`, `cosmos-autocli`, `cosmos-bank`,
+//!     `cosmos-consensus`, `cosmos-distribution`, `cosmos-evidence`, `cosmos-feegrant`,
+//!     `cosmos-gov`, `cosmos-group`, `cosmos-mint`, `cosmos-nft`, `cosmos-slashing`,
+//!     `cosmos-staking`, `cosmos-tx`, `cosmos-upgrade`, `cosmos-vesting`. The
+//!     `cosmos::base` and `cosmos::crypto` packages are always available.
+//!   - IBC: `ibc-core`, `ibc-fee`, `ibc-interchain-accounts`, `ibc-lightclients`,
+//!     `ibc-nft-transfer`, `ibc-transfer`.
+//!   - Others: `ics23`, `icq`, `interchain-security`, `packetforward`, `ratelimit`,
+//!     `stride`.
+//!
+//!   Along with the IBC core types, `ibc-core` provides the parsing of the IBC events
+//!   of the chains, see [`events`](crate::events), and along with the `ics23` types,
+//!   `ics23` provides the expansion of their compressed batch proofs, see
+//!   [`batch_proof`](crate::batch_proof).
+//! - `compile-protos`: regenerates the code at build time from the `.proto` files synced
+//!   under `proto` by `scripts/sync-protobuf.sh`, which are not part of the published crate,
+//!   or from the ones found in the directory given by the `IBC_PROTO_DIR` environment
+//!   variable. Additional type attributes can be given with the `IBC_PROTO_TYPE_ATTRIBUTES`
+//!   environment variable, and the codec of the gRPC clients and servers with
+//!   `IBC_PROTO_CODEC`, see `build.rs`.
 
 // Todo: automate the creation of this module setup based on the dots in the filenames.
 // This module setup is necessary because the generated code contains "super::" calls for dependencies.
@@ -11,6 +121,54 @@
 
 pub mod google;
 pub mod protobuf;
+pub mod registry;
+
+#[cfg(feature = "ics23")]
+pub mod batch_proof;
+#[cfg(all(feature = "json", any(feature = "client", feature = "server")))]
+pub mod codec;
+#[cfg(feature = "prost-reflect")]
+pub mod dynamic;
+#[cfg(feature = "ibc-core")]
+pub mod events;
+#[cfg(feature = "client")]
+pub mod grpc;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "server-reflection")]
+pub mod reflection;
+
+#[cfg(feature = "amino")]
+pub mod amino;
+
+#[cfg(feature = "ibc-core")]
+mod acknowledgement;
+#[cfg(feature = "json")]
+mod any_json;
+#[cfg(all(feature = "arbitrary", feature = "ibc-core"))]
+mod arbitrary;
+#[cfg(feature = "cosmos-authz")]
+mod authz;
+mod coin;
+#[cfg(feature = "ibc-core")]
+mod commitment;
+mod crypto;
+#[cfg(feature = "ibc-core")]
+mod handshake;
+#[cfg(feature = "ibc-core")]
+mod height;
+#[cfg(feature = "ibc-interchain-accounts")]
+mod interchain_accounts;
+#[cfg(feature = "ibc-lightclients")]
+mod lightclients;
+#[cfg(feature = "ibc-core")]
+mod state;
+#[cfg(feature = "ibc-transfer")]
+mod transfer;
+#[cfg(feature = "cosmos-tx")]
+mod tx;
+#[cfg(feature = "ibc-core")]
+mod wire_json;
 
 extern crate alloc;
 
@@ -18,6 +176,7 @@ extern crate alloc;
 #[macro_use]
 extern crate core as std;
 
+#[cfg(not(feature = "compile-protos"))]
 #[macro_export]
 macro_rules! include_proto {
     ($path:literal) => {
@@ -25,6 +184,14 @@ macro_rules! include_proto {
     };
 }
 
+#[cfg(feature = "compile-protos")]
+#[macro_export]
+macro_rules! include_proto {
+    ($path:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $path));
+    };
+}
+
 /// The version (commit hash) of the Cosmos SDK used when generating this library.
 pub const COSMOS_SDK_COMMIT: &str = include_str!("COSMOS_SDK_COMMIT");
 
@@ -32,9 +199,12 @@ pub const COSMOS_SDK_COMMIT: &str = include_str!("COSMOS_SDK_COMMIT");
 pub const IBC_GO_COMMIT: &str = include_str!("IBC_GO_COMMIT");
 
 pub mod cosmos {
+    #[cfg(feature = "cosmos-auth")]
     pub mod auth {
         pub mod v1beta1 {
             include_proto!("cosmos.auth.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.auth.v1beta1.serde.rs");
             /// EthAccount defines an Ethermint account.
             /// TODO: remove when/if a canonical `EthAccount`
             /// lands in the next Cosmos SDK release
@@ -47,228 +217,580 @@ pub mod cosmos {
                 #[prost(bytes = "vec", tag = "2")]
                 pub code_hash: ::prost::alloc::vec::Vec<u8>,
             }
+            impl crate::protobuf::Name for EthAccount {
+                const NAME: &'static str = "EthAccount";
+                const PACKAGE: &'static str = "ethermint.types.v1";
+            }
+        }
+    }
+    #[cfg(feature = "cosmos-autocli")]
+    pub mod autocli {
+        pub mod v1 {
+            include_proto!("cosmos.autocli.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.autocli.v1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-authz")]
+    pub mod authz {
+        pub mod v1beta1 {
+            include_proto!("cosmos.authz.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.authz.v1beta1.serde.rs");
         }
     }
+    #[cfg(feature = "cosmos-evidence")]
     pub mod evidence {
         pub mod v1beta1 {
             include_proto!("cosmos.evidence.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.evidence.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-nft")]
+    pub mod nft {
+        pub mod v1beta1 {
+            include_proto!("cosmos.nft.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.nft.v1beta1.serde.rs");
         }
     }
+    #[cfg(feature = "cosmos-staking")]
     pub mod staking {
         pub mod v1beta1 {
             include_proto!("cosmos.staking.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.staking.v1beta1.serde.rs");
         }
     }
+    #[cfg(feature = "cosmos-bank")]
     pub mod bank {
         pub mod v1beta1 {
             include_proto!("cosmos.bank.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.bank.v1beta1.serde.rs");
         }
     }
     pub mod base {
         pub mod abci {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.abci.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.abci.v1beta1.serde.rs");
             }
         }
         pub mod kv {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.kv.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.kv.v1beta1.serde.rs");
             }
         }
         pub mod node {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.node.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.node.v1beta1.serde.rs");
             }
         }
         pub mod query {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.query.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.query.v1beta1.serde.rs");
             }
         }
         pub mod reflection {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.reflection.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.reflection.v1beta1.serde.rs");
+            }
+            pub mod v2alpha1 {
+                include_proto!("cosmos.base.reflection.v2alpha1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.reflection.v2alpha1.serde.rs");
+            }
+        }
+        pub mod snapshots {
+            pub mod v1beta1 {
+                include_proto!("cosmos.base.snapshots.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.snapshots.v1beta1.serde.rs");
             }
         }
         pub mod store {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.store.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.store.v1beta1.serde.rs");
             }
         }
         pub mod v1beta1 {
             include_proto!("cosmos.base.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.base.v1beta1.serde.rs");
+
+            pub use crate::coin::{
+                checked_add_coins, checked_sub_coins, validate_amount, CoinError,
+            };
         }
         pub mod tendermint {
             pub mod v1beta1 {
                 include_proto!("cosmos.base.tendermint.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.base.tendermint.v1beta1.serde.rs");
             }
         }
     }
+    #[cfg(feature = "cosmos-consensus")]
+    pub mod consensus {
+        pub mod v1 {
+            include_proto!("cosmos.consensus.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.consensus.v1.serde.rs");
+        }
+    }
     pub mod crypto {
+        pub mod ed25519 {
+            include_proto!("cosmos.crypto.ed25519.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.crypto.ed25519.serde.rs");
+        }
         pub mod multisig {
+            include_proto!("cosmos.crypto.multisig.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.crypto.multisig.serde.rs");
+
             pub mod v1beta1 {
                 include_proto!("cosmos.crypto.multisig.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.crypto.multisig.v1beta1.serde.rs");
             }
         }
+        pub mod secp256k1 {
+            include_proto!("cosmos.crypto.secp256k1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.crypto.secp256k1.serde.rs");
+        }
+        pub mod secp256r1 {
+            include_proto!("cosmos.crypto.secp256r1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.crypto.secp256r1.serde.rs");
+        }
+
+        pub use crate::crypto::{PublicKey, PublicKeyError};
     }
+    #[cfg(feature = "cosmos-tx")]
     pub mod tx {
         pub mod signing {
             pub mod v1beta1 {
                 include_proto!("cosmos.tx.signing.v1beta1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("cosmos.tx.signing.v1beta1.serde.rs");
             }
         }
         pub mod v1beta1 {
             include_proto!("cosmos.tx.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.tx.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-tx")]
+    pub mod msg {
+        pub mod textual {
+            /// The `cosmos.msg.textual.v1` package only declares extensions of the
+            /// `google.protobuf` options, which are not generated, hence their field
+            /// numbers are given here along with the type of their value.
+            pub mod v1 {
+                /// The `string expert_custom_renderer` extension of the
+                /// `google.protobuf.MessageOptions`, which gives the identifier of the
+                /// custom renderer of a message in `SIGN_MODE_TEXTUAL`.
+                pub const EXPERT_CUSTOM_RENDERER: u32 = 11110009;
+            }
         }
     }
+    #[cfg(feature = "cosmos-upgrade")]
     pub mod upgrade {
         pub mod v1beta1 {
             include_proto!("cosmos.upgrade.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.upgrade.v1beta1.serde.rs");
         }
     }
+    #[cfg(feature = "cosmos-vesting")]
+    pub mod vesting {
+        pub mod v1beta1 {
+            include_proto!("cosmos.vesting.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.vesting.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-distribution")]
+    pub mod distribution {
+        pub mod v1beta1 {
+            include_proto!("cosmos.distribution.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.distribution.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-mint")]
+    pub mod mint {
+        pub mod v1beta1 {
+            include_proto!("cosmos.mint.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.mint.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-slashing")]
+    pub mod slashing {
+        pub mod v1beta1 {
+            include_proto!("cosmos.slashing.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.slashing.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-feegrant")]
+    pub mod feegrant {
+        pub mod v1beta1 {
+            include_proto!("cosmos.feegrant.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.feegrant.v1beta1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-group")]
+    pub mod group {
+        pub mod v1 {
+            include_proto!("cosmos.group.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.group.v1.serde.rs");
+        }
+    }
+    #[cfg(feature = "cosmos-gov")]
     pub mod gov {
+        pub mod v1 {
+            include_proto!("cosmos.gov.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.gov.v1.serde.rs");
+        }
         pub mod v1beta1 {
             include_proto!("cosmos.gov.v1beta1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("cosmos.gov.v1beta1.serde.rs");
         }
     }
+    #[cfg(feature = "ics23")]
     pub mod ics23 {
         pub use ics23 as v1;
     }
 }
 
 pub mod ibc {
+    #[cfg(any(
+        feature = "ibc-transfer",
+        feature = "ibc-fee",
+        feature = "ibc-interchain-accounts",
+        feature = "ibc-nft-transfer"
+    ))]
     #[deprecated(since = "0.15.0", note = "Use `ibc_proto::ibc::applications` instead")]
     pub mod apps {
         pub use super::applications::*;
     }
+    #[cfg(any(
+        feature = "ibc-transfer",
+        feature = "ibc-fee",
+        feature = "ibc-interchain-accounts",
+        feature = "ibc-nft-transfer"
+    ))]
     pub mod applications {
+        #[cfg(feature = "ibc-transfer")]
         pub mod transfer {
             pub mod v1 {
                 include_proto!("ibc.applications.transfer.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.applications.transfer.v1.serde.rs");
+
+                pub use crate::transfer::DenomError;
             }
             pub mod v2 {
                 include_proto!("ibc.applications.transfer.v2.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.applications.transfer.v2.serde.rs");
+
+                pub use crate::transfer::PacketDataError;
             }
         }
+        #[cfg(feature = "ibc-fee")]
         pub mod fee {
             pub mod v1 {
                 include_proto!("ibc.applications.fee.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.applications.fee.v1.serde.rs");
             }
         }
+        #[cfg(feature = "ibc-interchain-accounts")]
         pub mod interchain_accounts {
             pub mod v1 {
                 include_proto!("ibc.applications.interchain_accounts.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.applications.interchain_accounts.v1.serde.rs");
+
+                pub use crate::interchain_accounts::PacketDataError;
             }
             pub mod controller {
                 pub mod v1 {
                     include_proto!("ibc.applications.interchain_accounts.controller.v1.rs");
+                    #[cfg(feature = "json")]
+                    include_proto!("ibc.applications.interchain_accounts.controller.v1.serde.rs");
+                }
+            }
+            pub mod genesis {
+                pub mod v1 {
+                    include_proto!("ibc.applications.interchain_accounts.genesis.v1.rs");
+                    #[cfg(feature = "json")]
+                    include_proto!("ibc.applications.interchain_accounts.genesis.v1.serde.rs");
                 }
             }
             pub mod host {
                 pub mod v1 {
                     include_proto!("ibc.applications.interchain_accounts.host.v1.rs");
+                    #[cfg(feature = "json")]
+                    include_proto!("ibc.applications.interchain_accounts.host.v1.serde.rs");
                 }
             }
         }
+        #[cfg(feature = "ibc-nft-transfer")]
+        pub mod nft_transfer {
+            pub mod v1 {
+                include_proto!("ibc.applications.nft_transfer.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.applications.nft_transfer.v1.serde.rs");
+            }
+        }
     }
+    #[cfg(feature = "ibc-core")]
     pub mod core {
         pub mod channel {
             pub mod v1 {
                 include_proto!("ibc.core.channel.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.core.channel.v1.serde.rs");
+
+                pub use crate::acknowledgement::AcknowledgementError;
+                pub use crate::state::{ParseEnumError, UnknownEnumValue};
             }
         }
         pub mod client {
             pub mod v1 {
                 include_proto!("ibc.core.client.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.core.client.v1.serde.rs");
+
+                pub use crate::height::ParseHeightError;
             }
         }
         pub mod commitment {
             pub mod v1 {
                 include_proto!("ibc.core.commitment.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.core.commitment.v1.serde.rs");
+
+                #[cfg(feature = "proof-verification")]
+                pub use crate::commitment::{sdk_specs, VerificationError};
             }
         }
         pub mod connection {
             pub mod v1 {
                 include_proto!("ibc.core.connection.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.core.connection.v1.serde.rs");
+
+                pub use crate::state::{ParseEnumError, UnknownEnumValue};
             }
         }
         pub mod types {
             pub mod v1 {
                 include_proto!("ibc.core.types.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.core.types.v1.serde.rs");
             }
         }
     }
+    #[cfg(feature = "ibc-lightclients")]
     pub mod lightclients {
         pub mod localhost {
             pub mod v1 {
                 include_proto!("ibc.lightclients.localhost.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.localhost.v1.serde.rs");
+            }
+            pub mod v2 {
+                include_proto!("ibc.lightclients.localhost.v2.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.localhost.v2.serde.rs");
             }
         }
         pub mod solomachine {
             pub mod v1 {
                 include_proto!("ibc.lightclients.solomachine.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.solomachine.v1.serde.rs");
+            }
+            pub mod v3 {
+                include_proto!("ibc.lightclients.solomachine.v3.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.solomachine.v3.serde.rs");
             }
         }
         pub mod tendermint {
             pub mod v1 {
                 include_proto!("ibc.lightclients.tendermint.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.tendermint.v1.serde.rs");
+            }
+        }
+        pub mod wasm {
+            pub mod v1 {
+                include_proto!("ibc.lightclients.wasm.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("ibc.lightclients.wasm.v1.serde.rs");
             }
         }
+
+        pub use crate::lightclients::{AnyClientState, AnyConsensusState, AnyStateError};
     }
+    #[cfg(feature = "ibc-core")]
     pub mod mock {
         include_proto!("ibc.mock.rs");
+        #[cfg(feature = "json")]
+        include_proto!("ibc.mock.serde.rs");
     }
 }
 
 // Re-export `ics23` definitions for backward compatibility
+#[cfg(feature = "ics23")]
 pub use ics23;
 
+#[cfg(feature = "ics23")]
+macro_rules! impl_ics23_name {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl protobuf::Name for ics23::$ty {
+                const NAME: &'static str = stringify!($ty);
+                const PACKAGE: &'static str = "cosmos.ics23.v1";
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "ics23")]
+impl_ics23_name!(
+    ExistenceProof,
+    NonExistenceProof,
+    CommitmentProof,
+    LeafOp,
+    InnerOp,
+    ProofSpec,
+    InnerSpec,
+    BatchProof,
+    BatchEntry,
+    CompressedBatchProof,
+    CompressedBatchEntry,
+    CompressedExistenceProof,
+    CompressedNonExistenceProof,
+);
+
+#[cfg(feature = "icq")]
+pub mod icq {
+    pub mod v1 {
+        include_proto!("icq.v1.rs");
+        #[cfg(feature = "json")]
+        include_proto!("icq.v1.serde.rs");
+    }
+}
+
+#[cfg(feature = "interchain-security")]
 pub mod interchain_security {
     pub mod ccv {
         #[allow(clippy::match_single_binding)]
         pub mod v1 {
             include_proto!("interchain_security.ccv.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("interchain_security.ccv.v1.serde.rs");
         }
         pub mod provider {
             pub mod v1 {
                 include_proto!("interchain_security.ccv.provider.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("interchain_security.ccv.provider.v1.serde.rs");
             }
         }
         pub mod consumer {
             pub mod v1 {
                 include_proto!("interchain_security.ccv.consumer.v1.rs");
+                #[cfg(feature = "json")]
+                include_proto!("interchain_security.ccv.consumer.v1.serde.rs");
             }
         }
     }
 }
 
+#[cfg(feature = "packetforward")]
+pub mod packetforward {
+    pub mod v1 {
+        include_proto!("packetforward.v1.rs");
+        #[cfg(feature = "json")]
+        include_proto!("packetforward.v1.serde.rs");
+    }
+}
+
+#[cfg(feature = "ratelimit")]
+pub mod ratelimit {
+    pub mod v1 {
+        include_proto!("ratelimit.v1.rs");
+        #[cfg(feature = "json")]
+        include_proto!("ratelimit.v1.serde.rs");
+    }
+}
+
+#[cfg(feature = "stride")]
 pub mod stride {
     pub mod interchainquery {
         pub mod v1 {
             include_proto!("stride.interchainquery.v1.rs");
+            #[cfg(feature = "json")]
+            include_proto!("stride.interchainquery.v1.serde.rs");
         }
     }
 }
 
-#[cfg(feature = "std")]
-pub(crate) mod base64 {
-    use alloc::string::String;
-    use alloc::vec::Vec;
-
-    use base64::prelude::*;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+/// The `tendermint` proto packages are not generated by this crate but mapped onto
+/// [`tendermint_proto`], so that the types found in the fields of our messages are the
+/// very same ones. They are re-exported here to spare users a direct dependency on a
+/// matching version of `tendermint-proto`.
+///
+/// The packages of the other CometBFT lines, whose messages differ in a few fields
+/// (eg. of the blocks and the evidences, or the ABCI methods), are found in the
+/// modules named after these lines. The messages of this crate hold the types found
+/// at the root of this module, ie. those of the line `tendermint-proto` re-exports at
+/// its own root, which must then be converted from or to the other lines.
+pub mod tendermint {
+    pub use tendermint_proto::abci;
+    pub use tendermint_proto::crypto;
+    pub use tendermint_proto::p2p;
+    pub use tendermint_proto::types;
+    pub use tendermint_proto::version;
 
-    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
-        let encoded = BASE64_STANDARD.encode(bytes);
-        String::serialize(&encoded, serializer)
+    /// The packages of CometBFT 0.34.
+    pub mod v0_34 {
+        pub use tendermint_proto::v0_34::{abci, crypto, p2p, types, version};
     }
 
-    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
-        let base64 = String::deserialize(deserializer)?;
-        let bytes = BASE64_STANDARD
-            .decode(base64.as_bytes())
-            .map_err(serde::de::Error::custom)?;
-
-        Ok(bytes)
+    /// The packages of CometBFT 0.37.
+    pub mod v0_37 {
+        pub use tendermint_proto::v0_37::{abci, crypto, p2p, types, version};
     }
 }
+
+#[cfg(all(feature = "std", not(feature = "json")))]
+pub(crate) mod serializers;
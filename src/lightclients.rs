@@ -0,0 +1,417 @@
+//! Helpers to unpack the client and consensus states of the light clients, found as a
+//! `google.protobuf.Any` in the responses of the queries of the `ibc.core.client.v1`
+//! module, in the messages creating and upgrading the clients, and in the genesis.
+//!
+//! The states are decoded according to their type URL, into the current version of the
+//! states of the Tendermint, Wasm, solo machine and localhost clients, while the states
+//! of the other clients are kept packed, eg.
+//!
+//! ```ignore
+//! match AnyClientState::try_from(response.client_state.unwrap())? {
+//!     AnyClientState::Tendermint(state) => println!("{}", state.chain_id),
+//!     state => println!("client of type {}", state.type_url()),
+//! }
+//! ```
+//!
+//! The `Header` updating a Tendermint client is assembled with [`Header::new`] from the
+//! signed header and validator set of the target block, along with the height and the
+//! validators trusted by the client, or, with the `tendermint` feature, from the light
+//! blocks of `tendermint-rs` with [`Header::from_light_blocks`].
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+
+use prost::{DecodeError, Message};
+#[cfg(feature = "tendermint")]
+use tendermint_light_client_verifier::types::LightBlock;
+use tendermint_proto::types::{SignedHeader, ValidatorSet};
+
+use crate::google::protobuf::Any;
+use crate::ibc::core::client::v1::Height;
+use crate::ibc::lightclients::tendermint::v1::Header;
+use crate::ibc::lightclients::{localhost, solomachine, tendermint, wasm};
+use crate::protobuf::Name;
+
+macro_rules! impl_any_state {
+    ($name:ident { $($variant:ident($ty:ty)),* $(,)? }) => {
+        impl $name {
+            /// Unpacks the state from a `google.protobuf.Any`, according to its type URL,
+            /// keeping the states of the other clients packed.
+            pub fn from_any(any: &Any) -> Result<Self, AnyStateError> {
+                $(
+                    if any.type_url == <$ty>::type_url() {
+                        return <$ty>::decode(any.value.as_slice())
+                            .map(Self::$variant)
+                            .map_err(|e| AnyStateError::Decode {
+                                type_url: any.type_url.clone(),
+                                source: e,
+                            });
+                    }
+                )*
+
+                Ok(Self::Other(any.clone()))
+            }
+
+            /// Packs the state into a `google.protobuf.Any`.
+            pub fn to_any(&self) -> Any {
+                match self {
+                    $(Self::$variant(state) => encode(state),)*
+                    Self::Other(any) => any.clone(),
+                }
+            }
+
+            /// Returns the type URL of the state.
+            pub fn type_url(&self) -> String {
+                match self {
+                    $(Self::$variant(_) => <$ty>::type_url(),)*
+                    Self::Other(any) => any.type_url.clone(),
+                }
+            }
+        }
+
+        impl TryFrom<&Any> for $name {
+            type Error = AnyStateError;
+
+            fn try_from(any: &Any) -> Result<Self, Self::Error> {
+                Self::from_any(any)
+            }
+        }
+
+        impl TryFrom<Any> for $name {
+            type Error = AnyStateError;
+
+            fn try_from(any: Any) -> Result<Self, Self::Error> {
+                Self::from_any(&any)
+            }
+        }
+
+        impl From<$name> for Any {
+            fn from(state: $name) -> Self {
+                match state {
+                    $name::Other(any) => any,
+                    state => state.to_any(),
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for $name {
+                fn from(state: $ty) -> Self {
+                    Self::$variant(state)
+                }
+            }
+        )*
+    };
+}
+
+/// The client state of a light client.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyClientState {
+    Tendermint(tendermint::v1::ClientState),
+    Wasm(wasm::v1::ClientState),
+    Solomachine(solomachine::v3::ClientState),
+    Localhost(localhost::v2::ClientState),
+    /// The client state of another light client, left packed.
+    Other(Any),
+}
+
+impl_any_state!(AnyClientState {
+    Tendermint(tendermint::v1::ClientState),
+    Wasm(wasm::v1::ClientState),
+    Solomachine(solomachine::v3::ClientState),
+    Localhost(localhost::v2::ClientState),
+});
+
+/// The consensus state of a light client.
+///
+/// The localhost client has no consensus state, as it verifies the states of its own
+/// chain.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyConsensusState {
+    Tendermint(tendermint::v1::ConsensusState),
+    Wasm(wasm::v1::ConsensusState),
+    Solomachine(solomachine::v3::ConsensusState),
+    /// The consensus state of another light client, left packed.
+    Other(Any),
+}
+
+impl_any_state!(AnyConsensusState {
+    Tendermint(tendermint::v1::ConsensusState),
+    Wasm(wasm::v1::ConsensusState),
+    Solomachine(solomachine::v3::ConsensusState),
+});
+
+impl Header {
+    /// Creates the header updating a client to the given signed header, signed by
+    /// `validator_set`, from its consensus state at `trusted_height`, whose next
+    /// validators are `trusted_validators`, ie. the validators of the block after it.
+    pub fn new(
+        signed_header: SignedHeader,
+        validator_set: ValidatorSet,
+        trusted_height: Height,
+        trusted_validators: ValidatorSet,
+    ) -> Self {
+        Self {
+            signed_header: Some(Box::new(signed_header)),
+            validator_set: Some(Box::new(validator_set)),
+            trusted_height: Some(trusted_height),
+            trusted_validators: Some(Box::new(trusted_validators)),
+        }
+    }
+
+    /// Creates the header updating a client to the `target` light block, from its
+    /// consensus state at the `trusted` light block, as fetched by the light client of
+    /// `tendermint-rs`.
+    ///
+    /// The trusted validators are the next validators of the trusted block, and its
+    /// height is within the revision of its chain ID, see [`Height::from_chain_id`].
+    #[cfg(feature = "tendermint")]
+    pub fn from_light_blocks(target: LightBlock, trusted: &LightBlock) -> Self {
+        let trusted_height = Height::from_chain_id(
+            trusted.signed_header.header.chain_id.as_str(),
+            trusted.height().value(),
+        );
+
+        Self::new(
+            target.signed_header.into(),
+            target.validators.into(),
+            trusted_height,
+            trusted.next_validators.clone().into(),
+        )
+    }
+
+    /// Returns the height of the signed header, within the revision of its chain ID, see
+    /// [`Height::from_chain_id`].
+    pub fn height(&self) -> Option<Height> {
+        let header = self.signed_header.as_ref()?.header.as_ref()?;
+
+        Some(Height::from_chain_id(
+            &header.chain_id,
+            header.height as u64,
+        ))
+    }
+}
+
+fn encode<M: Message + Name>(state: &M) -> Any {
+    Any {
+        type_url: M::type_url(),
+        value: state.encode_to_vec(),
+    }
+}
+
+/// The errors of the unpacking of client and consensus states.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnyStateError {
+    /// The state could not be decoded as the message of its type URL.
+    Decode {
+        type_url: String,
+        source: DecodeError,
+    },
+}
+
+impl fmt::Display for AnyStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyStateError::Decode { type_url, source } => {
+                write!(f, "error decoding state of type `{type_url}`: {source}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AnyStateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnyStateError::Decode { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tendermint_client_state() -> tendermint::v1::ClientState {
+        tendermint::v1::ClientState {
+            chain_id: "osmosis-1".into(),
+            latest_height: Some(Height::new(1, 10)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_client_state_any() {
+        let state = tendermint_client_state();
+        let any = encode(&state);
+
+        assert_eq!(any.type_url, "/ibc.lightclients.tendermint.v1.ClientState");
+
+        let unpacked = AnyClientState::try_from(&any).unwrap();
+        assert_eq!(unpacked, AnyClientState::Tendermint(state.clone()));
+        assert_eq!(unpacked.type_url(), any.type_url);
+        assert_eq!(Any::from(unpacked), any);
+        assert_eq!(
+            AnyClientState::from(state),
+            AnyClientState::try_from(any).unwrap()
+        );
+
+        let state = localhost::v2::ClientState {
+            latest_height: Some(Height::new(0, 5)),
+        };
+        assert_eq!(
+            AnyClientState::from_any(&encode(&state)),
+            Ok(AnyClientState::Localhost(state))
+        );
+    }
+
+    #[test]
+    fn test_consensus_state_any() {
+        let state = solomachine::v3::ConsensusState {
+            public_key: None,
+            diversifier: "diversifier".into(),
+            timestamp: 10,
+        };
+        let any = encode(&state);
+
+        let unpacked = AnyConsensusState::try_from(&any).unwrap();
+        assert_eq!(unpacked, AnyConsensusState::Solomachine(state));
+        assert_eq!(unpacked.to_any(), any);
+    }
+
+    #[test]
+    fn test_other_state_any() {
+        let any = Any {
+            type_url: "/ibc.lightclients.other.v1.ClientState".into(),
+            value: alloc::vec![1, 2, 3],
+        };
+
+        let unpacked = AnyClientState::try_from(&any).unwrap();
+        assert_eq!(unpacked, AnyClientState::Other(any.clone()));
+        assert_eq!(unpacked.type_url(), any.type_url);
+        assert_eq!(Any::from(unpacked), any);
+
+        // The localhost client has no consensus state.
+        let any = encode(&localhost::v2::ClientState::default());
+        assert_eq!(
+            AnyConsensusState::try_from(&any),
+            Ok(AnyConsensusState::Other(any))
+        );
+    }
+
+    fn signed_header(chain_id: &str, height: i64) -> SignedHeader {
+        SignedHeader {
+            header: Some(tendermint_proto::types::Header {
+                chain_id: chain_id.into(),
+                height,
+                ..Default::default()
+            }),
+            commit: None,
+        }
+    }
+
+    fn validator_set(voting_power: i64) -> ValidatorSet {
+        ValidatorSet {
+            validators: alloc::vec![],
+            proposer: None,
+            total_voting_power: voting_power,
+        }
+    }
+
+    #[test]
+    fn test_header_new() {
+        let header = Header::new(
+            signed_header("osmosis-4", 12),
+            validator_set(1),
+            Height::new(4, 10),
+            validator_set(2),
+        );
+
+        assert_eq!(header.height(), Some(Height::new(4, 12)));
+        assert_eq!(header.trusted_height, Some(Height::new(4, 10)));
+        assert_eq!(header.validator_set, Some(Box::new(validator_set(1))));
+        assert_eq!(header.trusted_validators, Some(Box::new(validator_set(2))));
+        assert_eq!(Header::default().height(), None);
+    }
+
+    #[cfg(feature = "tendermint")]
+    #[test]
+    fn test_header_from_light_blocks() {
+        use ::tendermint::block::{self, signed_header, Commit};
+        use ::tendermint::{account, node, validator, PublicKey, Time};
+
+        fn light_block(height: u32, validator: u8, next_validator: u8) -> LightBlock {
+            let header = block::Header {
+                version: block::header::Version { block: 11, app: 0 },
+                chain_id: "osmosis-4".parse().unwrap(),
+                height: height.into(),
+                time: Time::unix_epoch(),
+                last_block_id: None,
+                last_commit_hash: None,
+                data_hash: None,
+                validators_hash: Default::default(),
+                next_validators_hash: Default::default(),
+                consensus_hash: Default::default(),
+                app_hash: Default::default(),
+                last_results_hash: None,
+                evidence_hash: None,
+                proposer_address: account::Id::new([0; 20]),
+            };
+            let commit = Commit {
+                height: height.into(),
+                ..Default::default()
+            };
+            let signed_header = signed_header::SignedHeader::new(header, commit).unwrap();
+
+            LightBlock::new(
+                signed_header,
+                validators(validator),
+                validators(next_validator),
+                node::Id::new([0; 20]),
+            )
+        }
+
+        // The validators are told apart by their voting power.
+        fn validators(voting_power: u8) -> validator::Set {
+            let public_key = PublicKey::from_raw_ed25519(&[0x58; 32]).unwrap();
+            let info = validator::Info {
+                address: account::Id::new([voting_power; 20]),
+                pub_key: public_key,
+                power: u32::from(voting_power).into(),
+                name: None,
+                proposer_priority: Default::default(),
+            };
+
+            validator::Set::without_proposer(alloc::vec![info])
+        }
+
+        let trusted = light_block(10, 1, 2);
+        let header = Header::from_light_blocks(light_block(12, 3, 4), &trusted);
+
+        assert_eq!(header.height(), Some(Height::new(4, 12)));
+        assert_eq!(header.trusted_height, Some(Height::new(4, 10)));
+        assert_eq!(
+            header.validator_set.map(|set| set.total_voting_power),
+            Some(3)
+        );
+        assert_eq!(
+            header.trusted_validators.map(|set| set.total_voting_power),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_state_any_decode_error() {
+        let any = Any {
+            type_url: tendermint::v1::ClientState::type_url(),
+            value: alloc::vec![0xff],
+        };
+
+        assert!(matches!(
+            AnyClientState::try_from(&any),
+            Err(AnyStateError::Decode { type_url, .. }) if type_url == any.type_url
+        ));
+    }
+}
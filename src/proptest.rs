@@ -0,0 +1,182 @@
+//! [`proptest`](::proptest) strategies for some of the IBC types, for property-based
+//! testing of their encoding and of the code handling them.
+//!
+//! The generated values are well-formed, eg. packets use valid port and channel identifiers
+//! and proofs are made of existence or non-existence proofs, but proofs are not expected
+//! to verify.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ::proptest::collection::vec;
+use ::proptest::option;
+use ::proptest::prelude::*;
+use ::proptest::sample::select;
+use ics23::commitment_proof::Proof;
+use ics23::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof};
+
+use crate::commitment::generate::{HASH_OPS, LENGTH_OPS, MAX_PATH_LEN, MAX_PROOFS_LEN};
+use crate::ibc::core::channel::v1::Packet;
+use crate::ibc::core::client::v1::Height;
+use crate::ibc::core::commitment::v1::MerkleProof;
+
+/// Returns a strategy for any [`Height`].
+pub fn any_height() -> impl Strategy<Value = Height> {
+    (any::<u64>(), any::<u64>()).prop_map(|(revision_number, revision_height)| Height {
+        revision_number,
+        revision_height,
+    })
+}
+
+/// Returns a strategy for valid port identifiers, eg. `transfer`.
+pub fn any_port_id() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9._+-]{1,63}"
+}
+
+/// Returns a strategy for valid channel identifiers, eg. `channel-42`.
+pub fn any_channel_id() -> impl Strategy<Value = String> {
+    any::<u32>().prop_map(|n| alloc::format!("channel-{}", n))
+}
+
+/// Returns a strategy for any [`Packet`] with valid identifiers, a non-zero sequence
+/// and a timeout height or timestamp.
+pub fn any_packet() -> impl Strategy<Value = Packet> {
+    (
+        1..u64::MAX,
+        any_port_id(),
+        any_channel_id(),
+        any_port_id(),
+        any_channel_id(),
+        vec(any::<u8>(), 0..256),
+        option::of(any_height()),
+        any::<u64>(),
+    )
+        .prop_filter(
+            "packet without timeout",
+            |(_, _, _, _, _, _, timeout_height, timeout_timestamp)| {
+                timeout_height.is_some() || *timeout_timestamp != 0
+            },
+        )
+        .prop_map(
+            |(
+                sequence,
+                source_port,
+                source_channel,
+                destination_port,
+                destination_channel,
+                data,
+                timeout_height,
+                timeout_timestamp,
+            )| Packet {
+                sequence,
+                source_port,
+                source_channel,
+                destination_port,
+                destination_channel,
+                data,
+                timeout_height,
+                timeout_timestamp,
+            },
+        )
+}
+
+/// Returns a strategy for any [`MerkleProof`] made of one to four commitment proofs.
+pub fn any_merkle_proof() -> impl Strategy<Value = MerkleProof> {
+    vec(any_commitment_proof(), 1..=MAX_PROOFS_LEN).prop_map(|proofs| MerkleProof { proofs })
+}
+
+/// Returns a strategy for any [`CommitmentProof`] holding an existence or a
+/// non-existence proof.
+pub fn any_commitment_proof() -> impl Strategy<Value = CommitmentProof> {
+    prop_oneof![
+        any_existence_proof().prop_map(Proof::Exist),
+        any_non_existence_proof().prop_map(Proof::Nonexist),
+    ]
+    .prop_map(|proof| CommitmentProof { proof: Some(proof) })
+}
+
+/// Returns a strategy for any [`ExistenceProof`] with up to eight inner nodes.
+pub fn any_existence_proof() -> impl Strategy<Value = ExistenceProof> {
+    (
+        any_bytes(),
+        any_bytes(),
+        any_leaf_op(),
+        vec(any_inner_op(), 0..=MAX_PATH_LEN),
+    )
+        .prop_map(|(key, value, leaf, path)| ExistenceProof {
+            key,
+            value,
+            leaf: Some(leaf),
+            path,
+        })
+}
+
+/// Returns a strategy for any [`NonExistenceProof`] with at least one neighbour.
+pub fn any_non_existence_proof() -> impl Strategy<Value = NonExistenceProof> {
+    (
+        any_bytes(),
+        option::of(any_existence_proof()),
+        option::of(any_existence_proof()),
+    )
+        .prop_filter("proof without neighbours", |(_, left, right)| {
+            left.is_some() || right.is_some()
+        })
+        .prop_map(|(key, left, right)| NonExistenceProof { key, left, right })
+}
+
+fn any_leaf_op() -> impl Strategy<Value = LeafOp> {
+    (
+        any_hash_op(),
+        any_hash_op(),
+        any_hash_op(),
+        select(LENGTH_OPS.to_vec()),
+        any_bytes(),
+    )
+        .prop_map(
+            |(hash, prehash_key, prehash_value, length, prefix)| LeafOp {
+                hash,
+                prehash_key,
+                prehash_value,
+                length,
+                prefix,
+            },
+        )
+}
+
+fn any_inner_op() -> impl Strategy<Value = InnerOp> {
+    (any_hash_op(), any_bytes(), any_bytes()).prop_map(|(hash, prefix, suffix)| InnerOp {
+        hash,
+        prefix,
+        suffix,
+    })
+}
+
+fn any_hash_op() -> impl Strategy<Value = i32> {
+    select(HASH_OPS.to_vec())
+}
+
+fn any_bytes() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..64)
+}
+
+#[cfg(test)]
+mod test {
+    use prost::Message;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_any_merkle_proof(proof in any_merkle_proof()) {
+            prop_assert!((1..=MAX_PROOFS_LEN).contains(&proof.proofs.len()));
+            prop_assert_eq!(MerkleProof::decode(&*proof.encode_to_vec()).unwrap(), proof);
+        }
+
+        #[test]
+        fn test_any_packet(packet in any_packet()) {
+            prop_assert_ne!(packet.sequence, 0);
+            prop_assert!(packet.timeout_height.is_some() || packet.timeout_timestamp != 0);
+            prop_assert_eq!(Packet::decode(&*packet.encode_to_vec()).unwrap(), packet);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 /// BaseAccount defines a base account type. It contains all the necessary fields
 /// for basic account functionality. Any custom account type should extend this
 /// type for additional functionality (e.g. vesting).
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BaseAccount {
@@ -14,6 +15,7 @@ pub struct BaseAccount {
     pub sequence: u64,
 }
 /// ModuleAccount defines an account for modules that holds coins on a pool.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModuleAccount {
@@ -25,6 +27,7 @@ pub struct ModuleAccount {
     pub permissions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// Params defines the parameters for the auth module.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
@@ -42,6 +45,7 @@ pub struct Params {
 /// QueryAccountsRequest is the request type for the Query/Accounts RPC method.
 ///
 /// Since: cosmos-sdk 0.43
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountsRequest {
@@ -54,6 +58,7 @@ pub struct QueryAccountsRequest {
 /// QueryAccountsResponse is the response type for the Query/Accounts RPC method.
 ///
 /// Since: cosmos-sdk 0.43
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountsResponse {
@@ -67,6 +72,7 @@ pub struct QueryAccountsResponse {
     >,
 }
 /// QueryAccountRequest is the request type for the Query/Account RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountRequest {
@@ -75,6 +81,7 @@ pub struct QueryAccountRequest {
     pub address: ::prost::alloc::string::String,
 }
 /// QueryAccountResponse is the response type for the Query/Account RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountResponse {
@@ -83,10 +90,12 @@ pub struct QueryAccountResponse {
     pub account: ::core::option::Option<super::super::super::google::protobuf::Any>,
 }
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
@@ -97,12 +106,14 @@ pub struct QueryParamsResponse {
 /// QueryModuleAccountsRequest is the request type for the Query/ModuleAccounts RPC method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryModuleAccountsRequest {}
 /// QueryModuleAccountsResponse is the response type for the Query/ModuleAccounts RPC method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryModuleAccountsResponse {
@@ -110,6 +121,7 @@ pub struct QueryModuleAccountsResponse {
     pub accounts: ::prost::alloc::vec::Vec<super::super::super::google::protobuf::Any>,
 }
 /// QueryModuleAccountByNameRequest is the request type for the Query/ModuleAccountByName RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryModuleAccountByNameRequest {
@@ -117,6 +129,7 @@ pub struct QueryModuleAccountByNameRequest {
     pub name: ::prost::alloc::string::String,
 }
 /// QueryModuleAccountByNameResponse is the response type for the Query/ModuleAccountByName RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryModuleAccountByNameResponse {
@@ -126,12 +139,14 @@ pub struct QueryModuleAccountByNameResponse {
 /// Bech32PrefixRequest is the request type for Bech32Prefix rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bech32PrefixRequest {}
 /// Bech32PrefixResponse is the response type for Bech32Prefix rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bech32PrefixResponse {
@@ -141,6 +156,7 @@ pub struct Bech32PrefixResponse {
 /// AddressBytesToStringRequest is the request type for AddressString rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddressBytesToStringRequest {
@@ -150,6 +166,7 @@ pub struct AddressBytesToStringRequest {
 /// AddressBytesToStringResponse is the response type for AddressString rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddressBytesToStringResponse {
@@ -159,6 +176,7 @@ pub struct AddressBytesToStringResponse {
 /// AddressStringToBytesRequest is the request type for AccountBytes rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddressStringToBytesRequest {
@@ -168,6 +186,7 @@ pub struct AddressStringToBytesRequest {
 /// AddressStringToBytesResponse is the response type for AddressBytes rpc method.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddressStringToBytesResponse {
@@ -177,6 +196,7 @@ pub struct AddressStringToBytesResponse {
 /// QueryAccountAddressByIDRequest is the request type for AccountAddressByID rpc method
 ///
 /// Since: cosmos-sdk 0.46.2
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountAddressByIdRequest {
@@ -189,6 +209,7 @@ pub struct QueryAccountAddressByIdRequest {
 /// QueryAccountAddressByIDResponse is the response type for AccountAddressByID rpc method
 ///
 /// Since: cosmos-sdk 0.46.2
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountAddressByIdResponse {
@@ -206,6 +227,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -216,6 +238,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -1163,6 +1206,7 @@ pub mod query_server {
     }
 }
 /// GenesisState defines the auth module's genesis state.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
@@ -1173,3 +1217,119 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "2")]
     pub accounts: ::prost::alloc::vec::Vec<super::super::super::google::protobuf::Any>,
 }
+impl crate::protobuf::Name for BaseAccount {
+    const NAME: &'static str = "BaseAccount";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for ModuleAccount {
+    const NAME: &'static str = "ModuleAccount";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountsRequest {
+    const NAME: &'static str = "QueryAccountsRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountsResponse {
+    const NAME: &'static str = "QueryAccountsResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountRequest {
+    const NAME: &'static str = "QueryAccountRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountResponse {
+    const NAME: &'static str = "QueryAccountResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryModuleAccountsRequest {
+    const NAME: &'static str = "QueryModuleAccountsRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryModuleAccountsResponse {
+    const NAME: &'static str = "QueryModuleAccountsResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryModuleAccountByNameRequest {
+    const NAME: &'static str = "QueryModuleAccountByNameRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryModuleAccountByNameResponse {
+    const NAME: &'static str = "QueryModuleAccountByNameResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for Bech32PrefixRequest {
+    const NAME: &'static str = "Bech32PrefixRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for Bech32PrefixResponse {
+    const NAME: &'static str = "Bech32PrefixResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for AddressBytesToStringRequest {
+    const NAME: &'static str = "AddressBytesToStringRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for AddressBytesToStringResponse {
+    const NAME: &'static str = "AddressBytesToStringResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for AddressStringToBytesRequest {
+    const NAME: &'static str = "AddressStringToBytesRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for AddressStringToBytesResponse {
+    const NAME: &'static str = "AddressStringToBytesResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountAddressByIdRequest {
+    const NAME: &'static str = "QueryAccountAddressByIDRequest";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for QueryAccountAddressByIdResponse {
+    const NAME: &'static str = "QueryAccountAddressByIDResponse";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.auth.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<BaseAccount>();
+    registry.register::<ModuleAccount>();
+    registry.register::<Params>();
+    registry.register::<QueryAccountsRequest>();
+    registry.register::<QueryAccountsResponse>();
+    registry.register::<QueryAccountRequest>();
+    registry.register::<QueryAccountResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryModuleAccountsRequest>();
+    registry.register::<QueryModuleAccountsResponse>();
+    registry.register::<QueryModuleAccountByNameRequest>();
+    registry.register::<QueryModuleAccountByNameResponse>();
+    registry.register::<Bech32PrefixRequest>();
+    registry.register::<Bech32PrefixResponse>();
+    registry.register::<AddressBytesToStringRequest>();
+    registry.register::<AddressBytesToStringResponse>();
+    registry.register::<AddressStringToBytesRequest>();
+    registry.register::<AddressStringToBytesResponse>();
+    registry.register::<QueryAccountAddressByIdRequest>();
+    registry.register::<QueryAccountAddressByIdResponse>();
+    registry.register::<GenesisState>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.auth.v1beta1.bin");
@@ -1,5 +1,13 @@
 /// Params defines the parameters for the bank module.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
@@ -10,7 +18,15 @@ pub struct Params {
 }
 /// SendEnabled maps coin denom to a send_enabled status (whether a denom is
 /// sendable).
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SendEnabled {
@@ -20,7 +36,15 @@ pub struct SendEnabled {
     pub enabled: bool,
 }
 /// Input models transaction input.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Input {
@@ -30,7 +54,15 @@ pub struct Input {
     pub coins: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
 /// Output models transaction outputs.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Output {
@@ -42,7 +74,15 @@ pub struct Output {
 /// Supply represents a struct that passively keeps track of the total supply
 /// amounts in the network.
 /// This message is deprecated now that supply is indexed by denom.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Supply {
@@ -51,7 +91,15 @@ pub struct Supply {
 }
 /// DenomUnit represents a struct that describes a given
 /// denomination unit of the basic token.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DenomUnit {
@@ -71,7 +119,15 @@ pub struct DenomUnit {
 }
 /// Metadata represents a struct that describes
 /// a basic token.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
@@ -111,7 +167,15 @@ pub struct Metadata {
     pub uri_hash: ::prost::alloc::string::String,
 }
 /// QueryBalanceRequest is the request type for the Query/Balance RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalanceRequest {
@@ -123,7 +187,15 @@ pub struct QueryBalanceRequest {
     pub denom: ::prost::alloc::string::String,
 }
 /// QueryBalanceResponse is the response type for the Query/Balance RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalanceResponse {
@@ -132,7 +204,15 @@ pub struct QueryBalanceResponse {
     pub balance: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// QueryBalanceRequest is the request type for the Query/AllBalances RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllBalancesRequest {
@@ -147,7 +227,15 @@ pub struct QueryAllBalancesRequest {
 }
 /// QueryAllBalancesResponse is the response type for the Query/AllBalances RPC
 /// method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllBalancesResponse {
@@ -164,7 +252,15 @@ pub struct QueryAllBalancesResponse {
 /// an account's spendable balances.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuerySpendableBalancesRequest {
@@ -181,7 +277,15 @@ pub struct QuerySpendableBalancesRequest {
 /// an account's spendable balances.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuerySpendableBalancesResponse {
@@ -196,7 +300,15 @@ pub struct QuerySpendableBalancesResponse {
 }
 /// QueryTotalSupplyRequest is the request type for the Query/TotalSupply RPC
 /// method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalSupplyRequest {
@@ -210,7 +322,15 @@ pub struct QueryTotalSupplyRequest {
 }
 /// QueryTotalSupplyResponse is the response type for the Query/TotalSupply RPC
 /// method
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalSupplyResponse {
@@ -226,7 +346,15 @@ pub struct QueryTotalSupplyResponse {
     >,
 }
 /// QuerySupplyOfRequest is the request type for the Query/SupplyOf RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuerySupplyOfRequest {
@@ -235,7 +363,15 @@ pub struct QuerySupplyOfRequest {
     pub denom: ::prost::alloc::string::String,
 }
 /// QuerySupplyOfResponse is the response type for the Query/SupplyOf RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuerySupplyOfResponse {
@@ -244,12 +380,28 @@ pub struct QuerySupplyOfResponse {
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// QueryParamsRequest defines the request type for querying x/bank parameters.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
 /// QueryParamsResponse defines the response type for querying x/bank parameters.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
@@ -257,7 +409,15 @@ pub struct QueryParamsResponse {
     pub params: ::core::option::Option<Params>,
 }
 /// QueryDenomsMetadataRequest is the request type for the Query/DenomsMetadata RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomsMetadataRequest {
@@ -269,7 +429,15 @@ pub struct QueryDenomsMetadataRequest {
 }
 /// QueryDenomsMetadataResponse is the response type for the Query/DenomsMetadata RPC
 /// method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomsMetadataResponse {
@@ -283,7 +451,15 @@ pub struct QueryDenomsMetadataResponse {
     >,
 }
 /// QueryDenomMetadataRequest is the request type for the Query/DenomMetadata RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomMetadataRequest {
@@ -293,7 +469,15 @@ pub struct QueryDenomMetadataRequest {
 }
 /// QueryDenomMetadataResponse is the response type for the Query/DenomMetadata RPC
 /// method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomMetadataResponse {
@@ -304,7 +488,15 @@ pub struct QueryDenomMetadataResponse {
 /// QueryDenomOwnersRequest defines the request type for the DenomOwners RPC query,
 /// which queries for a paginated set of all account holders of a particular
 /// denomination.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomOwnersRequest {
@@ -322,7 +514,15 @@ pub struct QueryDenomOwnersRequest {
 /// balance of the denominated token.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DenomOwner {
@@ -336,7 +536,15 @@ pub struct DenomOwner {
 /// QueryDenomOwnersResponse defines the RPC response of a DenomOwners RPC query.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomOwnersResponse {
@@ -359,6 +567,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -369,6 +578,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -1296,7 +1526,15 @@ pub mod query_server {
     }
 }
 /// MsgSend represents a message to send coins from one account to another.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSend {
@@ -1308,12 +1546,28 @@ pub struct MsgSend {
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
 /// MsgSendResponse defines the Msg/Send response type.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSendResponse {}
 /// MsgMultiSend represents an arbitrary multi-in, multi-out send message.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgMultiSend {
@@ -1323,7 +1577,15 @@ pub struct MsgMultiSend {
     pub outputs: ::prost::alloc::vec::Vec<Output>,
 }
 /// MsgMultiSendResponse defines the Msg/MultiSend response type.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgMultiSendResponse {}
@@ -1338,6 +1600,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -1348,6 +1611,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -1696,7 +1980,15 @@ pub mod msg_server {
     }
 }
 /// GenesisState defines the bank module's genesis state.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
@@ -1716,7 +2008,15 @@ pub struct GenesisState {
 }
 /// Balance defines an account address and balance pair used in the bank module's
 /// genesis state.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Balance {
@@ -1731,10 +2031,189 @@ pub struct Balance {
 /// the granter's account.
 ///
 /// Since: cosmos-sdk 0.43
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SendAuthorization {
     #[prost(message, repeated, tag = "1")]
     pub spend_limit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl crate::protobuf::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for SendEnabled {
+    const NAME: &'static str = "SendEnabled";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for Input {
+    const NAME: &'static str = "Input";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for Output {
+    const NAME: &'static str = "Output";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for Supply {
+    const NAME: &'static str = "Supply";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for DenomUnit {
+    const NAME: &'static str = "DenomUnit";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryBalanceRequest {
+    const NAME: &'static str = "QueryBalanceRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryBalanceResponse {
+    const NAME: &'static str = "QueryBalanceResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryAllBalancesRequest {
+    const NAME: &'static str = "QueryAllBalancesRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryAllBalancesResponse {
+    const NAME: &'static str = "QueryAllBalancesResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QuerySpendableBalancesRequest {
+    const NAME: &'static str = "QuerySpendableBalancesRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QuerySpendableBalancesResponse {
+    const NAME: &'static str = "QuerySpendableBalancesResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryTotalSupplyRequest {
+    const NAME: &'static str = "QueryTotalSupplyRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryTotalSupplyResponse {
+    const NAME: &'static str = "QueryTotalSupplyResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QuerySupplyOfRequest {
+    const NAME: &'static str = "QuerySupplyOfRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QuerySupplyOfResponse {
+    const NAME: &'static str = "QuerySupplyOfResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomsMetadataRequest {
+    const NAME: &'static str = "QueryDenomsMetadataRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomsMetadataResponse {
+    const NAME: &'static str = "QueryDenomsMetadataResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomMetadataRequest {
+    const NAME: &'static str = "QueryDenomMetadataRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomMetadataResponse {
+    const NAME: &'static str = "QueryDenomMetadataResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomOwnersRequest {
+    const NAME: &'static str = "QueryDenomOwnersRequest";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for DenomOwner {
+    const NAME: &'static str = "DenomOwner";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for QueryDenomOwnersResponse {
+    const NAME: &'static str = "QueryDenomOwnersResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for MsgSend {
+    const NAME: &'static str = "MsgSend";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for MsgSendResponse {
+    const NAME: &'static str = "MsgSendResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for MsgMultiSend {
+    const NAME: &'static str = "MsgMultiSend";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for MsgMultiSendResponse {
+    const NAME: &'static str = "MsgMultiSendResponse";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for Balance {
+    const NAME: &'static str = "Balance";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+impl crate::protobuf::Name for SendAuthorization {
+    const NAME: &'static str = "SendAuthorization";
+    const PACKAGE: &'static str = "cosmos.bank.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Params>();
+    registry.register::<SendEnabled>();
+    registry.register::<Input>();
+    registry.register::<Output>();
+    registry.register::<Supply>();
+    registry.register::<DenomUnit>();
+    registry.register::<Metadata>();
+    registry.register::<QueryBalanceRequest>();
+    registry.register::<QueryBalanceResponse>();
+    registry.register::<QueryAllBalancesRequest>();
+    registry.register::<QueryAllBalancesResponse>();
+    registry.register::<QuerySpendableBalancesRequest>();
+    registry.register::<QuerySpendableBalancesResponse>();
+    registry.register::<QueryTotalSupplyRequest>();
+    registry.register::<QueryTotalSupplyResponse>();
+    registry.register::<QuerySupplyOfRequest>();
+    registry.register::<QuerySupplyOfResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryDenomsMetadataRequest>();
+    registry.register::<QueryDenomsMetadataResponse>();
+    registry.register::<QueryDenomMetadataRequest>();
+    registry.register::<QueryDenomMetadataResponse>();
+    registry.register::<QueryDenomOwnersRequest>();
+    registry.register::<DenomOwner>();
+    registry.register::<QueryDenomOwnersResponse>();
+    registry.register::<MsgSend>();
+    registry.register::<MsgSendResponse>();
+    registry.register::<MsgMultiSend>();
+    registry.register::<MsgMultiSendResponse>();
+    registry.register::<GenesisState>();
+    registry.register::<Balance>();
+    registry.register::<SendAuthorization>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.bank.v1beta1.bin");
@@ -1,11 +1,18 @@
 /// TxResponse defines a structure containing relevant tx data and metadata. The
 /// tags are stringified and the log is JSON decoded.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxResponse {
     /// The block height
     #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub height: i64,
     /// The transaction hash.
     #[prost(string, tag = "2")]
@@ -31,9 +38,17 @@ pub struct TxResponse {
     pub info: ::prost::alloc::string::String,
     /// Amount of gas requested for transaction.
     #[prost(int64, tag = "9")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub gas_wanted: i64,
     /// Amount of gas consumed by transaction.
     #[prost(int64, tag = "10")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub gas_used: i64,
     /// The request transaction bytes.
     #[prost(message, optional, tag = "11")]
@@ -53,7 +68,11 @@ pub struct TxResponse {
     pub events: ::prost::alloc::vec::Vec<::tendermint_proto::abci::Event>,
 }
 /// ABCIMessageLog defines a structure containing an indexed tx ABCI message log.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciMessageLog {
@@ -68,7 +87,11 @@ pub struct AbciMessageLog {
 }
 /// StringEvent defines en Event object wrapper where all the attributes
 /// contain key/value pairs that are strings instead of raw bytes.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StringEvent {
@@ -79,7 +102,11 @@ pub struct StringEvent {
 }
 /// Attribute defines an attribute wrapper where the key and value are
 /// strings instead of raw bytes.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Attribute {
@@ -89,19 +116,34 @@ pub struct Attribute {
     pub value: ::prost::alloc::string::String,
 }
 /// GasInfo defines tx execution gas context.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GasInfo {
     /// GasWanted is the maximum units of work we allow this tx to perform.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub gas_wanted: u64,
     /// GasUsed is the amount of gas actually consumed.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub gas_used: u64,
 }
 /// Result is the union of ResponseFormat and ResponseCheckTx.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
@@ -111,6 +153,10 @@ pub struct Result {
     /// because it also contains the Msg response typeURL.
     #[deprecated]
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// Log contains the log information from message or handler execution.
     #[prost(string, tag = "2")]
@@ -129,7 +175,10 @@ pub struct Result {
 }
 /// SimulationResponse defines the response generated when a transaction is
 /// successfully simulated.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulationResponse {
@@ -140,18 +189,30 @@ pub struct SimulationResponse {
 }
 /// MsgData defines the data returned in a Result object during message
 /// execution.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgData {
     #[prost(string, tag = "1")]
     pub msg_type: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
 /// TxMsgData defines a list of MsgData. A transaction will have a MsgData object
 /// for each message.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxMsgData {
@@ -168,26 +229,105 @@ pub struct TxMsgData {
     >,
 }
 /// SearchTxsResult defines a structure for querying txs pageable
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchTxsResult {
     /// Count of all txs
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub total_count: u64,
     /// Count of txs in current page
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub count: u64,
     /// Index of current page, start from 1
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub page_number: u64,
     /// Count of total pages
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub page_total: u64,
     /// Max count txs per page
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub limit: u64,
     /// List of txs in current page
     #[prost(message, repeated, tag = "6")]
     pub txs: ::prost::alloc::vec::Vec<TxResponse>,
 }
+impl crate::protobuf::Name for TxResponse {
+    const NAME: &'static str = "TxResponse";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for AbciMessageLog {
+    const NAME: &'static str = "ABCIMessageLog";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for StringEvent {
+    const NAME: &'static str = "StringEvent";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for Attribute {
+    const NAME: &'static str = "Attribute";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for GasInfo {
+    const NAME: &'static str = "GasInfo";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for Result {
+    const NAME: &'static str = "Result";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for SimulationResponse {
+    const NAME: &'static str = "SimulationResponse";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for MsgData {
+    const NAME: &'static str = "MsgData";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for TxMsgData {
+    const NAME: &'static str = "TxMsgData";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+impl crate::protobuf::Name for SearchTxsResult {
+    const NAME: &'static str = "SearchTxsResult";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<TxResponse>();
+    registry.register::<AbciMessageLog>();
+    registry.register::<StringEvent>();
+    registry.register::<Attribute>();
+    registry.register::<GasInfo>();
+    registry.register::<Result>();
+    registry.register::<SimulationResponse>();
+    registry.register::<MsgData>();
+    registry.register::<TxMsgData>();
+    registry.register::<SearchTxsResult>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.abci.v1beta1.bin");
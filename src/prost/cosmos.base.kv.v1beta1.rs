@@ -1,4 +1,5 @@
 /// Pairs defines a repeated slice of Pair objects.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pairs {
@@ -6,6 +7,7 @@ pub struct Pairs {
     pub pairs: ::prost::alloc::vec::Vec<Pair>,
 }
 /// Pair defines a key/value bytes tuple.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pair {
@@ -14,3 +16,19 @@ pub struct Pair {
     #[prost(bytes = "vec", tag = "2")]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+impl crate::protobuf::Name for Pairs {
+    const NAME: &'static str = "Pairs";
+    const PACKAGE: &'static str = "cosmos.base.kv.v1beta1";
+}
+impl crate::protobuf::Name for Pair {
+    const NAME: &'static str = "Pair";
+    const PACKAGE: &'static str = "cosmos.base.kv.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Pairs>();
+    registry.register::<Pair>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.kv.v1beta1.bin");
@@ -1,8 +1,10 @@
 /// ConfigRequest defines the request structure for the Config gRPC query.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfigRequest {}
 /// ConfigResponse defines the response structure for the Config gRPC query.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfigResponse {
@@ -20,6 +22,7 @@ pub mod service_client {
     pub struct ServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl ServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -30,6 +33,27 @@ pub mod service_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl ServiceClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> ServiceClient<T>
     where
@@ -298,3 +322,19 @@ pub mod service_server {
         const NAME: &'static str = "cosmos.base.node.v1beta1.Service";
     }
 }
+impl crate::protobuf::Name for ConfigRequest {
+    const NAME: &'static str = "ConfigRequest";
+    const PACKAGE: &'static str = "cosmos.base.node.v1beta1";
+}
+impl crate::protobuf::Name for ConfigResponse {
+    const NAME: &'static str = "ConfigResponse";
+    const PACKAGE: &'static str = "cosmos.base.node.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<ConfigRequest>();
+    registry.register::<ConfigResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.node.v1beta1.bin");
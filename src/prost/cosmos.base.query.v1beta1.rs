@@ -5,7 +5,16 @@
 ///           Foo some_parameter = 1;
 ///           PageRequest pagination = 2;
 ///   }
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PageRequest {
@@ -13,15 +22,30 @@ pub struct PageRequest {
     /// querying the next page most efficiently. Only one of offset or key
     /// should be set.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     /// offset is a numeric offset that can be used when key is unavailable.
     /// It is less efficient than using key. Only one of offset or key should
     /// be set.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub offset: u64,
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub limit: u64,
     /// count_total is set to true  to indicate that the result set should include
     /// a count of the total number of items available for pagination in UIs.
@@ -42,7 +66,16 @@ pub struct PageRequest {
 ///           repeated Bar results = 1;
 ///           PageResponse page = 2;
 ///   }
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PageResponse {
@@ -50,9 +83,35 @@ pub struct PageResponse {
     /// query the next page most efficiently. It will be empty if
     /// there are no more results.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub next_key: ::prost::alloc::vec::Vec<u8>,
     /// total is total number of results available if PageRequest.count_total
     /// was set, its value is undefined otherwise
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub total: u64,
 }
+impl crate::protobuf::Name for PageRequest {
+    const NAME: &'static str = "PageRequest";
+    const PACKAGE: &'static str = "cosmos.base.query.v1beta1";
+}
+impl crate::protobuf::Name for PageResponse {
+    const NAME: &'static str = "PageResponse";
+    const PACKAGE: &'static str = "cosmos.base.query.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<PageRequest>();
+    registry.register::<PageResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.query.v1beta1.bin");
@@ -1,8 +1,10 @@
 /// ListAllInterfacesRequest is the request type of the ListAllInterfaces RPC.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListAllInterfacesRequest {}
 /// ListAllInterfacesResponse is the response type of the ListAllInterfaces RPC.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListAllInterfacesResponse {
@@ -12,6 +14,7 @@ pub struct ListAllInterfacesResponse {
 }
 /// ListImplementationsRequest is the request type of the ListImplementations
 /// RPC.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListImplementationsRequest {
@@ -21,6 +24,7 @@ pub struct ListImplementationsRequest {
 }
 /// ListImplementationsResponse is the response type of the ListImplementations
 /// RPC.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListImplementationsResponse {
@@ -40,6 +44,7 @@ pub mod reflection_service_client {
     pub struct ReflectionServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl ReflectionServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -50,6 +55,27 @@ pub mod reflection_service_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl ReflectionServiceClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> ReflectionServiceClient<T>
     where
@@ -423,3 +449,29 @@ pub mod reflection_service_server {
         const NAME: &'static str = "cosmos.base.reflection.v1beta1.ReflectionService";
     }
 }
+impl crate::protobuf::Name for ListAllInterfacesRequest {
+    const NAME: &'static str = "ListAllInterfacesRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+}
+impl crate::protobuf::Name for ListAllInterfacesResponse {
+    const NAME: &'static str = "ListAllInterfacesResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+}
+impl crate::protobuf::Name for ListImplementationsRequest {
+    const NAME: &'static str = "ListImplementationsRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+}
+impl crate::protobuf::Name for ListImplementationsResponse {
+    const NAME: &'static str = "ListImplementationsResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<ListAllInterfacesRequest>();
+    registry.register::<ListAllInterfacesResponse>();
+    registry.register::<ListImplementationsRequest>();
+    registry.register::<ListImplementationsResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.reflection.v1beta1.bin");
@@ -1,4 +1,5 @@
 /// AppDescriptor describes a cosmos-sdk based application
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppDescriptor {
@@ -23,6 +24,7 @@ pub struct AppDescriptor {
     pub tx: ::core::option::Option<TxDescriptor>,
 }
 /// TxDescriptor describes the accepted transaction type
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxDescriptor {
@@ -37,6 +39,7 @@ pub struct TxDescriptor {
 }
 /// AuthnDescriptor provides information on how to sign transactions without relying
 /// on the online RPCs GetTxMetadata and CombineUnsignedTxAndSignatures
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthnDescriptor {
@@ -48,6 +51,7 @@ pub struct AuthnDescriptor {
 /// NOTE(fdymylja): here we could go as far as providing an entire flow on how
 /// to sign a message given a SigningModeDescriptor, but it's better to think about
 /// this another time
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SigningModeDescriptor {
@@ -63,6 +67,7 @@ pub struct SigningModeDescriptor {
     pub authn_info_provider_method_fullname: ::prost::alloc::string::String,
 }
 /// ChainDescriptor describes chain information of the application
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChainDescriptor {
@@ -71,6 +76,7 @@ pub struct ChainDescriptor {
     pub id: ::prost::alloc::string::String,
 }
 /// CodecDescriptor describes the registered interfaces and provides metadata information on the types
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CodecDescriptor {
@@ -79,6 +85,7 @@ pub struct CodecDescriptor {
     pub interfaces: ::prost::alloc::vec::Vec<InterfaceDescriptor>,
 }
 /// InterfaceDescriptor describes the implementation of an interface
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceDescriptor {
@@ -96,6 +103,7 @@ pub struct InterfaceDescriptor {
     pub interface_implementers: ::prost::alloc::vec::Vec<InterfaceImplementerDescriptor>,
 }
 /// InterfaceImplementerDescriptor describes an interface implementer
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceImplementerDescriptor {
@@ -111,6 +119,7 @@ pub struct InterfaceImplementerDescriptor {
 }
 /// InterfaceAcceptingMessageDescriptor describes a protobuf message which contains
 /// an interface represented as a google.protobuf.Any
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceAcceptingMessageDescriptor {
@@ -124,6 +133,7 @@ pub struct InterfaceAcceptingMessageDescriptor {
     pub field_descriptor_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// ConfigurationDescriptor contains metadata information on the sdk.Config
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfigurationDescriptor {
@@ -132,6 +142,7 @@ pub struct ConfigurationDescriptor {
     pub bech32_account_address_prefix: ::prost::alloc::string::String,
 }
 /// MsgDescriptor describes a cosmos-sdk message that can be delivered with a transaction
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDescriptor {
@@ -140,10 +151,12 @@ pub struct MsgDescriptor {
     pub msg_type_url: ::prost::alloc::string::String,
 }
 /// GetAuthnDescriptorRequest is the request used for the GetAuthnDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetAuthnDescriptorRequest {}
 /// GetAuthnDescriptorResponse is the response returned by the GetAuthnDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetAuthnDescriptorResponse {
@@ -152,10 +165,12 @@ pub struct GetAuthnDescriptorResponse {
     pub authn: ::core::option::Option<AuthnDescriptor>,
 }
 /// GetChainDescriptorRequest is the request used for the GetChainDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetChainDescriptorRequest {}
 /// GetChainDescriptorResponse is the response returned by the GetChainDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetChainDescriptorResponse {
@@ -164,10 +179,12 @@ pub struct GetChainDescriptorResponse {
     pub chain: ::core::option::Option<ChainDescriptor>,
 }
 /// GetCodecDescriptorRequest is the request used for the GetCodecDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCodecDescriptorRequest {}
 /// GetCodecDescriptorResponse is the response returned by the GetCodecDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCodecDescriptorResponse {
@@ -176,10 +193,12 @@ pub struct GetCodecDescriptorResponse {
     pub codec: ::core::option::Option<CodecDescriptor>,
 }
 /// GetConfigurationDescriptorRequest is the request used for the GetConfigurationDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConfigurationDescriptorRequest {}
 /// GetConfigurationDescriptorResponse is the response returned by the GetConfigurationDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConfigurationDescriptorResponse {
@@ -188,10 +207,12 @@ pub struct GetConfigurationDescriptorResponse {
     pub config: ::core::option::Option<ConfigurationDescriptor>,
 }
 /// GetQueryServicesDescriptorRequest is the request used for the GetQueryServicesDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetQueryServicesDescriptorRequest {}
 /// GetQueryServicesDescriptorResponse is the response returned by the GetQueryServicesDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetQueryServicesDescriptorResponse {
@@ -200,10 +221,12 @@ pub struct GetQueryServicesDescriptorResponse {
     pub queries: ::core::option::Option<QueryServicesDescriptor>,
 }
 /// GetTxDescriptorRequest is the request used for the GetTxDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxDescriptorRequest {}
 /// GetTxDescriptorResponse is the response returned by the GetTxDescriptor RPC
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxDescriptorResponse {
@@ -213,6 +236,7 @@ pub struct GetTxDescriptorResponse {
     pub tx: ::core::option::Option<TxDescriptor>,
 }
 /// QueryServicesDescriptor contains the list of cosmos-sdk queriable services
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryServicesDescriptor {
@@ -221,6 +245,7 @@ pub struct QueryServicesDescriptor {
     pub query_services: ::prost::alloc::vec::Vec<QueryServiceDescriptor>,
 }
 /// QueryServiceDescriptor describes a cosmos-sdk queryable service
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryServiceDescriptor {
@@ -237,6 +262,7 @@ pub struct QueryServiceDescriptor {
 /// QueryMethodDescriptor describes a queryable method of a query service
 /// no other info is provided beside method name and tendermint queryable path
 /// because it would be redundant with the grpc reflection service
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryMethodDescriptor {
@@ -259,6 +285,7 @@ pub mod reflection_service_client {
     pub struct ReflectionServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl ReflectionServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -269,6 +296,27 @@ pub mod reflection_service_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl ReflectionServiceClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> ReflectionServiceClient<T>
     where
@@ -992,3 +1040,139 @@ pub mod reflection_service_server {
         const NAME: &'static str = "cosmos.base.reflection.v2alpha1.ReflectionService";
     }
 }
+impl crate::protobuf::Name for AppDescriptor {
+    const NAME: &'static str = "AppDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for TxDescriptor {
+    const NAME: &'static str = "TxDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for AuthnDescriptor {
+    const NAME: &'static str = "AuthnDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for SigningModeDescriptor {
+    const NAME: &'static str = "SigningModeDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for ChainDescriptor {
+    const NAME: &'static str = "ChainDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for CodecDescriptor {
+    const NAME: &'static str = "CodecDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for InterfaceDescriptor {
+    const NAME: &'static str = "InterfaceDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for InterfaceImplementerDescriptor {
+    const NAME: &'static str = "InterfaceImplementerDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for InterfaceAcceptingMessageDescriptor {
+    const NAME: &'static str = "InterfaceAcceptingMessageDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for ConfigurationDescriptor {
+    const NAME: &'static str = "ConfigurationDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for MsgDescriptor {
+    const NAME: &'static str = "MsgDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetAuthnDescriptorRequest {
+    const NAME: &'static str = "GetAuthnDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetAuthnDescriptorResponse {
+    const NAME: &'static str = "GetAuthnDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetChainDescriptorRequest {
+    const NAME: &'static str = "GetChainDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetChainDescriptorResponse {
+    const NAME: &'static str = "GetChainDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetCodecDescriptorRequest {
+    const NAME: &'static str = "GetCodecDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetCodecDescriptorResponse {
+    const NAME: &'static str = "GetCodecDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetConfigurationDescriptorRequest {
+    const NAME: &'static str = "GetConfigurationDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetConfigurationDescriptorResponse {
+    const NAME: &'static str = "GetConfigurationDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetQueryServicesDescriptorRequest {
+    const NAME: &'static str = "GetQueryServicesDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetQueryServicesDescriptorResponse {
+    const NAME: &'static str = "GetQueryServicesDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetTxDescriptorRequest {
+    const NAME: &'static str = "GetTxDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for GetTxDescriptorResponse {
+    const NAME: &'static str = "GetTxDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for QueryServicesDescriptor {
+    const NAME: &'static str = "QueryServicesDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for QueryServiceDescriptor {
+    const NAME: &'static str = "QueryServiceDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+impl crate::protobuf::Name for QueryMethodDescriptor {
+    const NAME: &'static str = "QueryMethodDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<AppDescriptor>();
+    registry.register::<TxDescriptor>();
+    registry.register::<AuthnDescriptor>();
+    registry.register::<SigningModeDescriptor>();
+    registry.register::<ChainDescriptor>();
+    registry.register::<CodecDescriptor>();
+    registry.register::<InterfaceDescriptor>();
+    registry.register::<InterfaceImplementerDescriptor>();
+    registry.register::<InterfaceAcceptingMessageDescriptor>();
+    registry.register::<ConfigurationDescriptor>();
+    registry.register::<MsgDescriptor>();
+    registry.register::<GetAuthnDescriptorRequest>();
+    registry.register::<GetAuthnDescriptorResponse>();
+    registry.register::<GetChainDescriptorRequest>();
+    registry.register::<GetChainDescriptorResponse>();
+    registry.register::<GetCodecDescriptorRequest>();
+    registry.register::<GetCodecDescriptorResponse>();
+    registry.register::<GetConfigurationDescriptorRequest>();
+    registry.register::<GetConfigurationDescriptorResponse>();
+    registry.register::<GetQueryServicesDescriptorRequest>();
+    registry.register::<GetQueryServicesDescriptorResponse>();
+    registry.register::<GetTxDescriptorRequest>();
+    registry.register::<GetTxDescriptorResponse>();
+    registry.register::<QueryServicesDescriptor>();
+    registry.register::<QueryServiceDescriptor>();
+    registry.register::<QueryMethodDescriptor>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.reflection.v2alpha1.bin");
@@ -1,4 +1,5 @@
 /// Snapshot contains Tendermint state sync snapshot info.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Snapshot {
@@ -14,6 +15,7 @@ pub struct Snapshot {
     pub metadata: ::core::option::Option<Metadata>,
 }
 /// Metadata contains SDK-specific snapshot metadata.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
@@ -24,6 +26,7 @@ pub struct Metadata {
 /// SnapshotItem is an item contained in a rootmulti.Store snapshot.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotItem {
@@ -34,6 +37,7 @@ pub struct SnapshotItem {
 /// Nested message and enum types in `SnapshotItem`.
 pub mod snapshot_item {
     /// item is the specific type of snapshot item.
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Item {
@@ -54,6 +58,7 @@ pub mod snapshot_item {
 /// SnapshotStoreItem contains metadata about a snapshotted store.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotStoreItem {
@@ -63,6 +68,7 @@ pub struct SnapshotStoreItem {
 /// SnapshotIAVLItem is an exported IAVL node.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotIavlItem {
@@ -80,6 +86,7 @@ pub struct SnapshotIavlItem {
 /// SnapshotExtensionMeta contains metadata about an external snapshotter.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotExtensionMeta {
@@ -91,6 +98,7 @@ pub struct SnapshotExtensionMeta {
 /// SnapshotExtensionPayload contains payloads of an external snapshotter.
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotExtensionPayload {
@@ -100,6 +108,7 @@ pub struct SnapshotExtensionPayload {
 /// SnapshotKVItem is an exported Key/Value Pair
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotKvItem {
@@ -111,9 +120,61 @@ pub struct SnapshotKvItem {
 /// SnapshotSchema is an exported schema of smt store
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotSchema {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl crate::protobuf::Name for Snapshot {
+    const NAME: &'static str = "Snapshot";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotItem {
+    const NAME: &'static str = "SnapshotItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotStoreItem {
+    const NAME: &'static str = "SnapshotStoreItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotIavlItem {
+    const NAME: &'static str = "SnapshotIavlItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotExtensionMeta {
+    const NAME: &'static str = "SnapshotExtensionMeta";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotExtensionPayload {
+    const NAME: &'static str = "SnapshotExtensionPayload";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotKvItem {
+    const NAME: &'static str = "SnapshotKVItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+impl crate::protobuf::Name for SnapshotSchema {
+    const NAME: &'static str = "SnapshotSchema";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Snapshot>();
+    registry.register::<Metadata>();
+    registry.register::<SnapshotItem>();
+    registry.register::<SnapshotStoreItem>();
+    registry.register::<SnapshotIavlItem>();
+    registry.register::<SnapshotExtensionMeta>();
+    registry.register::<SnapshotExtensionPayload>();
+    registry.register::<SnapshotKvItem>();
+    registry.register::<SnapshotSchema>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.snapshots.v1beta1.bin");
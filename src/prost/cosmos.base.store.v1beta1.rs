@@ -1,5 +1,6 @@
 /// CommitInfo defines commit information used by the multi-store when committing
 /// a version/height.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommitInfo {
@@ -10,6 +11,7 @@ pub struct CommitInfo {
 }
 /// StoreInfo defines store-specific commit information. It contains a reference
 /// between a store name and the commit ID.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreInfo {
@@ -20,6 +22,7 @@ pub struct StoreInfo {
 }
 /// CommitID defines the committment information when a specific store is
 /// committed.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommitId {
@@ -33,6 +36,7 @@ pub struct CommitId {
 /// Deletes
 ///
 /// Since: cosmos-sdk 0.43
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreKvPair {
@@ -47,3 +51,79 @@ pub struct StoreKvPair {
     #[prost(bytes = "vec", tag = "4")]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+/// BlockMetadata contains all the abci event data of a block
+/// the file streamer dump them into files together with the state changes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockMetadata {
+    #[prost(message, optional, tag = "1")]
+    pub request_begin_block: ::core::option::Option<
+        ::tendermint_proto::abci::RequestBeginBlock,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub response_begin_block: ::core::option::Option<
+        ::tendermint_proto::abci::ResponseBeginBlock,
+    >,
+    #[prost(message, repeated, tag = "3")]
+    pub deliver_txs: ::prost::alloc::vec::Vec<block_metadata::DeliverTx>,
+    #[prost(message, optional, tag = "4")]
+    pub request_end_block: ::core::option::Option<
+        ::tendermint_proto::abci::RequestEndBlock,
+    >,
+    #[prost(message, optional, tag = "5")]
+    pub response_end_block: ::core::option::Option<
+        ::tendermint_proto::abci::ResponseEndBlock,
+    >,
+    #[prost(message, optional, tag = "6")]
+    pub response_commit: ::core::option::Option<::tendermint_proto::abci::ResponseCommit>,
+}
+/// Nested message and enum types in `BlockMetadata`.
+pub mod block_metadata {
+    /// DeliverTx encapulate deliver tx request and response.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DeliverTx {
+        #[prost(message, optional, tag = "1")]
+        pub request: ::core::option::Option<::tendermint_proto::abci::RequestDeliverTx>,
+        #[prost(message, optional, tag = "2")]
+        pub response: ::core::option::Option<
+            ::tendermint_proto::abci::ResponseDeliverTx,
+        >,
+    }
+}
+impl crate::protobuf::Name for CommitInfo {
+    const NAME: &'static str = "CommitInfo";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+impl crate::protobuf::Name for StoreInfo {
+    const NAME: &'static str = "StoreInfo";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+impl crate::protobuf::Name for CommitId {
+    const NAME: &'static str = "CommitID";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+impl crate::protobuf::Name for StoreKvPair {
+    const NAME: &'static str = "StoreKVPair";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+impl crate::protobuf::Name for BlockMetadata {
+    const NAME: &'static str = "BlockMetadata";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+impl crate::protobuf::Name for block_metadata::DeliverTx {
+    const NAME: &'static str = "BlockMetadata.DeliverTx";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<CommitInfo>();
+    registry.register::<StoreInfo>();
+    registry.register::<CommitId>();
+    registry.register::<StoreKvPair>();
+    registry.register::<BlockMetadata>();
+    registry.register::<block_metadata::DeliverTx>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.store.v1beta1.bin");
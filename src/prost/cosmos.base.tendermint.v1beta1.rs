@@ -70,6 +70,7 @@ pub struct Header {
 }
 /// GetValidatorSetByHeightRequest is the request type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetValidatorSetByHeightRequest {
@@ -81,6 +82,7 @@ pub struct GetValidatorSetByHeightRequest {
 }
 /// GetValidatorSetByHeightResponse is the response type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetValidatorSetByHeightResponse {
@@ -94,6 +96,7 @@ pub struct GetValidatorSetByHeightResponse {
 }
 /// GetLatestValidatorSetRequest is the request type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestValidatorSetRequest {
@@ -103,6 +106,7 @@ pub struct GetLatestValidatorSetRequest {
 }
 /// GetLatestValidatorSetResponse is the response type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestValidatorSetResponse {
@@ -115,6 +119,7 @@ pub struct GetLatestValidatorSetResponse {
     pub pagination: ::core::option::Option<super::super::query::v1beta1::PageResponse>,
 }
 /// Validator is the type for the validator-set.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Validator {
@@ -131,6 +136,7 @@ pub struct Validator {
 }
 /// GetBlockByHeightRequest is the request type for the Query/GetBlockByHeight
 /// RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockByHeightRequest {
@@ -145,14 +151,17 @@ pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "1")]
     pub block_id: ::core::option::Option<::tendermint_proto::types::BlockId>,
     /// Deprecated: please use `sdk_block` instead
-    #[prost(message, optional, tag = "2")]
-    pub block: ::core::option::Option<::tendermint_proto::types::Block>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub block: ::core::option::Option<
+        ::prost::alloc::boxed::Box<::tendermint_proto::types::Block>,
+    >,
     /// Since: cosmos-sdk 0.47
     #[prost(message, optional, tag = "3")]
     pub sdk_block: ::core::option::Option<Block>,
 }
 /// GetLatestBlockRequest is the request type for the Query/GetLatestBlock RPC
 /// method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestBlockRequest {}
@@ -164,17 +173,21 @@ pub struct GetLatestBlockResponse {
     #[prost(message, optional, tag = "1")]
     pub block_id: ::core::option::Option<::tendermint_proto::types::BlockId>,
     /// Deprecated: please use `sdk_block` instead
-    #[prost(message, optional, tag = "2")]
-    pub block: ::core::option::Option<::tendermint_proto::types::Block>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub block: ::core::option::Option<
+        ::prost::alloc::boxed::Box<::tendermint_proto::types::Block>,
+    >,
     /// Since: cosmos-sdk 0.47
     #[prost(message, optional, tag = "3")]
     pub sdk_block: ::core::option::Option<Block>,
 }
 /// GetSyncingRequest is the request type for the Query/GetSyncing RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetSyncingRequest {}
 /// GetSyncingResponse is the response type for the Query/GetSyncing RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetSyncingResponse {
@@ -182,6 +195,7 @@ pub struct GetSyncingResponse {
     pub syncing: bool,
 }
 /// GetNodeInfoRequest is the request type for the Query/GetNodeInfo RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNodeInfoRequest {}
@@ -198,6 +212,7 @@ pub struct GetNodeInfoResponse {
     pub application_version: ::core::option::Option<VersionInfo>,
 }
 /// VersionInfo is the type for the GetNodeInfoResponse message.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VersionInfo {
@@ -220,6 +235,7 @@ pub struct VersionInfo {
     pub cosmos_sdk_version: ::prost::alloc::string::String,
 }
 /// Module is the type for VersionInfo
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Module {
@@ -234,6 +250,7 @@ pub struct Module {
     pub sum: ::prost::alloc::string::String,
 }
 /// ABCIQueryRequest defines the request structure for the ABCIQuery gRPC query.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciQueryRequest {
@@ -251,6 +268,7 @@ pub struct AbciQueryRequest {
 ///
 /// Note: This type is a duplicate of the ResponseQuery proto type defined in
 /// Tendermint.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciQueryResponse {
@@ -281,6 +299,7 @@ pub struct AbciQueryResponse {
 ///
 /// Note: This type is a duplicate of the ProofOp proto type defined in
 /// Tendermint.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOp {
@@ -295,6 +314,7 @@ pub struct ProofOp {
 ///
 /// Note: This type is a duplicate of the ProofOps proto type defined in
 /// Tendermint.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOps {
@@ -312,6 +332,7 @@ pub mod service_client {
     pub struct ServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl ServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -322,6 +343,27 @@ pub mod service_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl ServiceClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> ServiceClient<T>
     where
@@ -1119,3 +1161,114 @@ pub mod service_server {
         const NAME: &'static str = "cosmos.base.tendermint.v1beta1.Service";
     }
 }
+impl crate::protobuf::Name for Block {
+    const NAME: &'static str = "Block";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetValidatorSetByHeightRequest {
+    const NAME: &'static str = "GetValidatorSetByHeightRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetValidatorSetByHeightResponse {
+    const NAME: &'static str = "GetValidatorSetByHeightResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetLatestValidatorSetRequest {
+    const NAME: &'static str = "GetLatestValidatorSetRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetLatestValidatorSetResponse {
+    const NAME: &'static str = "GetLatestValidatorSetResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetBlockByHeightRequest {
+    const NAME: &'static str = "GetBlockByHeightRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetBlockByHeightResponse {
+    const NAME: &'static str = "GetBlockByHeightResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetLatestBlockRequest {
+    const NAME: &'static str = "GetLatestBlockRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetLatestBlockResponse {
+    const NAME: &'static str = "GetLatestBlockResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetSyncingRequest {
+    const NAME: &'static str = "GetSyncingRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetSyncingResponse {
+    const NAME: &'static str = "GetSyncingResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetNodeInfoRequest {
+    const NAME: &'static str = "GetNodeInfoRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for GetNodeInfoResponse {
+    const NAME: &'static str = "GetNodeInfoResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for VersionInfo {
+    const NAME: &'static str = "VersionInfo";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for Module {
+    const NAME: &'static str = "Module";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for AbciQueryRequest {
+    const NAME: &'static str = "ABCIQueryRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for AbciQueryResponse {
+    const NAME: &'static str = "ABCIQueryResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for ProofOp {
+    const NAME: &'static str = "ProofOp";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+impl crate::protobuf::Name for ProofOps {
+    const NAME: &'static str = "ProofOps";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Block>();
+    registry.register::<Header>();
+    registry.register::<GetValidatorSetByHeightRequest>();
+    registry.register::<GetValidatorSetByHeightResponse>();
+    registry.register::<GetLatestValidatorSetRequest>();
+    registry.register::<GetLatestValidatorSetResponse>();
+    registry.register::<Validator>();
+    registry.register::<GetBlockByHeightRequest>();
+    registry.register::<GetBlockByHeightResponse>();
+    registry.register::<GetLatestBlockRequest>();
+    registry.register::<GetLatestBlockResponse>();
+    registry.register::<GetSyncingRequest>();
+    registry.register::<GetSyncingResponse>();
+    registry.register::<GetNodeInfoRequest>();
+    registry.register::<GetNodeInfoResponse>();
+    registry.register::<VersionInfo>();
+    registry.register::<Module>();
+    registry.register::<AbciQueryRequest>();
+    registry.register::<AbciQueryResponse>();
+    registry.register::<ProofOp>();
+    registry.register::<ProofOps>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.base.tendermint.v1beta1.bin");
@@ -0,0 +1,53 @@
+/// PubKey is an ed25519 public key for handling Tendermint keys in SDK.
+/// It's needed for Any serialization and SDK compatibility.
+/// It must not be used in a non Tendermint key context because it doesn't implement
+/// ADR-28. Nevertheless, you will like to use ed25519 in app user level
+/// then you must create a new proto message and follow ADR-28 for Address construction.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+}
+/// Deprecated: PrivKey defines a ed25519 private key.
+/// NOTE: ed25519 keys must not be used in SDK apps except in a tendermint validator context.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrivKey {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+}
+impl crate::protobuf::Name for PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.ed25519";
+}
+impl crate::protobuf::Name for PrivKey {
+    const NAME: &'static str = "PrivKey";
+    const PACKAGE: &'static str = "cosmos.crypto.ed25519";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<PubKey>();
+    registry.register::<PrivKey>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.crypto.ed25519.bin");
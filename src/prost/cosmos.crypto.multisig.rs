@@ -0,0 +1,29 @@
+/// LegacyAminoPubKey specifies a public key type
+/// which nests multiple public keys and a threshold,
+/// it uses legacy amino address rules.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LegacyAminoPubKey {
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub public_keys: ::prost::alloc::vec::Vec<
+        super::super::super::google::protobuf::Any,
+    >,
+}
+impl crate::protobuf::Name for LegacyAminoPubKey {
+    const NAME: &'static str = "LegacyAminoPubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<LegacyAminoPubKey>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.crypto.multisig.bin");
@@ -1,23 +1,55 @@
 /// MultiSignature wraps the signatures from a multisig.LegacyAminoPubKey.
 /// See cosmos.tx.v1betata1.ModeInfo.Multi for how to specify which signers
 /// signed and with which modes.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MultiSignature {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64_vec")
+    )]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 /// CompactBitArray is an implementation of a space efficient bit array.
 /// This is used to ensure that the encoded data takes up a minimal amount of
 /// space after proto encoding.
 /// This is not thread safe, and is not intended for concurrent usage.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompactBitArray {
     #[prost(uint32, tag = "1")]
     pub extra_bits_stored: u32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub elems: ::prost::alloc::vec::Vec<u8>,
 }
+impl crate::protobuf::Name for MultiSignature {
+    const NAME: &'static str = "MultiSignature";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig.v1beta1";
+}
+impl crate::protobuf::Name for CompactBitArray {
+    const NAME: &'static str = "CompactBitArray";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<MultiSignature>();
+    registry.register::<CompactBitArray>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.crypto.multisig.v1beta1.bin");
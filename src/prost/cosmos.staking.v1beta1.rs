@@ -12,6 +12,7 @@ pub struct HistoricalInfo {
 }
 /// CommissionRates defines the initial commission rates to be used for creating
 /// a validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommissionRates {
@@ -26,6 +27,7 @@ pub struct CommissionRates {
     pub max_change_rate: ::prost::alloc::string::String,
 }
 /// Commission defines commission parameters for a given validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Commission {
@@ -39,6 +41,7 @@ pub struct Commission {
     >,
 }
 /// Description defines a validator description.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Description {
@@ -66,6 +69,7 @@ pub struct Description {
 /// bond shares is based on the amount of coins delegated divided by the current
 /// exchange rate. Voting power can be calculated as total bonded shares
 /// multiplied by exchange rate.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Validator {
@@ -110,6 +114,7 @@ pub struct Validator {
     pub min_self_delegation: ::prost::alloc::string::String,
 }
 /// ValAddresses defines a repeated set of validator addresses.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValAddresses {
@@ -119,6 +124,7 @@ pub struct ValAddresses {
 /// DVPair is struct that just has a delegator-validator pair with no other data.
 /// It is intended to be used as a marshalable pointer. For example, a DVPair can
 /// be used to construct the key to getting an UnbondingDelegation from state.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvPair {
@@ -128,6 +134,7 @@ pub struct DvPair {
     pub validator_address: ::prost::alloc::string::String,
 }
 /// DVPairs defines an array of DVPair objects.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvPairs {
@@ -138,6 +145,7 @@ pub struct DvPairs {
 /// with no other data. It is intended to be used as a marshalable pointer. For
 /// example, a DVVTriplet can be used to construct the key to getting a
 /// Redelegation from state.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvvTriplet {
@@ -149,6 +157,7 @@ pub struct DvvTriplet {
     pub validator_dst_address: ::prost::alloc::string::String,
 }
 /// DVVTriplets defines an array of DVVTriplet objects.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvvTriplets {
@@ -158,6 +167,7 @@ pub struct DvvTriplets {
 /// Delegation represents the bond with tokens held by an account. It is
 /// owned by one delegator, and is associated with the voting power of one
 /// validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Delegation {
@@ -173,6 +183,7 @@ pub struct Delegation {
 }
 /// UnbondingDelegation stores all of a single delegator's unbonding bonds
 /// for a single validator in an time-ordered list.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingDelegation {
@@ -189,6 +200,7 @@ pub struct UnbondingDelegation {
     pub entries: ::prost::alloc::vec::Vec<UnbondingDelegationEntry>,
 }
 /// UnbondingDelegationEntry defines an unbonding object with relevant metadata.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingDelegationEntry {
@@ -208,6 +220,7 @@ pub struct UnbondingDelegationEntry {
     pub balance: ::prost::alloc::string::String,
 }
 /// RedelegationEntry defines a redelegation object with relevant metadata.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationEntry {
@@ -228,6 +241,7 @@ pub struct RedelegationEntry {
 }
 /// Redelegation contains the list of a particular delegator's redelegating bonds
 /// from a particular source validator to a particular destination validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Redelegation {
@@ -247,6 +261,7 @@ pub struct Redelegation {
     pub entries: ::prost::alloc::vec::Vec<RedelegationEntry>,
 }
 /// Params defines the parameters for the staking module.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
@@ -273,6 +288,7 @@ pub struct Params {
 }
 /// DelegationResponse is equivalent to Delegation except that it contains a
 /// balance in addition to shares which is more suitable for client responses.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegationResponse {
@@ -284,6 +300,7 @@ pub struct DelegationResponse {
 /// RedelegationEntryResponse is equivalent to a RedelegationEntry except that it
 /// contains a balance in addition to shares which is more suitable for client
 /// responses.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationEntryResponse {
@@ -295,6 +312,7 @@ pub struct RedelegationEntryResponse {
 /// RedelegationResponse is equivalent to a Redelegation except that its entries
 /// contain a balance in addition to shares which is more suitable for client
 /// responses.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationResponse {
@@ -305,6 +323,7 @@ pub struct RedelegationResponse {
 }
 /// Pool is used for tracking bonded and not-bonded token supply of the bond
 /// denomination.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pool {
@@ -391,6 +410,7 @@ impl InfractionType {
     }
 }
 /// QueryValidatorsRequest is request type for Query/Validators RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorsRequest {
@@ -404,6 +424,7 @@ pub struct QueryValidatorsRequest {
     >,
 }
 /// QueryValidatorsResponse is response type for the Query/Validators RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorsResponse {
@@ -417,6 +438,7 @@ pub struct QueryValidatorsResponse {
     >,
 }
 /// QueryValidatorRequest is response type for the Query/Validator RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorRequest {
@@ -425,6 +447,7 @@ pub struct QueryValidatorRequest {
     pub validator_addr: ::prost::alloc::string::String,
 }
 /// QueryValidatorResponse is response type for the Query/Validator RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorResponse {
@@ -434,6 +457,7 @@ pub struct QueryValidatorResponse {
 }
 /// QueryValidatorDelegationsRequest is request type for the
 /// Query/ValidatorDelegations RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorDelegationsRequest {
@@ -448,6 +472,7 @@ pub struct QueryValidatorDelegationsRequest {
 }
 /// QueryValidatorDelegationsResponse is response type for the
 /// Query/ValidatorDelegations RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorDelegationsResponse {
@@ -461,6 +486,7 @@ pub struct QueryValidatorDelegationsResponse {
 }
 /// QueryValidatorUnbondingDelegationsRequest is required type for the
 /// Query/ValidatorUnbondingDelegations RPC method
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorUnbondingDelegationsRequest {
@@ -475,6 +501,7 @@ pub struct QueryValidatorUnbondingDelegationsRequest {
 }
 /// QueryValidatorUnbondingDelegationsResponse is response type for the
 /// Query/ValidatorUnbondingDelegations RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorUnbondingDelegationsResponse {
@@ -487,6 +514,7 @@ pub struct QueryValidatorUnbondingDelegationsResponse {
     >,
 }
 /// QueryDelegationRequest is request type for the Query/Delegation RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationRequest {
@@ -498,6 +526,7 @@ pub struct QueryDelegationRequest {
     pub validator_addr: ::prost::alloc::string::String,
 }
 /// QueryDelegationResponse is response type for the Query/Delegation RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationResponse {
@@ -507,6 +536,7 @@ pub struct QueryDelegationResponse {
 }
 /// QueryUnbondingDelegationRequest is request type for the
 /// Query/UnbondingDelegation RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryUnbondingDelegationRequest {
@@ -519,6 +549,7 @@ pub struct QueryUnbondingDelegationRequest {
 }
 /// QueryDelegationResponse is response type for the Query/UnbondingDelegation
 /// RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryUnbondingDelegationResponse {
@@ -528,6 +559,7 @@ pub struct QueryUnbondingDelegationResponse {
 }
 /// QueryDelegatorDelegationsRequest is request type for the
 /// Query/DelegatorDelegations RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorDelegationsRequest {
@@ -542,6 +574,7 @@ pub struct QueryDelegatorDelegationsRequest {
 }
 /// QueryDelegatorDelegationsResponse is response type for the
 /// Query/DelegatorDelegations RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorDelegationsResponse {
@@ -556,6 +589,7 @@ pub struct QueryDelegatorDelegationsResponse {
 }
 /// QueryDelegatorUnbondingDelegationsRequest is request type for the
 /// Query/DelegatorUnbondingDelegations RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorUnbondingDelegationsRequest {
@@ -570,6 +604,7 @@ pub struct QueryDelegatorUnbondingDelegationsRequest {
 }
 /// QueryUnbondingDelegatorDelegationsResponse is response type for the
 /// Query/UnbondingDelegatorDelegations RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorUnbondingDelegationsResponse {
@@ -583,6 +618,7 @@ pub struct QueryDelegatorUnbondingDelegationsResponse {
 }
 /// QueryRedelegationsRequest is request type for the Query/Redelegations RPC
 /// method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRedelegationsRequest {
@@ -603,6 +639,7 @@ pub struct QueryRedelegationsRequest {
 }
 /// QueryRedelegationsResponse is response type for the Query/Redelegations RPC
 /// method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRedelegationsResponse {
@@ -616,6 +653,7 @@ pub struct QueryRedelegationsResponse {
 }
 /// QueryDelegatorValidatorsRequest is request type for the
 /// Query/DelegatorValidators RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsRequest {
@@ -630,6 +668,7 @@ pub struct QueryDelegatorValidatorsRequest {
 }
 /// QueryDelegatorValidatorsResponse is response type for the
 /// Query/DelegatorValidators RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsResponse {
@@ -644,6 +683,7 @@ pub struct QueryDelegatorValidatorsResponse {
 }
 /// QueryDelegatorValidatorRequest is request type for the
 /// Query/DelegatorValidator RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorRequest {
@@ -656,6 +696,7 @@ pub struct QueryDelegatorValidatorRequest {
 }
 /// QueryDelegatorValidatorResponse response type for the
 /// Query/DelegatorValidator RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorResponse {
@@ -665,6 +706,7 @@ pub struct QueryDelegatorValidatorResponse {
 }
 /// QueryHistoricalInfoRequest is request type for the Query/HistoricalInfo RPC
 /// method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryHistoricalInfoRequest {
@@ -682,10 +724,12 @@ pub struct QueryHistoricalInfoResponse {
     pub hist: ::core::option::Option<HistoricalInfo>,
 }
 /// QueryPoolRequest is request type for the Query/Pool RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPoolRequest {}
 /// QueryPoolResponse is response type for the Query/Pool RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPoolResponse {
@@ -694,10 +738,12 @@ pub struct QueryPoolResponse {
     pub pool: ::core::option::Option<Pool>,
 }
 /// QueryParamsRequest is request type for the Query/Params RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
 /// QueryParamsResponse is response type for the Query/Params RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
@@ -716,6 +762,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -726,6 +773,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -2097,6 +2165,7 @@ pub mod query_server {
     }
 }
 /// MsgCreateValidator defines a SDK message for creating a new validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCreateValidator {
@@ -2116,10 +2185,12 @@ pub struct MsgCreateValidator {
     pub value: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// MsgCreateValidatorResponse defines the Msg/CreateValidator response type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCreateValidatorResponse {}
 /// MsgEditValidator defines a SDK message for editing an existing validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgEditValidator {
@@ -2137,11 +2208,13 @@ pub struct MsgEditValidator {
     pub min_self_delegation: ::prost::alloc::string::String,
 }
 /// MsgEditValidatorResponse defines the Msg/EditValidator response type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgEditValidatorResponse {}
 /// MsgDelegate defines a SDK message for performing a delegation of coins
 /// from a delegator to a validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDelegate {
@@ -2153,11 +2226,13 @@ pub struct MsgDelegate {
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// MsgDelegateResponse defines the Msg/Delegate response type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDelegateResponse {}
 /// MsgBeginRedelegate defines a SDK message for performing a redelegation
 /// of coins from a delegator and source validator to a destination validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgBeginRedelegate {
@@ -2171,6 +2246,7 @@ pub struct MsgBeginRedelegate {
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// MsgBeginRedelegateResponse defines the Msg/BeginRedelegate response type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgBeginRedelegateResponse {
@@ -2181,6 +2257,7 @@ pub struct MsgBeginRedelegateResponse {
 }
 /// MsgUndelegate defines a SDK message for performing an undelegation from a
 /// delegate and a validator.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgUndelegate {
@@ -2192,6 +2269,7 @@ pub struct MsgUndelegate {
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
 /// MsgUndelegateResponse defines the Msg/Undelegate response type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgUndelegateResponse {
@@ -2203,6 +2281,7 @@ pub struct MsgUndelegateResponse {
 /// MsgCancelUnbondingDelegation defines the SDK message for performing a cancel unbonding delegation for delegator
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCancelUnbondingDelegation {
@@ -2220,6 +2299,7 @@ pub struct MsgCancelUnbondingDelegation {
 /// MsgCancelUnbondingDelegationResponse
 ///
 /// Since: cosmos-sdk 0.46
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCancelUnbondingDelegationResponse {}
@@ -2234,6 +2314,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -2244,6 +2325,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -2930,6 +3032,7 @@ pub mod msg_server {
     }
 }
 /// GenesisState defines the staking module's genesis state.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
@@ -2960,6 +3063,7 @@ pub struct GenesisState {
     pub exported: bool,
 }
 /// LastValidatorPower required for validator set update logic.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LastValidatorPower {
@@ -2973,6 +3077,7 @@ pub struct LastValidatorPower {
 /// StakeAuthorization defines authorization for delegate/undelegate/redelegate.
 ///
 /// Since: cosmos-sdk 0.43
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StakeAuthorization {
@@ -2990,6 +3095,7 @@ pub struct StakeAuthorization {
 /// Nested message and enum types in `StakeAuthorization`.
 pub mod stake_authorization {
     /// Validators defines list of validator addresses.
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ValidatorsVec {
@@ -2997,6 +3103,7 @@ pub mod stake_authorization {
         pub address: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     }
     /// validators is the oneof that represents either allow_list or deny_list
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Validators {
@@ -3048,3 +3155,334 @@ impl AuthorizationType {
         }
     }
 }
+impl crate::protobuf::Name for HistoricalInfo {
+    const NAME: &'static str = "HistoricalInfo";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for CommissionRates {
+    const NAME: &'static str = "CommissionRates";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Commission {
+    const NAME: &'static str = "Commission";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Description {
+    const NAME: &'static str = "Description";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for ValAddresses {
+    const NAME: &'static str = "ValAddresses";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for DvPair {
+    const NAME: &'static str = "DVPair";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for DvPairs {
+    const NAME: &'static str = "DVPairs";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for DvvTriplet {
+    const NAME: &'static str = "DVVTriplet";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for DvvTriplets {
+    const NAME: &'static str = "DVVTriplets";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Delegation {
+    const NAME: &'static str = "Delegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for UnbondingDelegation {
+    const NAME: &'static str = "UnbondingDelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for UnbondingDelegationEntry {
+    const NAME: &'static str = "UnbondingDelegationEntry";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for RedelegationEntry {
+    const NAME: &'static str = "RedelegationEntry";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Redelegation {
+    const NAME: &'static str = "Redelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for DelegationResponse {
+    const NAME: &'static str = "DelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for RedelegationEntryResponse {
+    const NAME: &'static str = "RedelegationEntryResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for RedelegationResponse {
+    const NAME: &'static str = "RedelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for Pool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for ValidatorUpdates {
+    const NAME: &'static str = "ValidatorUpdates";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorsRequest {
+    const NAME: &'static str = "QueryValidatorsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorsResponse {
+    const NAME: &'static str = "QueryValidatorsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorRequest {
+    const NAME: &'static str = "QueryValidatorRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorResponse {
+    const NAME: &'static str = "QueryValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorDelegationsRequest {
+    const NAME: &'static str = "QueryValidatorDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorDelegationsResponse {
+    const NAME: &'static str = "QueryValidatorDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorUnbondingDelegationsRequest {
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryValidatorUnbondingDelegationsResponse {
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegationRequest {
+    const NAME: &'static str = "QueryDelegationRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegationResponse {
+    const NAME: &'static str = "QueryDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryUnbondingDelegationRequest {
+    const NAME: &'static str = "QueryUnbondingDelegationRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryUnbondingDelegationResponse {
+    const NAME: &'static str = "QueryUnbondingDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorDelegationsRequest {
+    const NAME: &'static str = "QueryDelegatorDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorDelegationsResponse {
+    const NAME: &'static str = "QueryDelegatorDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorUnbondingDelegationsRequest {
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorUnbondingDelegationsResponse {
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryRedelegationsRequest {
+    const NAME: &'static str = "QueryRedelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryRedelegationsResponse {
+    const NAME: &'static str = "QueryRedelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorValidatorsRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorValidatorsResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorValidatorRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryDelegatorValidatorResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryHistoricalInfoRequest {
+    const NAME: &'static str = "QueryHistoricalInfoRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryHistoricalInfoResponse {
+    const NAME: &'static str = "QueryHistoricalInfoResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryPoolRequest {
+    const NAME: &'static str = "QueryPoolRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryPoolResponse {
+    const NAME: &'static str = "QueryPoolResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgCreateValidator {
+    const NAME: &'static str = "MsgCreateValidator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgCreateValidatorResponse {
+    const NAME: &'static str = "MsgCreateValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgEditValidator {
+    const NAME: &'static str = "MsgEditValidator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgEditValidatorResponse {
+    const NAME: &'static str = "MsgEditValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgDelegate {
+    const NAME: &'static str = "MsgDelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgDelegateResponse {
+    const NAME: &'static str = "MsgDelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgBeginRedelegate {
+    const NAME: &'static str = "MsgBeginRedelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgBeginRedelegateResponse {
+    const NAME: &'static str = "MsgBeginRedelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgUndelegate {
+    const NAME: &'static str = "MsgUndelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgUndelegateResponse {
+    const NAME: &'static str = "MsgUndelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgCancelUnbondingDelegation {
+    const NAME: &'static str = "MsgCancelUnbondingDelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for MsgCancelUnbondingDelegationResponse {
+    const NAME: &'static str = "MsgCancelUnbondingDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for LastValidatorPower {
+    const NAME: &'static str = "LastValidatorPower";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for StakeAuthorization {
+    const NAME: &'static str = "StakeAuthorization";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+impl crate::protobuf::Name for stake_authorization::ValidatorsVec {
+    const NAME: &'static str = "StakeAuthorization.Validators";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<HistoricalInfo>();
+    registry.register::<CommissionRates>();
+    registry.register::<Commission>();
+    registry.register::<Description>();
+    registry.register::<Validator>();
+    registry.register::<ValAddresses>();
+    registry.register::<DvPair>();
+    registry.register::<DvPairs>();
+    registry.register::<DvvTriplet>();
+    registry.register::<DvvTriplets>();
+    registry.register::<Delegation>();
+    registry.register::<UnbondingDelegation>();
+    registry.register::<UnbondingDelegationEntry>();
+    registry.register::<RedelegationEntry>();
+    registry.register::<Redelegation>();
+    registry.register::<Params>();
+    registry.register::<DelegationResponse>();
+    registry.register::<RedelegationEntryResponse>();
+    registry.register::<RedelegationResponse>();
+    registry.register::<Pool>();
+    registry.register::<ValidatorUpdates>();
+    registry.register::<QueryValidatorsRequest>();
+    registry.register::<QueryValidatorsResponse>();
+    registry.register::<QueryValidatorRequest>();
+    registry.register::<QueryValidatorResponse>();
+    registry.register::<QueryValidatorDelegationsRequest>();
+    registry.register::<QueryValidatorDelegationsResponse>();
+    registry.register::<QueryValidatorUnbondingDelegationsRequest>();
+    registry.register::<QueryValidatorUnbondingDelegationsResponse>();
+    registry.register::<QueryDelegationRequest>();
+    registry.register::<QueryDelegationResponse>();
+    registry.register::<QueryUnbondingDelegationRequest>();
+    registry.register::<QueryUnbondingDelegationResponse>();
+    registry.register::<QueryDelegatorDelegationsRequest>();
+    registry.register::<QueryDelegatorDelegationsResponse>();
+    registry.register::<QueryDelegatorUnbondingDelegationsRequest>();
+    registry.register::<QueryDelegatorUnbondingDelegationsResponse>();
+    registry.register::<QueryRedelegationsRequest>();
+    registry.register::<QueryRedelegationsResponse>();
+    registry.register::<QueryDelegatorValidatorsRequest>();
+    registry.register::<QueryDelegatorValidatorsResponse>();
+    registry.register::<QueryDelegatorValidatorRequest>();
+    registry.register::<QueryDelegatorValidatorResponse>();
+    registry.register::<QueryHistoricalInfoRequest>();
+    registry.register::<QueryHistoricalInfoResponse>();
+    registry.register::<QueryPoolRequest>();
+    registry.register::<QueryPoolResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<MsgCreateValidator>();
+    registry.register::<MsgCreateValidatorResponse>();
+    registry.register::<MsgEditValidator>();
+    registry.register::<MsgEditValidatorResponse>();
+    registry.register::<MsgDelegate>();
+    registry.register::<MsgDelegateResponse>();
+    registry.register::<MsgBeginRedelegate>();
+    registry.register::<MsgBeginRedelegateResponse>();
+    registry.register::<MsgUndelegate>();
+    registry.register::<MsgUndelegateResponse>();
+    registry.register::<MsgCancelUnbondingDelegation>();
+    registry.register::<MsgCancelUnbondingDelegationResponse>();
+    registry.register::<GenesisState>();
+    registry.register::<LastValidatorPower>();
+    registry.register::<StakeAuthorization>();
+    registry.register::<stake_authorization::ValidatorsVec>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.staking.v1beta1.bin");
@@ -1,5 +1,150 @@
+/// TextualData represents all the information needed to generate
+/// the textual SignDoc (which is []Screen encoded to XBOR). It is meant to be
+/// used as an internal type in Textual's implementations.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextualData {
+    /// body_bytes is a protobuf serialization of a TxBody that matches the
+    /// representation in SignDoc.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub body_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
+    /// representation in SignDoc.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub auth_info_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// signer_data represents all data in SignerData.
+    #[prost(message, optional, tag = "3")]
+    pub signer_data: ::core::option::Option<SignerData>,
+}
+/// SignerData represents all data in the SignerData the transaction is signed
+/// with, ie. the data of the signer which is not part of the transaction.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignerData {
+    /// address is the Bech32 address of the signer.
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// chain_id is the chain that this transaction is targeting.
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// account_number is the account number of the signer.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub account_number: u64,
+    /// sequence is the account sequence of the signer.
+    #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub sequence: u64,
+    /// pub_key is the public key of the signer.
+    #[prost(message, optional, tag = "5")]
+    pub pub_key: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+}
+/// Envelope is an internal data structure used to generate the tx envelope
+/// screens. It contains all the information of the transaction, in a flat
+/// structure which is rendered field by field.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Envelope {
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub account_number: u64,
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub sequence: u64,
+    #[prost(string, tag = "4")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub public_key: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(message, repeated, tag = "6")]
+    pub message: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(string, tag = "7")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "8")]
+    pub fees: ::prost::alloc::vec::Vec<super::super::super::base::v1beta1::Coin>,
+    #[prost(string, tag = "9")]
+    pub fee_payer: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub fee_granter: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "11")]
+    pub tip: ::prost::alloc::vec::Vec<super::super::super::base::v1beta1::Coin>,
+    #[prost(string, tag = "12")]
+    pub tipper: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "13")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "14")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    pub timeout_height: u64,
+    #[prost(message, repeated, tag = "15")]
+    pub other_signer: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(message, repeated, tag = "16")]
+    pub extension_options: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(message, repeated, tag = "17")]
+    pub non_critical_extension_options: ::prost::alloc::vec::Vec<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(string, tag = "18")]
+    pub hash_of_raw_bytes: ::prost::alloc::string::String,
+}
 /// SignatureDescriptors wraps multiple SignatureDescriptor's.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignatureDescriptors {
@@ -11,7 +156,11 @@ pub struct SignatureDescriptors {
 /// a signature including the public key of the signer, signing modes and the
 /// signature itself. It is primarily used for coordinating signatures between
 /// clients.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignatureDescriptor {
@@ -26,12 +175,20 @@ pub struct SignatureDescriptor {
     /// number of committed transactions signed by a given address. It is used to prevent
     /// replay attacks.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub sequence: u64,
 }
 /// Nested message and enum types in `SignatureDescriptor`.
 pub mod signature_descriptor {
     /// Data represents signature data
-    #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        derive(::serde::Serialize, ::serde::Deserialize)
+    )]
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Data {
@@ -42,7 +199,11 @@ pub mod signature_descriptor {
     /// Nested message and enum types in `Data`.
     pub mod data {
         /// Single is the signature data for a single signer
-        #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(
+            all(feature = "std", not(feature = "json")),
+            derive(::serde::Serialize, ::serde::Deserialize)
+        )]
+        #[derive(Eq, Hash)]
         #[allow(clippy::derive_partial_eq_without_eq)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct Single {
@@ -51,10 +212,18 @@ pub mod signature_descriptor {
             pub mode: i32,
             /// signature is the raw signature bytes
             #[prost(bytes = "vec", tag = "2")]
+            #[cfg_attr(
+                all(feature = "std", not(feature = "json")),
+                serde(with = "crate::serializers::base64")
+            )]
             pub signature: ::prost::alloc::vec::Vec<u8>,
         }
         /// Multi is the signature data for a multisig public key
-        #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(
+            all(feature = "std", not(feature = "json")),
+            derive(::serde::Serialize, ::serde::Deserialize)
+        )]
+        #[derive(Eq, Hash)]
         #[allow(clippy::derive_partial_eq_without_eq)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct Multi {
@@ -68,7 +237,11 @@ pub mod signature_descriptor {
             pub signatures: ::prost::alloc::vec::Vec<super::Data>,
         }
         /// sum is the oneof that specifies whether this represents single or multi-signature data
-        #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(
+            all(feature = "std", not(feature = "json")),
+            derive(::serde::Serialize, ::serde::Deserialize)
+        )]
+        #[derive(Eq, Hash)]
         #[allow(clippy::derive_partial_eq_without_eq)]
         #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum Sum {
@@ -89,7 +262,10 @@ pub mod signature_descriptor {
 /// encouraged to open a small PR against this file to add a new case
 /// to this SignMode enum describing their sign mode so that different
 /// apps have a consistent version of this enum.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SignMode {
@@ -153,3 +329,49 @@ impl SignMode {
         }
     }
 }
+impl crate::protobuf::Name for TextualData {
+    const NAME: &'static str = "TextualData";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for SignerData {
+    const NAME: &'static str = "SignerData";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for Envelope {
+    const NAME: &'static str = "Envelope";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for SignatureDescriptors {
+    const NAME: &'static str = "SignatureDescriptors";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for SignatureDescriptor {
+    const NAME: &'static str = "SignatureDescriptor";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for signature_descriptor::Data {
+    const NAME: &'static str = "SignatureDescriptor.Data";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for signature_descriptor::data::Single {
+    const NAME: &'static str = "SignatureDescriptor.Data.Single";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+impl crate::protobuf::Name for signature_descriptor::data::Multi {
+    const NAME: &'static str = "SignatureDescriptor.Data.Multi";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<TextualData>();
+    registry.register::<SignerData>();
+    registry.register::<Envelope>();
+    registry.register::<SignatureDescriptors>();
+    registry.register::<SignatureDescriptor>();
+    registry.register::<signature_descriptor::Data>();
+    registry.register::<signature_descriptor::data::Single>();
+    registry.register::<signature_descriptor::data::Multi>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.tx.signing.v1beta1.bin");
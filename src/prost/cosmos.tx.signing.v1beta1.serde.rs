@@ -0,0 +1,1370 @@
+impl serde::Serialize for Envelope {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.public_key.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.Envelope", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("accountNumber", ToString::to_string(&self.account_number).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("address", &self.address)?;
+        }
+        if let Some(v) = self.public_key.as_ref() {
+            struct_ser.serialize_field("publicKey", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        if true {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if true {
+            struct_ser.serialize_field("fees", &self.fees)?;
+        }
+        if true {
+            struct_ser.serialize_field("feePayer", &self.fee_payer)?;
+        }
+        if true {
+            struct_ser.serialize_field("feeGranter", &self.fee_granter)?;
+        }
+        if true {
+            struct_ser.serialize_field("tip", &self.tip)?;
+        }
+        if true {
+            struct_ser.serialize_field("tipper", &self.tipper)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("gasLimit", ToString::to_string(&self.gas_limit).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timeoutHeight", ToString::to_string(&self.timeout_height).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("otherSigner", &self.other_signer)?;
+        }
+        if true {
+            struct_ser.serialize_field("extensionOptions", &self.extension_options)?;
+        }
+        if true {
+            struct_ser.serialize_field("nonCriticalExtensionOptions", &self.non_critical_extension_options)?;
+        }
+        if true {
+            struct_ser.serialize_field("hashOfRawBytes", &self.hash_of_raw_bytes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Envelope {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "account_number",
+            "accountNumber",
+            "sequence",
+            "address",
+            "public_key",
+            "publicKey",
+            "message",
+            "memo",
+            "fees",
+            "fee_payer",
+            "feePayer",
+            "fee_granter",
+            "feeGranter",
+            "tip",
+            "tipper",
+            "gas_limit",
+            "gasLimit",
+            "timeout_height",
+            "timeoutHeight",
+            "other_signer",
+            "otherSigner",
+            "extension_options",
+            "extensionOptions",
+            "non_critical_extension_options",
+            "nonCriticalExtensionOptions",
+            "hash_of_raw_bytes",
+            "hashOfRawBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            AccountNumber,
+            Sequence,
+            Address,
+            PublicKey,
+            Message,
+            Memo,
+            Fees,
+            FeePayer,
+            FeeGranter,
+            Tip,
+            Tipper,
+            GasLimit,
+            TimeoutHeight,
+            OtherSigner,
+            ExtensionOptions,
+            NonCriticalExtensionOptions,
+            HashOfRawBytes,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "accountNumber" | "account_number" => Ok(GeneratedField::AccountNumber),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "address" => Ok(GeneratedField::Address),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "message" => Ok(GeneratedField::Message),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "fees" => Ok(GeneratedField::Fees),
+                            "feePayer" | "fee_payer" => Ok(GeneratedField::FeePayer),
+                            "feeGranter" | "fee_granter" => Ok(GeneratedField::FeeGranter),
+                            "tip" => Ok(GeneratedField::Tip),
+                            "tipper" => Ok(GeneratedField::Tipper),
+                            "gasLimit" | "gas_limit" => Ok(GeneratedField::GasLimit),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "otherSigner" | "other_signer" => Ok(GeneratedField::OtherSigner),
+                            "extensionOptions" | "extension_options" => Ok(GeneratedField::ExtensionOptions),
+                            "nonCriticalExtensionOptions" | "non_critical_extension_options" => Ok(GeneratedField::NonCriticalExtensionOptions),
+                            "hashOfRawBytes" | "hash_of_raw_bytes" => Ok(GeneratedField::HashOfRawBytes),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Envelope;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.Envelope")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Envelope, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut account_number__ = None;
+                let mut sequence__ = None;
+                let mut address__ = None;
+                let mut public_key__ = None;
+                let mut message__ = None;
+                let mut memo__ = None;
+                let mut fees__ = None;
+                let mut fee_payer__ = None;
+                let mut fee_granter__ = None;
+                let mut tip__ = None;
+                let mut tipper__ = None;
+                let mut gas_limit__ = None;
+                let mut timeout_height__ = None;
+                let mut other_signer__ = None;
+                let mut extension_options__ = None;
+                let mut non_critical_extension_options__ = None;
+                let mut hash_of_raw_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AccountNumber => {
+                            if account_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountNumber"));
+                            }
+                            account_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fees => {
+                            if fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees"));
+                            }
+                            fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeePayer => {
+                            if fee_payer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feePayer"));
+                            }
+                            fee_payer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeGranter => {
+                            if fee_granter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGranter"));
+                            }
+                            fee_granter__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Tip => {
+                            if tip__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tip"));
+                            }
+                            tip__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Tipper => {
+                            if tipper__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tipper"));
+                            }
+                            tipper__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GasLimit => {
+                            if gas_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasLimit"));
+                            }
+                            gas_limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
+                            timeout_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OtherSigner => {
+                            if other_signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("otherSigner"));
+                            }
+                            other_signer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExtensionOptions => {
+                            if extension_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("extensionOptions"));
+                            }
+                            extension_options__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NonCriticalExtensionOptions => {
+                            if non_critical_extension_options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonCriticalExtensionOptions"));
+                            }
+                            non_critical_extension_options__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::HashOfRawBytes => {
+                            if hash_of_raw_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hashOfRawBytes"));
+                            }
+                            hash_of_raw_bytes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Envelope {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    account_number: account_number__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    address: address__.unwrap_or_default(),
+                    public_key: public_key__,
+                    message: message__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    fees: fees__.unwrap_or_default(),
+                    fee_payer: fee_payer__.unwrap_or_default(),
+                    fee_granter: fee_granter__.unwrap_or_default(),
+                    tip: tip__.unwrap_or_default(),
+                    tipper: tipper__.unwrap_or_default(),
+                    gas_limit: gas_limit__.unwrap_or_default(),
+                    timeout_height: timeout_height__.unwrap_or_default(),
+                    other_signer: other_signer__.unwrap_or_default(),
+                    extension_options: extension_options__.unwrap_or_default(),
+                    non_critical_extension_options: non_critical_extension_options__.unwrap_or_default(),
+                    hash_of_raw_bytes: hash_of_raw_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.Envelope", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SIGN_MODE_UNSPECIFIED",
+            Self::Direct => "SIGN_MODE_DIRECT",
+            Self::Textual => "SIGN_MODE_TEXTUAL",
+            Self::DirectAux => "SIGN_MODE_DIRECT_AUX",
+            Self::LegacyAminoJson => "SIGN_MODE_LEGACY_AMINO_JSON",
+            Self::Eip191 => "SIGN_MODE_EIP_191",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SIGN_MODE_UNSPECIFIED",
+            "SIGN_MODE_DIRECT",
+            "SIGN_MODE_TEXTUAL",
+            "SIGN_MODE_DIRECT_AUX",
+            "SIGN_MODE_LEGACY_AMINO_JSON",
+            "SIGN_MODE_EIP_191",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(SignMode::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(SignMode::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SIGN_MODE_UNSPECIFIED" => Ok(SignMode::Unspecified),
+                    "SIGN_MODE_DIRECT" => Ok(SignMode::Direct),
+                    "SIGN_MODE_TEXTUAL" => Ok(SignMode::Textual),
+                    "SIGN_MODE_DIRECT_AUX" => Ok(SignMode::DirectAux),
+                    "SIGN_MODE_LEGACY_AMINO_JSON" => Ok(SignMode::LegacyAminoJson),
+                    "SIGN_MODE_EIP_191" => Ok(SignMode::Eip191),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignatureDescriptor {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.public_key.is_some() {
+            len += 1;
+        }
+        if self.data.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor", len)?;
+        if let Some(v) = self.public_key.as_ref() {
+            struct_ser.serialize_field("publicKey", v)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignatureDescriptor {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_key",
+            "publicKey",
+            "data",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicKey,
+            Data,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "data" => Ok(GeneratedField::Data),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignatureDescriptor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignatureDescriptor")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignatureDescriptor, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_key__ = None;
+                let mut data__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SignatureDescriptor {
+                    public_key: public_key__,
+                    data: data__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for signature_descriptor::Data {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sum.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data", len)?;
+        if let Some(v) = self.sum.as_ref() {
+            match v {
+                signature_descriptor::data::Sum::Single(v) => {
+                    struct_ser.serialize_field("single", v)?;
+                }
+                signature_descriptor::data::Sum::Multi(v) => {
+                    struct_ser.serialize_field("multi", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for signature_descriptor::Data {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "single",
+            "multi",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Single,
+            Multi,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "single" => Ok(GeneratedField::Single),
+                            "multi" => Ok(GeneratedField::Multi),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = signature_descriptor::Data;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignatureDescriptor.Data")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<signature_descriptor::Data, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sum__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Single => {
+                            if sum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("single"));
+                            }
+                            sum__ = map_.next_value::<::std::option::Option<_>>()?.map(signature_descriptor::data::Sum::Single);
+                        }
+                        GeneratedField::Multi => {
+                            if sum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multi"));
+                            }
+                            sum__ = map_.next_value::<::std::option::Option<_>>()?.map(signature_descriptor::data::Sum::Multi);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(signature_descriptor::Data {
+                    sum: sum__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for signature_descriptor::data::Multi {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.bitarray.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Multi", len)?;
+        if let Some(v) = self.bitarray.as_ref() {
+            struct_ser.serialize_field("bitarray", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("signatures", &self.signatures)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for signature_descriptor::data::Multi {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bitarray",
+            "signatures",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Bitarray,
+            Signatures,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bitarray" => Ok(GeneratedField::Bitarray),
+                            "signatures" => Ok(GeneratedField::Signatures),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = signature_descriptor::data::Multi;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Multi")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<signature_descriptor::data::Multi, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bitarray__ = None;
+                let mut signatures__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Bitarray => {
+                            if bitarray__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bitarray"));
+                            }
+                            bitarray__ = map_.next_value()?;
+                        }
+                        GeneratedField::Signatures => {
+                            if signatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatures"));
+                            }
+                            signatures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(signature_descriptor::data::Multi {
+                    bitarray: bitarray__,
+                    signatures: signatures__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Multi", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for signature_descriptor::data::Single {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Single", len)?;
+        if true {
+            let v = SignMode::from_i32(self.mode)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.mode)))?;
+            struct_ser.serialize_field("mode", &v)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for signature_descriptor::data::Single {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "mode",
+            "signature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Mode,
+            Signature,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "mode" => Ok(GeneratedField::Mode),
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = signature_descriptor::data::Single;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Single")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<signature_descriptor::data::Single, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut mode__ = None;
+                let mut signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Mode => {
+                            if mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mode"));
+                            }
+                            mode__ = Some(map_.next_value::<SignMode>()? as i32);
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(signature_descriptor::data::Single {
+                    mode: mode__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Single", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignatureDescriptors {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptors", len)?;
+        if true {
+            struct_ser.serialize_field("signatures", &self.signatures)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignatureDescriptors {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signatures",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signatures,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signatures" => Ok(GeneratedField::Signatures),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignatureDescriptors;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignatureDescriptors")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignatureDescriptors, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signatures__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signatures => {
+                            if signatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatures"));
+                            }
+                            signatures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SignatureDescriptors {
+                    signatures: signatures__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignatureDescriptors", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignerData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.pub_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.SignerData", len)?;
+        if true {
+            struct_ser.serialize_field("address", &self.address)?;
+        }
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("accountNumber", ToString::to_string(&self.account_number).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if let Some(v) = self.pub_key.as_ref() {
+            struct_ser.serialize_field("pubKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignerData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "chain_id",
+            "chainId",
+            "account_number",
+            "accountNumber",
+            "sequence",
+            "pub_key",
+            "pubKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            ChainId,
+            AccountNumber,
+            Sequence,
+            PubKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "accountNumber" | "account_number" => Ok(GeneratedField::AccountNumber),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignerData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.SignerData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignerData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut chain_id__ = None;
+                let mut account_number__ = None;
+                let mut sequence__ = None;
+                let mut pub_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AccountNumber => {
+                            if account_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountNumber"));
+                            }
+                            account_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PubKey => {
+                            if pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubKey"));
+                            }
+                            pub_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SignerData {
+                    address: address__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                    account_number: account_number__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    pub_key: pub_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.SignerData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TextualData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.signer_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("cosmos.tx.signing.v1beta1.TextualData", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("bodyBytes", pbjson::private::base64::encode(&self.body_bytes).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("authInfoBytes", pbjson::private::base64::encode(&self.auth_info_bytes).as_str())?;
+        }
+        if let Some(v) = self.signer_data.as_ref() {
+            struct_ser.serialize_field("signerData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TextualData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body_bytes",
+            "bodyBytes",
+            "auth_info_bytes",
+            "authInfoBytes",
+            "signer_data",
+            "signerData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BodyBytes,
+            AuthInfoBytes,
+            SignerData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bodyBytes" | "body_bytes" => Ok(GeneratedField::BodyBytes),
+                            "authInfoBytes" | "auth_info_bytes" => Ok(GeneratedField::AuthInfoBytes),
+                            "signerData" | "signer_data" => Ok(GeneratedField::SignerData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TextualData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct cosmos.tx.signing.v1beta1.TextualData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TextualData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body_bytes__ = None;
+                let mut auth_info_bytes__ = None;
+                let mut signer_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BodyBytes => {
+                            if body_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bodyBytes"));
+                            }
+                            body_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AuthInfoBytes => {
+                            if auth_info_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authInfoBytes"));
+                            }
+                            auth_info_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SignerData => {
+                            if signer_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signerData"));
+                            }
+                            signer_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TextualData {
+                    body_bytes: body_bytes__.unwrap_or_default(),
+                    auth_info_bytes: auth_info_bytes__.unwrap_or_default(),
+                    signer_data: signer_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("cosmos.tx.signing.v1beta1.TextualData", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,5 +1,9 @@
 /// Tx is the standard type used for broadcasting transactions.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Tx {
@@ -14,6 +18,10 @@ pub struct Tx {
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
     #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64_vec")
+    )]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 /// TxRaw is a variant of Tx that pins the signer's exact binary representation
@@ -21,36 +29,64 @@ pub struct Tx {
 /// verification. The binary `serialize(tx: TxRaw)` is stored in Tendermint and
 /// the hash `sha256(serialize(tx: TxRaw))` becomes the "txhash", commonly used
 /// as the transaction ID.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxRaw {
     /// body_bytes is a protobuf serialization of a TxBody that matches the
     /// representation in SignDoc.
-    #[prost(bytes = "vec", tag = "1")]
-    pub body_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "bytes", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub body_bytes: ::prost::bytes::Bytes,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in SignDoc.
-    #[prost(bytes = "vec", tag = "2")]
-    pub auth_info_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "bytes", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub auth_info_bytes: ::prost::bytes::Bytes,
     /// signatures is a list of signatures that matches the length and order of
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
-    #[prost(bytes = "vec", repeated, tag = "3")]
-    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "bytes", repeated, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64_vec")
+    )]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// SignDoc is the type used for generating sign bytes for SIGN_MODE_DIRECT.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignDoc {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub body_bytes: ::prost::alloc::vec::Vec<u8>,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub auth_info_bytes: ::prost::alloc::vec::Vec<u8>,
     /// chain_id is the unique identifier of the chain this transaction targets.
     /// It prevents signed transactions from being used on another chain by an
@@ -59,19 +95,31 @@ pub struct SignDoc {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub account_number: u64,
 }
 /// SignDocDirectAux is the type used for generating sign bytes for
 /// SIGN_MODE_DIRECT_AUX.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignDocDirectAux {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub body_bytes: ::prost::alloc::vec::Vec<u8>,
     /// public_key is the public key of the signing account.
     #[prost(message, optional, tag = "2")]
@@ -83,9 +131,17 @@ pub struct SignDocDirectAux {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub account_number: u64,
     /// sequence is the sequence number of the signing account.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub sequence: u64,
     /// Tip is the optional tip used for transactions fees paid in another denom.
     /// It should be left empty if the signer is not the tipper for this
@@ -97,7 +153,11 @@ pub struct SignDocDirectAux {
     pub tip: ::core::option::Option<Tip>,
 }
 /// TxBody is the body of a transaction that all signers sign over.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxBody {
@@ -118,6 +178,10 @@ pub struct TxBody {
     /// timeout is the block height after which this transaction will not
     /// be processed by the chain
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub timeout_height: u64,
     /// extension_options are arbitrary options that can be added by chains
     /// when the default options are not sufficient. If any of these are present
@@ -136,7 +200,11 @@ pub struct TxBody {
 }
 /// AuthInfo describes the fee and signer modes that are used to sign a
 /// transaction.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthInfo {
@@ -163,7 +231,11 @@ pub struct AuthInfo {
 }
 /// SignerInfo describes the public key and signing mode of a single top-level
 /// signer.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignerInfo {
@@ -180,10 +252,18 @@ pub struct SignerInfo {
     /// number of committed transactions signed by a given address. It is used to
     /// prevent replay attacks.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub sequence: u64,
 }
 /// ModeInfo describes the signing mode of a single or nested multisig signer.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModeInfo {
@@ -197,7 +277,11 @@ pub mod mode_info {
     /// Single is the mode info for a single signer. It is structured as a message
     /// to allow for additional fields such as locale for SIGN_MODE_TEXTUAL in the
     /// future
-    #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        derive(::serde::Serialize, ::serde::Deserialize)
+    )]
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Single {
@@ -206,7 +290,11 @@ pub mod mode_info {
         pub mode: i32,
     }
     /// Multi is the mode info for a multisig public key
-    #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        derive(::serde::Serialize, ::serde::Deserialize)
+    )]
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Multi {
@@ -222,7 +310,11 @@ pub mod mode_info {
     }
     /// sum is the oneof that specifies whether this represents a single or nested
     /// multisig signer
-    #[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        derive(::serde::Serialize, ::serde::Deserialize)
+    )]
+    #[derive(Eq, Hash)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
@@ -237,7 +329,11 @@ pub mod mode_info {
 /// Fee includes the amount of coins paid in fees and the maximum
 /// gas to be used by the transaction. The ratio yields an effective "gasprice",
 /// which must be above some miminum to be accepted into the mempool.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Fee {
@@ -247,6 +343,10 @@ pub struct Fee {
     /// gas_limit is the maximum gas that can be used in transaction processing
     /// before an out of gas error occurs
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub gas_limit: u64,
     /// if unset, the first signer is responsible for paying the fees. If set, the specified account must pay the fees.
     /// the payer must be a tx signer (and thus have signed this field in AuthInfo).
@@ -262,7 +362,11 @@ pub struct Fee {
 /// Tip is the tip used for meta-transactions.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Tip {
@@ -279,7 +383,11 @@ pub struct Tip {
 /// by the node if sent directly as-is.
 ///
 /// Since: cosmos-sdk 0.46
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuxSignerData {
@@ -298,11 +406,19 @@ pub struct AuxSignerData {
     pub mode: i32,
     /// sig is the signature of the sign doc.
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub sig: ::prost::alloc::vec::Vec<u8>,
 }
 /// GetTxsEventRequest is the request type for the Service.TxsByEvents
 /// RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxsEventRequest {
@@ -320,15 +436,26 @@ pub struct GetTxsEventRequest {
     pub order_by: i32,
     /// page is the page number to query, starts at 1. If not provided, will default to first page.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub page: u64,
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub limit: u64,
 }
 /// GetTxsEventResponse is the response type for the Service.TxsByEvents
 /// RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxsEventResponse {
@@ -349,23 +476,38 @@ pub struct GetTxsEventResponse {
     >,
     /// total is total number of results available
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub total: u64,
 }
 /// BroadcastTxRequest is the request type for the Service.BroadcastTxRequest
 /// RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastTxRequest {
     /// tx_bytes is the raw transaction.
-    #[prost(bytes = "vec", tag = "1")]
-    pub tx_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "bytes", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub tx_bytes: ::prost::bytes::Bytes,
     #[prost(enumeration = "BroadcastMode", tag = "2")]
     pub mode: i32,
 }
 /// BroadcastTxResponse is the response type for the
 /// Service.BroadcastTx method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastTxResponse {
@@ -377,7 +519,11 @@ pub struct BroadcastTxResponse {
 }
 /// SimulateRequest is the request type for the Service.Simulate
 /// RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateRequest {
@@ -389,12 +535,19 @@ pub struct SimulateRequest {
     /// tx_bytes is the raw transaction.
     ///
     /// Since: cosmos-sdk 0.43
-    #[prost(bytes = "vec", tag = "2")]
-    pub tx_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "bytes", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    pub tx_bytes: ::prost::bytes::Bytes,
 }
 /// SimulateResponse is the response type for the
 /// Service.SimulateRPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateResponse {
@@ -407,7 +560,11 @@ pub struct SimulateResponse {
 }
 /// GetTxRequest is the request type for the Service.GetTx
 /// RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxRequest {
@@ -416,7 +573,10 @@ pub struct GetTxRequest {
     pub hash: ::prost::alloc::string::String,
 }
 /// GetTxResponse is the response type for the Service.GetTx method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxResponse {
@@ -433,12 +593,20 @@ pub struct GetTxResponse {
 /// RPC method.
 ///
 /// Since: cosmos-sdk 0.45.2
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockWithTxsRequest {
     /// height is the height of the block to query.
     #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
     pub height: i64,
     /// pagination defines a pagination for the request.
     #[prost(message, optional, tag = "2")]
@@ -449,7 +617,10 @@ pub struct GetBlockWithTxsRequest {
 /// GetBlockWithTxsResponse is the response type for the Service.GetBlockWithTxs method.
 ///
 /// Since: cosmos-sdk 0.45.2
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockWithTxsResponse {
@@ -467,7 +638,10 @@ pub struct GetBlockWithTxsResponse {
     >,
 }
 /// OrderBy defines the sorting order
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OrderBy {
@@ -501,7 +675,10 @@ impl OrderBy {
     }
 }
 /// BroadcastMode specifies the broadcast mode for the TxService.Broadcast RPC method.
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BroadcastMode {
@@ -552,6 +729,7 @@ pub mod service_client {
     pub struct ServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl ServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -562,6 +740,27 @@ pub mod service_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl ServiceClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> ServiceClient<T>
     where
@@ -1150,3 +1349,124 @@ pub mod service_server {
         const NAME: &'static str = "cosmos.tx.v1beta1.Service";
     }
 }
+impl crate::protobuf::Name for Tx {
+    const NAME: &'static str = "Tx";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for TxRaw {
+    const NAME: &'static str = "TxRaw";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for SignDoc {
+    const NAME: &'static str = "SignDoc";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for SignDocDirectAux {
+    const NAME: &'static str = "SignDocDirectAux";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for TxBody {
+    const NAME: &'static str = "TxBody";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for AuthInfo {
+    const NAME: &'static str = "AuthInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for SignerInfo {
+    const NAME: &'static str = "SignerInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for ModeInfo {
+    const NAME: &'static str = "ModeInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for mode_info::Single {
+    const NAME: &'static str = "ModeInfo.Single";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for mode_info::Multi {
+    const NAME: &'static str = "ModeInfo.Multi";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for Tip {
+    const NAME: &'static str = "Tip";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for AuxSignerData {
+    const NAME: &'static str = "AuxSignerData";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetTxsEventRequest {
+    const NAME: &'static str = "GetTxsEventRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetTxsEventResponse {
+    const NAME: &'static str = "GetTxsEventResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for BroadcastTxRequest {
+    const NAME: &'static str = "BroadcastTxRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for BroadcastTxResponse {
+    const NAME: &'static str = "BroadcastTxResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for SimulateRequest {
+    const NAME: &'static str = "SimulateRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for SimulateResponse {
+    const NAME: &'static str = "SimulateResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetTxRequest {
+    const NAME: &'static str = "GetTxRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetTxResponse {
+    const NAME: &'static str = "GetTxResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetBlockWithTxsRequest {
+    const NAME: &'static str = "GetBlockWithTxsRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+impl crate::protobuf::Name for GetBlockWithTxsResponse {
+    const NAME: &'static str = "GetBlockWithTxsResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Tx>();
+    registry.register::<TxRaw>();
+    registry.register::<SignDoc>();
+    registry.register::<SignDocDirectAux>();
+    registry.register::<TxBody>();
+    registry.register::<AuthInfo>();
+    registry.register::<SignerInfo>();
+    registry.register::<ModeInfo>();
+    registry.register::<mode_info::Single>();
+    registry.register::<mode_info::Multi>();
+    registry.register::<Fee>();
+    registry.register::<Tip>();
+    registry.register::<AuxSignerData>();
+    registry.register::<GetTxsEventRequest>();
+    registry.register::<GetTxsEventResponse>();
+    registry.register::<BroadcastTxRequest>();
+    registry.register::<BroadcastTxResponse>();
+    registry.register::<SimulateRequest>();
+    registry.register::<SimulateResponse>();
+    registry.register::<GetTxRequest>();
+    registry.register::<GetTxResponse>();
+    registry.register::<GetBlockWithTxsRequest>();
+    registry.register::<GetBlockWithTxsResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("cosmos.tx.v1beta1.bin");
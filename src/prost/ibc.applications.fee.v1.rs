@@ -1,4 +1,13 @@
 /// Fee defines the ICS29 receive, acknowledgement and timeout fees
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Fee {
@@ -19,6 +28,15 @@ pub struct Fee {
     >,
 }
 /// PacketFee contains ICS29 relayer fees, refund address and optional list of permitted relayers
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketFee {
@@ -33,6 +51,15 @@ pub struct PacketFee {
     pub relayers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// PacketFees contains a list of type PacketFee
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketFees {
@@ -41,6 +68,15 @@ pub struct PacketFees {
     pub packet_fees: ::prost::alloc::vec::Vec<PacketFee>,
 }
 /// IdentifiedPacketFees contains a list of type PacketFee and associated PacketId
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IdentifiedPacketFees {
@@ -54,6 +90,15 @@ pub struct IdentifiedPacketFees {
     pub packet_fees: ::prost::alloc::vec::Vec<PacketFee>,
 }
 /// GenesisState defines the ICS29 fee middleware genesis state
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
@@ -76,6 +121,15 @@ pub struct GenesisState {
     pub forward_relayers: ::prost::alloc::vec::Vec<ForwardRelayerAddress>,
 }
 /// FeeEnabledChannel contains the PortID & ChannelID for a fee enabled channel
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeeEnabledChannel {
@@ -87,6 +141,15 @@ pub struct FeeEnabledChannel {
     pub channel_id: ::prost::alloc::string::String,
 }
 /// RegisteredPayee contains the relayer address and payee address for a specific channel
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisteredPayee {
@@ -102,6 +165,15 @@ pub struct RegisteredPayee {
 }
 /// RegisteredCounterpartyPayee contains the relayer address and counterparty payee address for a specific channel (used
 /// for recv fee distribution)
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisteredCounterpartyPayee {
@@ -116,6 +188,15 @@ pub struct RegisteredCounterpartyPayee {
     pub counterparty_payee: ::prost::alloc::string::String,
 }
 /// ForwardRelayerAddress contains the forward relayer address and PacketId used for async acknowledgements
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ForwardRelayerAddress {
@@ -129,6 +210,15 @@ pub struct ForwardRelayerAddress {
     >,
 }
 /// QueryIncentivizedPacketsRequest defines the request type for the IncentivizedPackets rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketsRequest {
@@ -139,9 +229,23 @@ pub struct QueryIncentivizedPacketsRequest {
     >,
     /// block height at which to query
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub query_height: u64,
 }
 /// QueryIncentivizedPacketsResponse defines the response type for the IncentivizedPackets rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketsResponse {
@@ -150,6 +254,15 @@ pub struct QueryIncentivizedPacketsResponse {
     pub incentivized_packets: ::prost::alloc::vec::Vec<IdentifiedPacketFees>,
 }
 /// QueryIncentivizedPacketRequest defines the request type for the IncentivizedPacket rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketRequest {
@@ -160,9 +273,23 @@ pub struct QueryIncentivizedPacketRequest {
     >,
     /// block height at which to query
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub query_height: u64,
 }
 /// QueryIncentivizedPacketsResponse defines the response type for the IncentivizedPacket rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketResponse {
@@ -172,6 +299,15 @@ pub struct QueryIncentivizedPacketResponse {
 }
 /// QueryIncentivizedPacketsForChannelRequest defines the request type for querying for all incentivized packets
 /// for a specific channel
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketsForChannelRequest {
@@ -186,9 +322,23 @@ pub struct QueryIncentivizedPacketsForChannelRequest {
     pub channel_id: ::prost::alloc::string::String,
     /// Height to query at
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub query_height: u64,
 }
 /// QueryIncentivizedPacketsResponse defines the response type for the incentivized packets RPC
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryIncentivizedPacketsForChannelResponse {
@@ -197,6 +347,15 @@ pub struct QueryIncentivizedPacketsForChannelResponse {
     pub incentivized_packets: ::prost::alloc::vec::Vec<IdentifiedPacketFees>,
 }
 /// QueryTotalRecvFeesRequest defines the request type for the TotalRecvFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalRecvFeesRequest {
@@ -207,6 +366,15 @@ pub struct QueryTotalRecvFeesRequest {
     >,
 }
 /// QueryTotalRecvFeesResponse defines the response type for the TotalRecvFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalRecvFeesResponse {
@@ -217,6 +385,15 @@ pub struct QueryTotalRecvFeesResponse {
     >,
 }
 /// QueryTotalAckFeesRequest defines the request type for the TotalAckFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalAckFeesRequest {
@@ -227,6 +404,15 @@ pub struct QueryTotalAckFeesRequest {
     >,
 }
 /// QueryTotalAckFeesResponse defines the response type for the TotalAckFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalAckFeesResponse {
@@ -237,6 +423,15 @@ pub struct QueryTotalAckFeesResponse {
     >,
 }
 /// QueryTotalTimeoutFeesRequest defines the request type for the TotalTimeoutFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalTimeoutFeesRequest {
@@ -247,6 +442,15 @@ pub struct QueryTotalTimeoutFeesRequest {
     >,
 }
 /// QueryTotalTimeoutFeesResponse defines the response type for the TotalTimeoutFees rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTotalTimeoutFeesResponse {
@@ -257,6 +461,15 @@ pub struct QueryTotalTimeoutFeesResponse {
     >,
 }
 /// QueryPayeeRequest defines the request type for the Payee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPayeeRequest {
@@ -268,6 +481,15 @@ pub struct QueryPayeeRequest {
     pub relayer: ::prost::alloc::string::String,
 }
 /// QueryPayeeResponse defines the response type for the Payee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPayeeResponse {
@@ -276,6 +498,15 @@ pub struct QueryPayeeResponse {
     pub payee_address: ::prost::alloc::string::String,
 }
 /// QueryCounterpartyPayeeRequest defines the request type for the CounterpartyPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCounterpartyPayeeRequest {
@@ -287,6 +518,15 @@ pub struct QueryCounterpartyPayeeRequest {
     pub relayer: ::prost::alloc::string::String,
 }
 /// QueryCounterpartyPayeeResponse defines the response type for the CounterpartyPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCounterpartyPayeeResponse {
@@ -295,6 +535,15 @@ pub struct QueryCounterpartyPayeeResponse {
     pub counterparty_payee: ::prost::alloc::string::String,
 }
 /// QueryFeeEnabledChannelsRequest defines the request type for the FeeEnabledChannels rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeEnabledChannelsRequest {
@@ -305,9 +554,23 @@ pub struct QueryFeeEnabledChannelsRequest {
     >,
     /// block height at which to query
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::from_str")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub query_height: u64,
 }
 /// QueryFeeEnabledChannelsResponse defines the response type for the FeeEnabledChannels rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeEnabledChannelsResponse {
@@ -316,6 +579,15 @@ pub struct QueryFeeEnabledChannelsResponse {
     pub fee_enabled_channels: ::prost::alloc::vec::Vec<FeeEnabledChannel>,
 }
 /// QueryFeeEnabledChannelRequest defines the request type for the FeeEnabledChannel rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeEnabledChannelRequest {
@@ -327,6 +599,15 @@ pub struct QueryFeeEnabledChannelRequest {
     pub channel_id: ::prost::alloc::string::String,
 }
 /// QueryFeeEnabledChannelResponse defines the response type for the FeeEnabledChannel rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeEnabledChannelResponse {
@@ -345,6 +626,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -355,6 +637,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -1391,6 +1694,15 @@ pub mod query_server {
     }
 }
 /// MsgRegisterPayee defines the request type for the RegisterPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterPayee {
@@ -1408,10 +1720,28 @@ pub struct MsgRegisterPayee {
     pub payee: ::prost::alloc::string::String,
 }
 /// MsgRegisterPayeeResponse defines the response type for the RegisterPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterPayeeResponse {}
 /// MsgRegisterCounterpartyPayee defines the request type for the RegisterCounterpartyPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterCounterpartyPayee {
@@ -1429,12 +1759,30 @@ pub struct MsgRegisterCounterpartyPayee {
     pub counterparty_payee: ::prost::alloc::string::String,
 }
 /// MsgRegisterCounterpartyPayeeResponse defines the response type for the RegisterCounterpartyPayee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterCounterpartyPayeeResponse {}
 /// MsgPayPacketFee defines the request type for the PayPacketFee rpc
 /// This Msg can be used to pay for a packet at the next sequence send & should be combined with the Msg that will be
 /// paid for
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgPayPacketFee {
@@ -1455,11 +1803,29 @@ pub struct MsgPayPacketFee {
     pub relayers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// MsgPayPacketFeeResponse defines the response type for the PayPacketFee rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgPayPacketFeeResponse {}
 /// MsgPayPacketFeeAsync defines the request type for the PayPacketFeeAsync rpc
 /// This Msg can be used to pay for a packet at a specified sequence (instead of the next sequence send)
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgPayPacketFeeAsync {
@@ -1473,6 +1839,15 @@ pub struct MsgPayPacketFeeAsync {
     pub packet_fee: ::core::option::Option<PacketFee>,
 }
 /// MsgPayPacketFeeAsyncResponse defines the response type for the PayPacketFeeAsync rpc
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgPayPacketFeeAsyncResponse {}
@@ -1487,6 +1862,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -1497,6 +1873,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -2045,11 +2442,25 @@ pub mod msg_server {
     }
 }
 /// IncentivizedAcknowledgement is the acknowledgement format to be used by applications wrapped in the fee middleware
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IncentivizedAcknowledgement {
     /// the underlying app acknowledgement bytes
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
+    #[cfg_attr(all(feature = "json-schema", feature = "std"), schemars(with = "String"))]
     pub app_acknowledgement: ::prost::alloc::vec::Vec<u8>,
     /// the relayer address which submits the recv packet message
     #[prost(string, tag = "2")]
@@ -2060,6 +2471,15 @@ pub struct IncentivizedAcknowledgement {
 }
 /// Metadata defines the ICS29 channel specific metadata encoded into the channel version bytestring
 /// See ICS004: <https://github.com/cosmos/ibc/tree/master/spec/core/ics-004-channel-and-packet-semantics#Versioning>
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
@@ -2070,3 +2490,204 @@ pub struct Metadata {
     #[prost(string, tag = "2")]
     pub app_version: ::prost::alloc::string::String,
 }
+impl crate::protobuf::Name for Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for PacketFee {
+    const NAME: &'static str = "PacketFee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for PacketFees {
+    const NAME: &'static str = "PacketFees";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for IdentifiedPacketFees {
+    const NAME: &'static str = "IdentifiedPacketFees";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for FeeEnabledChannel {
+    const NAME: &'static str = "FeeEnabledChannel";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for RegisteredPayee {
+    const NAME: &'static str = "RegisteredPayee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for RegisteredCounterpartyPayee {
+    const NAME: &'static str = "RegisteredCounterpartyPayee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for ForwardRelayerAddress {
+    const NAME: &'static str = "ForwardRelayerAddress";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketsRequest {
+    const NAME: &'static str = "QueryIncentivizedPacketsRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketsResponse {
+    const NAME: &'static str = "QueryIncentivizedPacketsResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketRequest {
+    const NAME: &'static str = "QueryIncentivizedPacketRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketResponse {
+    const NAME: &'static str = "QueryIncentivizedPacketResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketsForChannelRequest {
+    const NAME: &'static str = "QueryIncentivizedPacketsForChannelRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryIncentivizedPacketsForChannelResponse {
+    const NAME: &'static str = "QueryIncentivizedPacketsForChannelResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalRecvFeesRequest {
+    const NAME: &'static str = "QueryTotalRecvFeesRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalRecvFeesResponse {
+    const NAME: &'static str = "QueryTotalRecvFeesResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalAckFeesRequest {
+    const NAME: &'static str = "QueryTotalAckFeesRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalAckFeesResponse {
+    const NAME: &'static str = "QueryTotalAckFeesResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalTimeoutFeesRequest {
+    const NAME: &'static str = "QueryTotalTimeoutFeesRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryTotalTimeoutFeesResponse {
+    const NAME: &'static str = "QueryTotalTimeoutFeesResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryPayeeRequest {
+    const NAME: &'static str = "QueryPayeeRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryPayeeResponse {
+    const NAME: &'static str = "QueryPayeeResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryCounterpartyPayeeRequest {
+    const NAME: &'static str = "QueryCounterpartyPayeeRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryCounterpartyPayeeResponse {
+    const NAME: &'static str = "QueryCounterpartyPayeeResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryFeeEnabledChannelsRequest {
+    const NAME: &'static str = "QueryFeeEnabledChannelsRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryFeeEnabledChannelsResponse {
+    const NAME: &'static str = "QueryFeeEnabledChannelsResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryFeeEnabledChannelRequest {
+    const NAME: &'static str = "QueryFeeEnabledChannelRequest";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for QueryFeeEnabledChannelResponse {
+    const NAME: &'static str = "QueryFeeEnabledChannelResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgRegisterPayee {
+    const NAME: &'static str = "MsgRegisterPayee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgRegisterPayeeResponse {
+    const NAME: &'static str = "MsgRegisterPayeeResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgRegisterCounterpartyPayee {
+    const NAME: &'static str = "MsgRegisterCounterpartyPayee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgRegisterCounterpartyPayeeResponse {
+    const NAME: &'static str = "MsgRegisterCounterpartyPayeeResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgPayPacketFee {
+    const NAME: &'static str = "MsgPayPacketFee";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgPayPacketFeeResponse {
+    const NAME: &'static str = "MsgPayPacketFeeResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgPayPacketFeeAsync {
+    const NAME: &'static str = "MsgPayPacketFeeAsync";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for MsgPayPacketFeeAsyncResponse {
+    const NAME: &'static str = "MsgPayPacketFeeAsyncResponse";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for IncentivizedAcknowledgement {
+    const NAME: &'static str = "IncentivizedAcknowledgement";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+impl crate::protobuf::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "ibc.applications.fee.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Fee>();
+    registry.register::<PacketFee>();
+    registry.register::<PacketFees>();
+    registry.register::<IdentifiedPacketFees>();
+    registry.register::<GenesisState>();
+    registry.register::<FeeEnabledChannel>();
+    registry.register::<RegisteredPayee>();
+    registry.register::<RegisteredCounterpartyPayee>();
+    registry.register::<ForwardRelayerAddress>();
+    registry.register::<QueryIncentivizedPacketsRequest>();
+    registry.register::<QueryIncentivizedPacketsResponse>();
+    registry.register::<QueryIncentivizedPacketRequest>();
+    registry.register::<QueryIncentivizedPacketResponse>();
+    registry.register::<QueryIncentivizedPacketsForChannelRequest>();
+    registry.register::<QueryIncentivizedPacketsForChannelResponse>();
+    registry.register::<QueryTotalRecvFeesRequest>();
+    registry.register::<QueryTotalRecvFeesResponse>();
+    registry.register::<QueryTotalAckFeesRequest>();
+    registry.register::<QueryTotalAckFeesResponse>();
+    registry.register::<QueryTotalTimeoutFeesRequest>();
+    registry.register::<QueryTotalTimeoutFeesResponse>();
+    registry.register::<QueryPayeeRequest>();
+    registry.register::<QueryPayeeResponse>();
+    registry.register::<QueryCounterpartyPayeeRequest>();
+    registry.register::<QueryCounterpartyPayeeResponse>();
+    registry.register::<QueryFeeEnabledChannelsRequest>();
+    registry.register::<QueryFeeEnabledChannelsResponse>();
+    registry.register::<QueryFeeEnabledChannelRequest>();
+    registry.register::<QueryFeeEnabledChannelResponse>();
+    registry.register::<MsgRegisterPayee>();
+    registry.register::<MsgRegisterPayeeResponse>();
+    registry.register::<MsgRegisterCounterpartyPayee>();
+    registry.register::<MsgRegisterCounterpartyPayeeResponse>();
+    registry.register::<MsgPayPacketFee>();
+    registry.register::<MsgPayPacketFeeResponse>();
+    registry.register::<MsgPayPacketFeeAsync>();
+    registry.register::<MsgPayPacketFeeAsyncResponse>();
+    registry.register::<IncentivizedAcknowledgement>();
+    registry.register::<Metadata>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("ibc.applications.fee.v1.bin");
@@ -0,0 +1,96 @@
+/// GenesisState defines the interchain accounts genesis state
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisState {
+    #[prost(message, optional, tag = "1")]
+    pub controller_genesis_state: ::core::option::Option<ControllerGenesisState>,
+    #[prost(message, optional, tag = "2")]
+    pub host_genesis_state: ::core::option::Option<HostGenesisState>,
+}
+/// ControllerGenesisState defines the interchain accounts controller genesis state
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ControllerGenesisState {
+    #[prost(message, repeated, tag = "1")]
+    pub active_channels: ::prost::alloc::vec::Vec<ActiveChannel>,
+    #[prost(message, repeated, tag = "2")]
+    pub interchain_accounts: ::prost::alloc::vec::Vec<RegisteredInterchainAccount>,
+    #[prost(string, repeated, tag = "3")]
+    pub ports: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "4")]
+    pub params: ::core::option::Option<super::super::controller::v1::Params>,
+}
+/// HostGenesisState defines the interchain accounts host genesis state
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HostGenesisState {
+    #[prost(message, repeated, tag = "1")]
+    pub active_channels: ::prost::alloc::vec::Vec<ActiveChannel>,
+    #[prost(message, repeated, tag = "2")]
+    pub interchain_accounts: ::prost::alloc::vec::Vec<RegisteredInterchainAccount>,
+    #[prost(string, tag = "3")]
+    pub port: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub params: ::core::option::Option<super::super::host::v1::Params>,
+}
+/// ActiveChannel contains a connection ID, port ID and associated active channel ID, as well as a boolean flag to
+/// indicate if the channel is middleware enabled
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveChannel {
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub channel_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub is_middleware_enabled: bool,
+}
+/// RegisteredInterchainAccount contains a connection ID, port ID and associated interchain account address
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisteredInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub account_address: ::prost::alloc::string::String,
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.genesis.v1";
+}
+impl crate::protobuf::Name for ControllerGenesisState {
+    const NAME: &'static str = "ControllerGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.genesis.v1";
+}
+impl crate::protobuf::Name for HostGenesisState {
+    const NAME: &'static str = "HostGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.genesis.v1";
+}
+impl crate::protobuf::Name for ActiveChannel {
+    const NAME: &'static str = "ActiveChannel";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.genesis.v1";
+}
+impl crate::protobuf::Name for RegisteredInterchainAccount {
+    const NAME: &'static str = "RegisteredInterchainAccount";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.genesis.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<GenesisState>();
+    registry.register::<ControllerGenesisState>();
+    registry.register::<HostGenesisState>();
+    registry.register::<ActiveChannel>();
+    registry.register::<RegisteredInterchainAccount>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("ibc.applications.interchain_accounts.genesis.v1.bin");
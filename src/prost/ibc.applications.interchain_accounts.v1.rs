@@ -1,4 +1,5 @@
 /// An InterchainAccount is defined as a BaseAccount & the address of the account owner on the controller chain
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterchainAccount {
@@ -10,6 +11,7 @@ pub struct InterchainAccount {
     pub account_owner: ::prost::alloc::string::String,
 }
 /// GenesisState defines the interchain accounts genesis state
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
@@ -19,6 +21,7 @@ pub struct GenesisState {
     pub host_genesis_state: ::core::option::Option<HostGenesisState>,
 }
 /// ControllerGenesisState defines the interchain accounts controller genesis state
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ControllerGenesisState {
@@ -32,6 +35,7 @@ pub struct ControllerGenesisState {
     pub params: ::core::option::Option<super::controller::v1::Params>,
 }
 /// HostGenesisState defines the interchain accounts host genesis state
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HostGenesisState {
@@ -45,6 +49,7 @@ pub struct HostGenesisState {
     pub params: ::core::option::Option<super::host::v1::Params>,
 }
 /// ActiveChannel contains a connection ID, port ID and associated active channel ID
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ActiveChannel {
@@ -56,6 +61,7 @@ pub struct ActiveChannel {
     pub channel_id: ::prost::alloc::string::String,
 }
 /// RegisteredInterchainAccount contains a connection ID, port ID and associated interchain account address
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisteredInterchainAccount {
@@ -67,17 +73,27 @@ pub struct RegisteredInterchainAccount {
     pub account_address: ::prost::alloc::string::String,
 }
 /// InterchainAccountPacketData is comprised of a raw transaction, type of transaction and optional memo field.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterchainAccountPacketData {
     #[prost(enumeration = "Type", tag = "1")]
     pub r#type: i32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        all(feature = "std", not(feature = "json")),
+        serde(with = "crate::serializers::base64")
+    )]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "3")]
     pub memo: ::prost::alloc::string::String,
 }
 /// CosmosTx contains a list of sdk.Msg's. It should be used when sending transactions to an SDK host chain.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CosmosTx {
@@ -118,6 +134,7 @@ impl Type {
 }
 /// Metadata defines a set of protocol specific data encoded into the ICS27 channel version bytestring
 /// See ICS004: <https://github.com/cosmos/ibc/tree/master/spec/core/ics-004-channel-and-packet-semantics#Versioning>
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
@@ -141,3 +158,54 @@ pub struct Metadata {
     #[prost(string, tag = "6")]
     pub tx_type: ::prost::alloc::string::String,
 }
+impl crate::protobuf::Name for InterchainAccount {
+    const NAME: &'static str = "InterchainAccount";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for ControllerGenesisState {
+    const NAME: &'static str = "ControllerGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for HostGenesisState {
+    const NAME: &'static str = "HostGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for ActiveChannel {
+    const NAME: &'static str = "ActiveChannel";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for RegisteredInterchainAccount {
+    const NAME: &'static str = "RegisteredInterchainAccount";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for InterchainAccountPacketData {
+    const NAME: &'static str = "InterchainAccountPacketData";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for CosmosTx {
+    const NAME: &'static str = "CosmosTx";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+impl crate::protobuf::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<InterchainAccount>();
+    registry.register::<GenesisState>();
+    registry.register::<ControllerGenesisState>();
+    registry.register::<HostGenesisState>();
+    registry.register::<ActiveChannel>();
+    registry.register::<RegisteredInterchainAccount>();
+    registry.register::<InterchainAccountPacketData>();
+    registry.register::<CosmosTx>();
+    registry.register::<Metadata>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("ibc.applications.interchain_accounts.v1.bin");
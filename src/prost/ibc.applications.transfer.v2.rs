@@ -1,7 +1,15 @@
 /// FungibleTokenPacketData defines a struct for the packet payload
 /// See FungibleTokenPacketData spec:
 /// <https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer#data-structures>
-#[cfg_attr(feature = "std", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FungibleTokenPacketData {
@@ -21,3 +29,77 @@ pub struct FungibleTokenPacketData {
     #[prost(string, tag = "5")]
     pub memo: ::prost::alloc::string::String,
 }
+/// FungibleTokenPacketDataV2 defines a struct for the packet payload
+/// See FungibleTokenPacketDataV2 spec:
+/// <https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer#data-structures>
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FungibleTokenPacketDataV2 {
+    /// the tokens to be transferred
+    #[prost(message, repeated, tag = "1")]
+    pub tokens: ::prost::alloc::vec::Vec<super::v1::Token>,
+    /// the sender address
+    #[prost(string, tag = "2")]
+    pub sender: ::prost::alloc::string::String,
+    /// the recipient address on the destination chain
+    #[prost(string, tag = "3")]
+    pub receiver: ::prost::alloc::string::String,
+    /// optional memo
+    #[prost(string, tag = "4")]
+    pub memo: ::prost::alloc::string::String,
+    /// optional forwarding information
+    #[prost(message, optional, tag = "5")]
+    pub forwarding: ::core::option::Option<ForwardingPacketData>,
+}
+/// ForwardingPacketData defines a list of port ID, channel ID pairs determining the path
+/// through which a packet must be forwarded, and the destination memo string to be used in the
+/// final destination of the tokens.
+#[cfg_attr(
+    all(feature = "std", not(feature = "json")),
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "json-schema", feature = "std"),
+    derive(::schemars::JsonSchema)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingPacketData {
+    /// optional memo consumed by final destination chain
+    #[prost(string, tag = "1")]
+    pub destination_memo: ::prost::alloc::string::String,
+    /// optional intermediate path through which packet will be forwarded.
+    #[prost(message, repeated, tag = "2")]
+    pub hops: ::prost::alloc::vec::Vec<super::v1::Hop>,
+}
+impl crate::protobuf::Name for FungibleTokenPacketData {
+    const NAME: &'static str = "FungibleTokenPacketData";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v2";
+}
+impl crate::protobuf::Name for FungibleTokenPacketDataV2 {
+    const NAME: &'static str = "FungibleTokenPacketDataV2";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v2";
+}
+impl crate::protobuf::Name for ForwardingPacketData {
+    const NAME: &'static str = "ForwardingPacketData";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v2";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<FungibleTokenPacketData>();
+    registry.register::<FungibleTokenPacketDataV2>();
+    registry.register::<ForwardingPacketData>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("ibc.applications.transfer.v2.bin");
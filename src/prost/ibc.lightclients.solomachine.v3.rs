@@ -0,0 +1,181 @@
+/// ClientState defines a solo machine client that tracks the current consensus
+/// state and if the client is frozen.
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    /// latest sequence of the client state
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// frozen sequence of the solo machine
+    #[prost(bool, tag = "2")]
+    pub is_frozen: bool,
+    #[prost(message, optional, tag = "3")]
+    pub consensus_state: ::core::option::Option<ConsensusState>,
+}
+/// ConsensusState defines a solo machine consensus state. The sequence of a
+/// consensus state is contained in the "height" key used in storing the
+/// consensus state.
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    /// public key of the solo machine
+    #[prost(message, optional, tag = "1")]
+    pub public_key: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// diversifier allows the same public key to be re-used across different solo
+    /// machine clients (potentially on different chains) without being considered
+    /// misbehaviour.
+    #[prost(string, tag = "2")]
+    pub diversifier: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub timestamp: u64,
+}
+/// Header defines a solo machine consensus header
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Header {
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub new_public_key: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    #[prost(string, tag = "4")]
+    pub new_diversifier: ::prost::alloc::string::String,
+}
+/// Misbehaviour defines misbehaviour for a solo machine which consists
+/// of a sequence and two signatures over different messages at that sequence.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Misbehaviour {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(message, optional, tag = "2")]
+    pub signature_one: ::core::option::Option<SignatureAndData>,
+    #[prost(message, optional, tag = "3")]
+    pub signature_two: ::core::option::Option<SignatureAndData>,
+}
+/// SignatureAndData contains a signature and the data signed over to create that
+/// signature.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignatureAndData {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub path: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+}
+/// TimestampedSignatureData contains the signature data and the timestamp of the
+/// signature.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimestampedSignatureData {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature_data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+/// SignBytes defines the signed bytes used for signature verification.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignBytes {
+    /// the sequence number
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// the proof timestamp
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    /// the public key diversifier
+    #[prost(string, tag = "3")]
+    pub diversifier: ::prost::alloc::string::String,
+    /// the standardised path bytes
+    #[prost(bytes = "vec", tag = "4")]
+    pub path: ::prost::alloc::vec::Vec<u8>,
+    /// the marshaled data bytes
+    #[prost(bytes = "vec", tag = "5")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// HeaderData returns the SignBytes data for update verification.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeaderData {
+    /// header public key
+    #[prost(message, optional, tag = "1")]
+    pub new_pub_key: ::core::option::Option<
+        super::super::super::super::google::protobuf::Any,
+    >,
+    /// header diversifier
+    #[prost(string, tag = "2")]
+    pub new_diversifier: ::prost::alloc::string::String,
+}
+impl crate::protobuf::Name for ClientState {
+    const NAME: &'static str = "ClientState";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for ConsensusState {
+    const NAME: &'static str = "ConsensusState";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for Misbehaviour {
+    const NAME: &'static str = "Misbehaviour";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for SignatureAndData {
+    const NAME: &'static str = "SignatureAndData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for TimestampedSignatureData {
+    const NAME: &'static str = "TimestampedSignatureData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for SignBytes {
+    const NAME: &'static str = "SignBytes";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+impl crate::protobuf::Name for HeaderData {
+    const NAME: &'static str = "HeaderData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v3";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<ClientState>();
+    registry.register::<ConsensusState>();
+    registry.register::<Header>();
+    registry.register::<Misbehaviour>();
+    registry.register::<SignatureAndData>();
+    registry.register::<TimestampedSignatureData>();
+    registry.register::<SignBytes>();
+    registry.register::<HeaderData>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("ibc.lightclients.solomachine.v3.bin");
@@ -1,4 +1,5 @@
 /// Params defines the parameters for CCV consumer module
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
@@ -50,6 +51,7 @@ pub struct Params {
 }
 /// LastTransmissionBlockHeight is the last time validator holding
 /// pools were transmitted to the provider chain
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LastTransmissionBlockHeight {
@@ -57,6 +59,7 @@ pub struct LastTransmissionBlockHeight {
     pub height: i64,
 }
 /// CrossChainValidator defines the validators for CCV consumer module
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CrossChainValidator {
@@ -71,6 +74,7 @@ pub struct CrossChainValidator {
     >,
 }
 /// MaturingVSCPacket contains the maturing time of a received VSCPacket
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MaturingVscPacket {
@@ -133,6 +137,7 @@ pub struct GenesisState {
 }
 /// HeightValsetUpdateID defines the genesis information for the mapping
 /// of each block height to a valset update id
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HeightToValsetUpdateId {
@@ -143,6 +148,7 @@ pub struct HeightToValsetUpdateId {
 }
 /// OutstandingDowntime defines the genesis information for each validator
 /// flagged with an outstanding downtime slashing.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OutstandingDowntime {
@@ -150,6 +156,7 @@ pub struct OutstandingDowntime {
     pub validator_consensus_address: ::prost::alloc::string::String,
 }
 /// NextFeeDistributionEstimate holds information about next fee distribution
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NextFeeDistributionEstimate {
@@ -175,19 +182,23 @@ pub struct NextFeeDistributionEstimate {
     #[prost(string, tag = "7")]
     pub to_consumer: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryNextFeeDistributionEstimateRequest {}
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryNextFeeDistributionEstimateResponse {
     #[prost(message, optional, tag = "1")]
     pub data: ::core::option::Option<NextFeeDistributionEstimate>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
 /// QueryParamsResponse is response type for the Query/Params RPC method.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
@@ -205,6 +216,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -215,6 +227,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -587,3 +620,69 @@ pub mod query_server {
         const NAME: &'static str = "interchain_security.ccv.consumer.v1.Query";
     }
 }
+impl crate::protobuf::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for LastTransmissionBlockHeight {
+    const NAME: &'static str = "LastTransmissionBlockHeight";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for CrossChainValidator {
+    const NAME: &'static str = "CrossChainValidator";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for MaturingVscPacket {
+    const NAME: &'static str = "MaturingVSCPacket";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for HeightToValsetUpdateId {
+    const NAME: &'static str = "HeightToValsetUpdateID";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for OutstandingDowntime {
+    const NAME: &'static str = "OutstandingDowntime";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for NextFeeDistributionEstimate {
+    const NAME: &'static str = "NextFeeDistributionEstimate";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for QueryNextFeeDistributionEstimateRequest {
+    const NAME: &'static str = "QueryNextFeeDistributionEstimateRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for QueryNextFeeDistributionEstimateResponse {
+    const NAME: &'static str = "QueryNextFeeDistributionEstimateResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+impl crate::protobuf::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.consumer.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<Params>();
+    registry.register::<LastTransmissionBlockHeight>();
+    registry.register::<CrossChainValidator>();
+    registry.register::<MaturingVscPacket>();
+    registry.register::<GenesisState>();
+    registry.register::<HeightToValsetUpdateId>();
+    registry.register::<OutstandingDowntime>();
+    registry.register::<NextFeeDistributionEstimate>();
+    registry.register::<QueryNextFeeDistributionEstimateRequest>();
+    registry.register::<QueryNextFeeDistributionEstimateResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("interchain_security.ccv.consumer.v1.bin");
@@ -0,0 +1,1649 @@
+impl serde::Serialize for CrossChainValidator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.pubkey.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.CrossChainValidator", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("address", pbjson::private::base64::encode(&self.address).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("power", ToString::to_string(&self.power).as_str())?;
+        }
+        if let Some(v) = self.pubkey.as_ref() {
+            struct_ser.serialize_field("pubkey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CrossChainValidator {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "power",
+            "pubkey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            Power,
+            Pubkey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "power" => Ok(GeneratedField::Power),
+                            "pubkey" => Ok(GeneratedField::Pubkey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CrossChainValidator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.CrossChainValidator")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CrossChainValidator, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut power__ = None;
+                let mut pubkey__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Power => {
+                            if power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("power"));
+                            }
+                            power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Pubkey => {
+                            if pubkey__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubkey"));
+                            }
+                            pubkey__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CrossChainValidator {
+                    address: address__.unwrap_or_default(),
+                    power: power__.unwrap_or_default(),
+                    pubkey: pubkey__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.CrossChainValidator", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.params.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.provider_client_state.is_some() {
+            len += 1;
+        }
+        if self.provider_consensus_state.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.pending_consumer_packets.is_some() {
+            len += 1;
+        }
+        if self.last_transmission_block_height.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.GenesisState", len)?;
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("providerClientId", &self.provider_client_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("providerChannelId", &self.provider_channel_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("newChain", &self.new_chain)?;
+        }
+        if let Some(v) = self.provider_client_state.as_ref() {
+            struct_ser.serialize_field("providerClientState", v)?;
+        }
+        if let Some(v) = self.provider_consensus_state.as_ref() {
+            struct_ser.serialize_field("providerConsensusState", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("maturingPackets", &self.maturing_packets)?;
+        }
+        if true {
+            struct_ser.serialize_field("initialValSet", &self.initial_val_set)?;
+        }
+        if true {
+            struct_ser.serialize_field("heightToValsetUpdateId", &self.height_to_valset_update_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("outstandingDowntimeSlashing", &self.outstanding_downtime_slashing)?;
+        }
+        if let Some(v) = self.pending_consumer_packets.as_ref() {
+            struct_ser.serialize_field("pendingConsumerPackets", v)?;
+        }
+        if let Some(v) = self.last_transmission_block_height.as_ref() {
+            struct_ser.serialize_field("lastTransmissionBlockHeight", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "params",
+            "provider_client_id",
+            "providerClientId",
+            "provider_channel_id",
+            "providerChannelId",
+            "new_chain",
+            "newChain",
+            "provider_client_state",
+            "providerClientState",
+            "provider_consensus_state",
+            "providerConsensusState",
+            "maturing_packets",
+            "maturingPackets",
+            "initial_val_set",
+            "initialValSet",
+            "height_to_valset_update_id",
+            "heightToValsetUpdateId",
+            "outstanding_downtime_slashing",
+            "outstandingDowntimeSlashing",
+            "pending_consumer_packets",
+            "pendingConsumerPackets",
+            "last_transmission_block_height",
+            "lastTransmissionBlockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Params,
+            ProviderClientId,
+            ProviderChannelId,
+            NewChain,
+            ProviderClientState,
+            ProviderConsensusState,
+            MaturingPackets,
+            InitialValSet,
+            HeightToValsetUpdateId,
+            OutstandingDowntimeSlashing,
+            PendingConsumerPackets,
+            LastTransmissionBlockHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "params" => Ok(GeneratedField::Params),
+                            "providerClientId" | "provider_client_id" => Ok(GeneratedField::ProviderClientId),
+                            "providerChannelId" | "provider_channel_id" => Ok(GeneratedField::ProviderChannelId),
+                            "newChain" | "new_chain" => Ok(GeneratedField::NewChain),
+                            "providerClientState" | "provider_client_state" => Ok(GeneratedField::ProviderClientState),
+                            "providerConsensusState" | "provider_consensus_state" => Ok(GeneratedField::ProviderConsensusState),
+                            "maturingPackets" | "maturing_packets" => Ok(GeneratedField::MaturingPackets),
+                            "initialValSet" | "initial_val_set" => Ok(GeneratedField::InitialValSet),
+                            "heightToValsetUpdateId" | "height_to_valset_update_id" => Ok(GeneratedField::HeightToValsetUpdateId),
+                            "outstandingDowntimeSlashing" | "outstanding_downtime_slashing" => Ok(GeneratedField::OutstandingDowntimeSlashing),
+                            "pendingConsumerPackets" | "pending_consumer_packets" => Ok(GeneratedField::PendingConsumerPackets),
+                            "lastTransmissionBlockHeight" | "last_transmission_block_height" => Ok(GeneratedField::LastTransmissionBlockHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.GenesisState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut params__ = None;
+                let mut provider_client_id__ = None;
+                let mut provider_channel_id__ = None;
+                let mut new_chain__ = None;
+                let mut provider_client_state__ = None;
+                let mut provider_consensus_state__ = None;
+                let mut maturing_packets__ = None;
+                let mut initial_val_set__ = None;
+                let mut height_to_valset_update_id__ = None;
+                let mut outstanding_downtime_slashing__ = None;
+                let mut pending_consumer_packets__ = None;
+                let mut last_transmission_block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProviderClientId => {
+                            if provider_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerClientId"));
+                            }
+                            provider_client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderChannelId => {
+                            if provider_channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerChannelId"));
+                            }
+                            provider_channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewChain => {
+                            if new_chain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newChain"));
+                            }
+                            new_chain__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderClientState => {
+                            if provider_client_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerClientState"));
+                            }
+                            provider_client_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProviderConsensusState => {
+                            if provider_consensus_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerConsensusState"));
+                            }
+                            provider_consensus_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaturingPackets => {
+                            if maturing_packets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maturingPackets"));
+                            }
+                            maturing_packets__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitialValSet => {
+                            if initial_val_set__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialValSet"));
+                            }
+                            initial_val_set__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::HeightToValsetUpdateId => {
+                            if height_to_valset_update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightToValsetUpdateId"));
+                            }
+                            height_to_valset_update_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OutstandingDowntimeSlashing => {
+                            if outstanding_downtime_slashing__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outstandingDowntimeSlashing"));
+                            }
+                            outstanding_downtime_slashing__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PendingConsumerPackets => {
+                            if pending_consumer_packets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingConsumerPackets"));
+                            }
+                            pending_consumer_packets__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastTransmissionBlockHeight => {
+                            if last_transmission_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastTransmissionBlockHeight"));
+                            }
+                            last_transmission_block_height__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisState {
+                    params: params__,
+                    provider_client_id: provider_client_id__.unwrap_or_default(),
+                    provider_channel_id: provider_channel_id__.unwrap_or_default(),
+                    new_chain: new_chain__.unwrap_or_default(),
+                    provider_client_state: provider_client_state__,
+                    provider_consensus_state: provider_consensus_state__,
+                    maturing_packets: maturing_packets__.unwrap_or_default(),
+                    initial_val_set: initial_val_set__.unwrap_or_default(),
+                    height_to_valset_update_id: height_to_valset_update_id__.unwrap_or_default(),
+                    outstanding_downtime_slashing: outstanding_downtime_slashing__.unwrap_or_default(),
+                    pending_consumer_packets: pending_consumer_packets__,
+                    last_transmission_block_height: last_transmission_block_height__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.GenesisState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for HeightToValsetUpdateId {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.HeightToValsetUpdateID", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("valsetUpdateId", ToString::to_string(&self.valset_update_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for HeightToValsetUpdateId {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "valset_update_id",
+            "valsetUpdateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            ValsetUpdateId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "valsetUpdateId" | "valset_update_id" => Ok(GeneratedField::ValsetUpdateId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = HeightToValsetUpdateId;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.HeightToValsetUpdateID")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HeightToValsetUpdateId, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut valset_update_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValsetUpdateId => {
+                            if valset_update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valsetUpdateId"));
+                            }
+                            valset_update_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(HeightToValsetUpdateId {
+                    height: height__.unwrap_or_default(),
+                    valset_update_id: valset_update_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.HeightToValsetUpdateID", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LastTransmissionBlockHeight {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.LastTransmissionBlockHeight", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LastTransmissionBlockHeight {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LastTransmissionBlockHeight;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.LastTransmissionBlockHeight")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LastTransmissionBlockHeight, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LastTransmissionBlockHeight {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.LastTransmissionBlockHeight", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MaturingVscPacket {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if self.maturity_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.MaturingVSCPacket", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vscId", ToString::to_string(&self.vsc_id).as_str())?;
+        }
+        if let Some(v) = self.maturity_time.as_ref() {
+            struct_ser.serialize_field("maturityTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MaturingVscPacket {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vsc_id",
+            "vscId",
+            "maturity_time",
+            "maturityTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VscId,
+            MaturityTime,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vscId" | "vsc_id" => Ok(GeneratedField::VscId),
+                            "maturityTime" | "maturity_time" => Ok(GeneratedField::MaturityTime),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MaturingVscPacket;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.MaturingVSCPacket")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MaturingVscPacket, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vsc_id__ = None;
+                let mut maturity_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VscId => {
+                            if vsc_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscId"));
+                            }
+                            vsc_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaturityTime => {
+                            if maturity_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maturityTime"));
+                            }
+                            maturity_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MaturingVscPacket {
+                    vsc_id: vsc_id__.unwrap_or_default(),
+                    maturity_time: maturity_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.MaturingVSCPacket", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NextFeeDistributionEstimate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.NextFeeDistributionEstimate", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("currentHeight", ToString::to_string(&self.current_height).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastHeight", ToString::to_string(&self.last_height).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nextHeight", ToString::to_string(&self.next_height).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("distributionFraction", &self.distribution_fraction)?;
+        }
+        if true {
+            struct_ser.serialize_field("total", &self.total)?;
+        }
+        if true {
+            struct_ser.serialize_field("toProvider", &self.to_provider)?;
+        }
+        if true {
+            struct_ser.serialize_field("toConsumer", &self.to_consumer)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NextFeeDistributionEstimate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_height",
+            "currentHeight",
+            "last_height",
+            "lastHeight",
+            "next_height",
+            "nextHeight",
+            "distribution_fraction",
+            "distributionFraction",
+            "total",
+            "to_provider",
+            "toProvider",
+            "to_consumer",
+            "toConsumer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentHeight,
+            LastHeight,
+            NextHeight,
+            DistributionFraction,
+            Total,
+            ToProvider,
+            ToConsumer,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentHeight" | "current_height" => Ok(GeneratedField::CurrentHeight),
+                            "lastHeight" | "last_height" => Ok(GeneratedField::LastHeight),
+                            "nextHeight" | "next_height" => Ok(GeneratedField::NextHeight),
+                            "distributionFraction" | "distribution_fraction" => Ok(GeneratedField::DistributionFraction),
+                            "total" => Ok(GeneratedField::Total),
+                            "toProvider" | "to_provider" => Ok(GeneratedField::ToProvider),
+                            "toConsumer" | "to_consumer" => Ok(GeneratedField::ToConsumer),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NextFeeDistributionEstimate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.NextFeeDistributionEstimate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NextFeeDistributionEstimate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_height__ = None;
+                let mut last_height__ = None;
+                let mut next_height__ = None;
+                let mut distribution_fraction__ = None;
+                let mut total__ = None;
+                let mut to_provider__ = None;
+                let mut to_consumer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentHeight => {
+                            if current_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentHeight"));
+                            }
+                            current_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastHeight => {
+                            if last_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastHeight"));
+                            }
+                            last_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextHeight => {
+                            if next_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextHeight"));
+                            }
+                            next_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DistributionFraction => {
+                            if distribution_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distributionFraction"));
+                            }
+                            distribution_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Total => {
+                            if total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("total"));
+                            }
+                            total__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ToProvider => {
+                            if to_provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toProvider"));
+                            }
+                            to_provider__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ToConsumer => {
+                            if to_consumer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toConsumer"));
+                            }
+                            to_consumer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NextFeeDistributionEstimate {
+                    current_height: current_height__.unwrap_or_default(),
+                    last_height: last_height__.unwrap_or_default(),
+                    next_height: next_height__.unwrap_or_default(),
+                    distribution_fraction: distribution_fraction__.unwrap_or_default(),
+                    total: total__.unwrap_or_default(),
+                    to_provider: to_provider__.unwrap_or_default(),
+                    to_consumer: to_consumer__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.NextFeeDistributionEstimate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OutstandingDowntime {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.OutstandingDowntime", len)?;
+        if true {
+            struct_ser.serialize_field("validatorConsensusAddress", &self.validator_consensus_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OutstandingDowntime {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_consensus_address",
+            "validatorConsensusAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorConsensusAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorConsensusAddress" | "validator_consensus_address" => Ok(GeneratedField::ValidatorConsensusAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OutstandingDowntime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.OutstandingDowntime")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OutstandingDowntime, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_consensus_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorConsensusAddress => {
+                            if validator_consensus_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorConsensusAddress"));
+                            }
+                            validator_consensus_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OutstandingDowntime {
+                    validator_consensus_address: validator_consensus_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.OutstandingDowntime", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Params {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.ccv_timeout_period.is_some() {
+            len += 1;
+        }
+        if self.transfer_timeout_period.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.unbonding_period.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.Params", len)?;
+        if true {
+            struct_ser.serialize_field("enabled", &self.enabled)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocksPerDistributionTransmission", ToString::to_string(&self.blocks_per_distribution_transmission).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("distributionTransmissionChannel", &self.distribution_transmission_channel)?;
+        }
+        if true {
+            struct_ser.serialize_field("providerFeePoolAddrStr", &self.provider_fee_pool_addr_str)?;
+        }
+        if let Some(v) = self.ccv_timeout_period.as_ref() {
+            struct_ser.serialize_field("ccvTimeoutPeriod", v)?;
+        }
+        if let Some(v) = self.transfer_timeout_period.as_ref() {
+            struct_ser.serialize_field("transferTimeoutPeriod", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerRedistributionFraction", &self.consumer_redistribution_fraction)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("historicalEntries", ToString::to_string(&self.historical_entries).as_str())?;
+        }
+        if let Some(v) = self.unbonding_period.as_ref() {
+            struct_ser.serialize_field("unbondingPeriod", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Params {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "enabled",
+            "blocks_per_distribution_transmission",
+            "blocksPerDistributionTransmission",
+            "distribution_transmission_channel",
+            "distributionTransmissionChannel",
+            "provider_fee_pool_addr_str",
+            "providerFeePoolAddrStr",
+            "ccv_timeout_period",
+            "ccvTimeoutPeriod",
+            "transfer_timeout_period",
+            "transferTimeoutPeriod",
+            "consumer_redistribution_fraction",
+            "consumerRedistributionFraction",
+            "historical_entries",
+            "historicalEntries",
+            "unbonding_period",
+            "unbondingPeriod",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Enabled,
+            BlocksPerDistributionTransmission,
+            DistributionTransmissionChannel,
+            ProviderFeePoolAddrStr,
+            CcvTimeoutPeriod,
+            TransferTimeoutPeriod,
+            ConsumerRedistributionFraction,
+            HistoricalEntries,
+            UnbondingPeriod,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "enabled" => Ok(GeneratedField::Enabled),
+                            "blocksPerDistributionTransmission" | "blocks_per_distribution_transmission" => Ok(GeneratedField::BlocksPerDistributionTransmission),
+                            "distributionTransmissionChannel" | "distribution_transmission_channel" => Ok(GeneratedField::DistributionTransmissionChannel),
+                            "providerFeePoolAddrStr" | "provider_fee_pool_addr_str" => Ok(GeneratedField::ProviderFeePoolAddrStr),
+                            "ccvTimeoutPeriod" | "ccv_timeout_period" => Ok(GeneratedField::CcvTimeoutPeriod),
+                            "transferTimeoutPeriod" | "transfer_timeout_period" => Ok(GeneratedField::TransferTimeoutPeriod),
+                            "consumerRedistributionFraction" | "consumer_redistribution_fraction" => Ok(GeneratedField::ConsumerRedistributionFraction),
+                            "historicalEntries" | "historical_entries" => Ok(GeneratedField::HistoricalEntries),
+                            "unbondingPeriod" | "unbonding_period" => Ok(GeneratedField::UnbondingPeriod),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Params;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.Params")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Params, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut enabled__ = None;
+                let mut blocks_per_distribution_transmission__ = None;
+                let mut distribution_transmission_channel__ = None;
+                let mut provider_fee_pool_addr_str__ = None;
+                let mut ccv_timeout_period__ = None;
+                let mut transfer_timeout_period__ = None;
+                let mut consumer_redistribution_fraction__ = None;
+                let mut historical_entries__ = None;
+                let mut unbonding_period__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Enabled => {
+                            if enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enabled"));
+                            }
+                            enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlocksPerDistributionTransmission => {
+                            if blocks_per_distribution_transmission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocksPerDistributionTransmission"));
+                            }
+                            blocks_per_distribution_transmission__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DistributionTransmissionChannel => {
+                            if distribution_transmission_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distributionTransmissionChannel"));
+                            }
+                            distribution_transmission_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderFeePoolAddrStr => {
+                            if provider_fee_pool_addr_str__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerFeePoolAddrStr"));
+                            }
+                            provider_fee_pool_addr_str__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CcvTimeoutPeriod => {
+                            if ccv_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ccvTimeoutPeriod"));
+                            }
+                            ccv_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::TransferTimeoutPeriod => {
+                            if transfer_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferTimeoutPeriod"));
+                            }
+                            transfer_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::ConsumerRedistributionFraction => {
+                            if consumer_redistribution_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerRedistributionFraction"));
+                            }
+                            consumer_redistribution_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::HistoricalEntries => {
+                            if historical_entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historicalEntries"));
+                            }
+                            historical_entries__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondingPeriod => {
+                            if unbonding_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingPeriod"));
+                            }
+                            unbonding_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Params {
+                    enabled: enabled__.unwrap_or_default(),
+                    blocks_per_distribution_transmission: blocks_per_distribution_transmission__.unwrap_or_default(),
+                    distribution_transmission_channel: distribution_transmission_channel__.unwrap_or_default(),
+                    provider_fee_pool_addr_str: provider_fee_pool_addr_str__.unwrap_or_default(),
+                    ccv_timeout_period: ccv_timeout_period__,
+                    transfer_timeout_period: transfer_timeout_period__,
+                    consumer_redistribution_fraction: consumer_redistribution_fraction__.unwrap_or_default(),
+                    historical_entries: historical_entries__.unwrap_or_default(),
+                    unbonding_period: unbonding_period__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.Params", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryNextFeeDistributionEstimateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryNextFeeDistributionEstimateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryNextFeeDistributionEstimateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryNextFeeDistributionEstimateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryNextFeeDistributionEstimateRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryNextFeeDistributionEstimateResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryNextFeeDistributionEstimateResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryNextFeeDistributionEstimateResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryNextFeeDistributionEstimateResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryNextFeeDistributionEstimateResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.QueryNextFeeDistributionEstimateResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryParamsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.QueryParamsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryParamsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryParamsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.QueryParamsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryParamsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryParamsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.QueryParamsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryParamsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.consumer.v1.QueryParamsResponse", len)?;
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryParamsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "params",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Params,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "params" => Ok(GeneratedField::Params),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryParamsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.consumer.v1.QueryParamsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryParamsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryParamsResponse {
+                    params: params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.consumer.v1.QueryParamsResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,6 +1,7 @@
 /// ConsumerAdditionProposal is a governance proposal on the provider chain to spawn a new consumer chain.
 /// If it passes, then all validators on the provider chain are expected to validate the consumer chain at spawn time
 /// or get slashed. It is recommended that spawn time occurs after the proposal end time.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsumerAdditionProposal {
@@ -68,6 +69,7 @@ pub struct ConsumerAdditionProposal {
 /// ConsumerRemovalProposal is a governance proposal on the provider chain to remove (and stop) a consumer chain.
 /// If it passes, all the consumer chain's state is removed from the provider chain. The outstanding unbonding
 /// operation funds are released.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsumerRemovalProposal {
@@ -86,6 +88,7 @@ pub struct ConsumerRemovalProposal {
         super::super::super::super::google::protobuf::Timestamp,
     >,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EquivocationProposal {
@@ -103,6 +106,7 @@ pub struct EquivocationProposal {
 }
 /// A persisted queue entry indicating that a slash packet data instance needs to be handled.
 /// This type belongs in the "global" queue, to coordinate slash packet handling times between consumers.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GlobalSlashEntry {
@@ -169,6 +173,7 @@ pub struct Params {
     #[prost(int64, tag = "8")]
     pub max_throttled_packets: i64,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HandshakeMetadata {
@@ -179,6 +184,7 @@ pub struct HandshakeMetadata {
 }
 /// SlashAcks contains addesses of consumer chain validators
 /// successfully slashed on the provider chain
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SlashAcks {
@@ -186,6 +192,7 @@ pub struct SlashAcks {
     pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// ConsumerAdditionProposals holds pending governance proposals on the provider chain to spawn a new chain.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsumerAdditionProposals {
@@ -194,6 +201,7 @@ pub struct ConsumerAdditionProposals {
     pub pending: ::prost::alloc::vec::Vec<ConsumerAdditionProposal>,
 }
 /// ConsumerRemovalProposals holds pending governance proposals on the provider chain to remove (and stop) a consumer chain.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsumerRemovalProposals {
@@ -202,12 +210,14 @@ pub struct ConsumerRemovalProposals {
     pub pending: ::prost::alloc::vec::Vec<ConsumerRemovalProposal>,
 }
 /// AddressList contains a list of consensus addresses
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddressList {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChannelToChain {
@@ -218,6 +228,7 @@ pub struct ChannelToChain {
 }
 /// VscUnbondingOps contains the IDs of unbonding operations that are waiting for
 /// at least one VSCMaturedPacket with vscID from a consumer chain
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VscUnbondingOps {
@@ -228,6 +239,7 @@ pub struct VscUnbondingOps {
 }
 /// UnbondingOp contains the ids of consumer chains that need to unbond before
 /// the unbonding operation with the given ID can unbond
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingOp {
@@ -239,6 +251,7 @@ pub struct UnbondingOp {
         ::prost::alloc::string::String,
     >,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InitTimeoutTimestamp {
@@ -247,6 +260,7 @@ pub struct InitTimeoutTimestamp {
     #[prost(uint64, tag = "2")]
     pub timestamp: u64,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VscSendTimestamp {
@@ -267,6 +281,7 @@ pub struct KeyAssignmentReplacement {
     #[prost(int64, tag = "3")]
     pub power: i64,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerGenesisRequest {
@@ -279,33 +294,40 @@ pub struct QueryConsumerGenesisResponse {
     #[prost(message, optional, tag = "1")]
     pub genesis_state: ::core::option::Option<super::super::consumer::v1::GenesisState>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainsRequest {}
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainsResponse {
     #[prost(message, repeated, tag = "1")]
     pub chains: ::prost::alloc::vec::Vec<Chain>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainStartProposalsRequest {}
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainStartProposalsResponse {
     #[prost(message, optional, tag = "1")]
     pub proposals: ::core::option::Option<ConsumerAdditionProposals>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainStopProposalsRequest {}
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConsumerChainStopProposalsResponse {
     #[prost(message, optional, tag = "1")]
     pub proposals: ::core::option::Option<ConsumerRemovalProposals>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Chain {
@@ -314,6 +336,7 @@ pub struct Chain {
     #[prost(string, tag = "2")]
     pub client_id: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorConsumerAddrRequest {
@@ -324,6 +347,7 @@ pub struct QueryValidatorConsumerAddrRequest {
     #[prost(string, tag = "2")]
     pub provider_address: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorConsumerAddrResponse {
@@ -331,6 +355,7 @@ pub struct QueryValidatorConsumerAddrResponse {
     #[prost(string, tag = "1")]
     pub consumer_address: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorProviderAddrRequest {
@@ -341,6 +366,7 @@ pub struct QueryValidatorProviderAddrRequest {
     #[prost(string, tag = "2")]
     pub consumer_address: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorProviderAddrResponse {
@@ -348,6 +374,7 @@ pub struct QueryValidatorProviderAddrResponse {
     #[prost(string, tag = "1")]
     pub provider_address: ::prost::alloc::string::String,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryThrottleStateRequest {}
@@ -370,6 +397,7 @@ pub struct QueryThrottleStateResponse {
     #[prost(message, repeated, tag = "4")]
     pub packets: ::prost::alloc::vec::Vec<ThrottledSlashPacket>,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryThrottledConsumerPacketDataRequest {
@@ -423,6 +451,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -433,6 +462,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -1341,6 +1391,7 @@ pub mod query_server {
         const NAME: &'static str = "interchain_security.ccv.provider.v1.Query";
     }
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgAssignConsumerKey {
@@ -1356,6 +1407,7 @@ pub struct MsgAssignConsumerKey {
         super::super::super::super::google::protobuf::Any,
     >,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgAssignConsumerKeyResponse {}
@@ -1373,6 +1425,7 @@ pub struct MsgSubmitConsumerMisbehaviour {
         super::super::super::super::ibc::lightclients::tendermint::v1::Misbehaviour,
     >,
 }
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSubmitConsumerMisbehaviourResponse {}
@@ -1387,6 +1440,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -1397,6 +1451,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -1832,6 +1907,7 @@ pub struct ConsumerState {
 }
 /// ValsetUpdateIdToHeight defines the genesis information for the mapping
 /// of each valset udpate id to a block height
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValsetUpdateIdToHeight {
@@ -1854,6 +1930,7 @@ pub struct ValidatorConsumerPubKey {
 }
 /// Used to serialize the ValidatorConsumerAddr index from key assignment
 /// ValidatorByConsumerAddr: (chainID, consumerAddr consAddr) -> providerAddr consAddr
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorByConsumerAddr {
@@ -1866,6 +1943,7 @@ pub struct ValidatorByConsumerAddr {
 }
 /// Used to serialize the ConsumerAddrsToPrune index from key assignment
 /// ConsumerAddrsToPrune: (chainID, vscID uint64) -> consumerAddrs AddressList
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsumerAddrsToPrune {
@@ -1876,3 +1954,234 @@ pub struct ConsumerAddrsToPrune {
     #[prost(message, optional, tag = "3")]
     pub consumer_addrs: ::core::option::Option<AddressList>,
 }
+impl crate::protobuf::Name for ConsumerAdditionProposal {
+    const NAME: &'static str = "ConsumerAdditionProposal";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ConsumerRemovalProposal {
+    const NAME: &'static str = "ConsumerRemovalProposal";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for EquivocationProposal {
+    const NAME: &'static str = "EquivocationProposal";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for GlobalSlashEntry {
+    const NAME: &'static str = "GlobalSlashEntry";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for HandshakeMetadata {
+    const NAME: &'static str = "HandshakeMetadata";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for SlashAcks {
+    const NAME: &'static str = "SlashAcks";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ConsumerAdditionProposals {
+    const NAME: &'static str = "ConsumerAdditionProposals";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ConsumerRemovalProposals {
+    const NAME: &'static str = "ConsumerRemovalProposals";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for AddressList {
+    const NAME: &'static str = "AddressList";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ChannelToChain {
+    const NAME: &'static str = "ChannelToChain";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for VscUnbondingOps {
+    const NAME: &'static str = "VscUnbondingOps";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for UnbondingOp {
+    const NAME: &'static str = "UnbondingOp";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for InitTimeoutTimestamp {
+    const NAME: &'static str = "InitTimeoutTimestamp";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for VscSendTimestamp {
+    const NAME: &'static str = "VscSendTimestamp";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for KeyAssignmentReplacement {
+    const NAME: &'static str = "KeyAssignmentReplacement";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerGenesisRequest {
+    const NAME: &'static str = "QueryConsumerGenesisRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerGenesisResponse {
+    const NAME: &'static str = "QueryConsumerGenesisResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainsRequest {
+    const NAME: &'static str = "QueryConsumerChainsRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainsResponse {
+    const NAME: &'static str = "QueryConsumerChainsResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainStartProposalsRequest {
+    const NAME: &'static str = "QueryConsumerChainStartProposalsRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainStartProposalsResponse {
+    const NAME: &'static str = "QueryConsumerChainStartProposalsResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainStopProposalsRequest {
+    const NAME: &'static str = "QueryConsumerChainStopProposalsRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryConsumerChainStopProposalsResponse {
+    const NAME: &'static str = "QueryConsumerChainStopProposalsResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for Chain {
+    const NAME: &'static str = "Chain";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryValidatorConsumerAddrRequest {
+    const NAME: &'static str = "QueryValidatorConsumerAddrRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryValidatorConsumerAddrResponse {
+    const NAME: &'static str = "QueryValidatorConsumerAddrResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryValidatorProviderAddrRequest {
+    const NAME: &'static str = "QueryValidatorProviderAddrRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryValidatorProviderAddrResponse {
+    const NAME: &'static str = "QueryValidatorProviderAddrResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryThrottleStateRequest {
+    const NAME: &'static str = "QueryThrottleStateRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryThrottleStateResponse {
+    const NAME: &'static str = "QueryThrottleStateResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryThrottledConsumerPacketDataRequest {
+    const NAME: &'static str = "QueryThrottledConsumerPacketDataRequest";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for QueryThrottledConsumerPacketDataResponse {
+    const NAME: &'static str = "QueryThrottledConsumerPacketDataResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ThrottledSlashPacket {
+    const NAME: &'static str = "ThrottledSlashPacket";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ThrottledPacketDataWrapper {
+    const NAME: &'static str = "ThrottledPacketDataWrapper";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for MsgAssignConsumerKey {
+    const NAME: &'static str = "MsgAssignConsumerKey";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for MsgAssignConsumerKeyResponse {
+    const NAME: &'static str = "MsgAssignConsumerKeyResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for MsgSubmitConsumerMisbehaviour {
+    const NAME: &'static str = "MsgSubmitConsumerMisbehaviour";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for MsgSubmitConsumerMisbehaviourResponse {
+    const NAME: &'static str = "MsgSubmitConsumerMisbehaviourResponse";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ConsumerState {
+    const NAME: &'static str = "ConsumerState";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ValsetUpdateIdToHeight {
+    const NAME: &'static str = "ValsetUpdateIdToHeight";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ValidatorConsumerPubKey {
+    const NAME: &'static str = "ValidatorConsumerPubKey";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ValidatorByConsumerAddr {
+    const NAME: &'static str = "ValidatorByConsumerAddr";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+impl crate::protobuf::Name for ConsumerAddrsToPrune {
+    const NAME: &'static str = "ConsumerAddrsToPrune";
+    const PACKAGE: &'static str = "interchain_security.ccv.provider.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<ConsumerAdditionProposal>();
+    registry.register::<ConsumerRemovalProposal>();
+    registry.register::<EquivocationProposal>();
+    registry.register::<GlobalSlashEntry>();
+    registry.register::<Params>();
+    registry.register::<HandshakeMetadata>();
+    registry.register::<SlashAcks>();
+    registry.register::<ConsumerAdditionProposals>();
+    registry.register::<ConsumerRemovalProposals>();
+    registry.register::<AddressList>();
+    registry.register::<ChannelToChain>();
+    registry.register::<VscUnbondingOps>();
+    registry.register::<UnbondingOp>();
+    registry.register::<InitTimeoutTimestamp>();
+    registry.register::<VscSendTimestamp>();
+    registry.register::<KeyAssignmentReplacement>();
+    registry.register::<QueryConsumerGenesisRequest>();
+    registry.register::<QueryConsumerGenesisResponse>();
+    registry.register::<QueryConsumerChainsRequest>();
+    registry.register::<QueryConsumerChainsResponse>();
+    registry.register::<QueryConsumerChainStartProposalsRequest>();
+    registry.register::<QueryConsumerChainStartProposalsResponse>();
+    registry.register::<QueryConsumerChainStopProposalsRequest>();
+    registry.register::<QueryConsumerChainStopProposalsResponse>();
+    registry.register::<Chain>();
+    registry.register::<QueryValidatorConsumerAddrRequest>();
+    registry.register::<QueryValidatorConsumerAddrResponse>();
+    registry.register::<QueryValidatorProviderAddrRequest>();
+    registry.register::<QueryValidatorProviderAddrResponse>();
+    registry.register::<QueryThrottleStateRequest>();
+    registry.register::<QueryThrottleStateResponse>();
+    registry.register::<QueryThrottledConsumerPacketDataRequest>();
+    registry.register::<QueryThrottledConsumerPacketDataResponse>();
+    registry.register::<ThrottledSlashPacket>();
+    registry.register::<ThrottledPacketDataWrapper>();
+    registry.register::<MsgAssignConsumerKey>();
+    registry.register::<MsgAssignConsumerKeyResponse>();
+    registry.register::<MsgSubmitConsumerMisbehaviour>();
+    registry.register::<MsgSubmitConsumerMisbehaviourResponse>();
+    registry.register::<GenesisState>();
+    registry.register::<ConsumerState>();
+    registry.register::<ValsetUpdateIdToHeight>();
+    registry.register::<ValidatorConsumerPubKey>();
+    registry.register::<ValidatorByConsumerAddr>();
+    registry.register::<ConsumerAddrsToPrune>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("interchain_security.ccv.provider.v1.bin");
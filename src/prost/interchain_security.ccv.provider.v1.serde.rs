@@ -0,0 +1,5523 @@
+impl serde::Serialize for AddressList {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.AddressList", len)?;
+        if true {
+            struct_ser.serialize_field("addresses", &self.addresses.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddressList {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addresses",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addresses,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addresses" => Ok(GeneratedField::Addresses),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddressList;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.AddressList")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AddressList, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut addresses__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addresses => {
+                            if addresses__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addresses"));
+                            }
+                            addresses__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AddressList {
+                    addresses: addresses__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.AddressList", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Chain {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.Chain", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Chain {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "client_id",
+            "clientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ClientId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Chain;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.Chain")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Chain, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut client_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
+                            }
+                            client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Chain {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    client_id: client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.Chain", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChannelToChain {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ChannelToChain", len)?;
+        if true {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChannelToChain {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            ChainId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChannelToChain;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ChannelToChain")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChannelToChain, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ChannelToChain {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ChannelToChain", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerAdditionProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.initial_height.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.spawn_time.is_some() {
+            len += 1;
+        }
+        if self.unbonding_period.is_some() {
+            len += 1;
+        }
+        if self.ccv_timeout_period.is_some() {
+            len += 1;
+        }
+        if self.transfer_timeout_period.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerAdditionProposal", len)?;
+        if true {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if true {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.initial_height.as_ref() {
+            struct_ser.serialize_field("initialHeight", v)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("genesisHash", pbjson::private::base64::encode(&self.genesis_hash).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("binaryHash", pbjson::private::base64::encode(&self.binary_hash).as_str())?;
+        }
+        if let Some(v) = self.spawn_time.as_ref() {
+            struct_ser.serialize_field("spawnTime", v)?;
+        }
+        if let Some(v) = self.unbonding_period.as_ref() {
+            struct_ser.serialize_field("unbondingPeriod", v)?;
+        }
+        if let Some(v) = self.ccv_timeout_period.as_ref() {
+            struct_ser.serialize_field("ccvTimeoutPeriod", v)?;
+        }
+        if let Some(v) = self.transfer_timeout_period.as_ref() {
+            struct_ser.serialize_field("transferTimeoutPeriod", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerRedistributionFraction", &self.consumer_redistribution_fraction)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocksPerDistributionTransmission", ToString::to_string(&self.blocks_per_distribution_transmission).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("historicalEntries", ToString::to_string(&self.historical_entries).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerAdditionProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "description",
+            "chain_id",
+            "chainId",
+            "initial_height",
+            "initialHeight",
+            "genesis_hash",
+            "genesisHash",
+            "binary_hash",
+            "binaryHash",
+            "spawn_time",
+            "spawnTime",
+            "unbonding_period",
+            "unbondingPeriod",
+            "ccv_timeout_period",
+            "ccvTimeoutPeriod",
+            "transfer_timeout_period",
+            "transferTimeoutPeriod",
+            "consumer_redistribution_fraction",
+            "consumerRedistributionFraction",
+            "blocks_per_distribution_transmission",
+            "blocksPerDistributionTransmission",
+            "historical_entries",
+            "historicalEntries",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            Description,
+            ChainId,
+            InitialHeight,
+            GenesisHash,
+            BinaryHash,
+            SpawnTime,
+            UnbondingPeriod,
+            CcvTimeoutPeriod,
+            TransferTimeoutPeriod,
+            ConsumerRedistributionFraction,
+            BlocksPerDistributionTransmission,
+            HistoricalEntries,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "description" => Ok(GeneratedField::Description),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "initialHeight" | "initial_height" => Ok(GeneratedField::InitialHeight),
+                            "genesisHash" | "genesis_hash" => Ok(GeneratedField::GenesisHash),
+                            "binaryHash" | "binary_hash" => Ok(GeneratedField::BinaryHash),
+                            "spawnTime" | "spawn_time" => Ok(GeneratedField::SpawnTime),
+                            "unbondingPeriod" | "unbonding_period" => Ok(GeneratedField::UnbondingPeriod),
+                            "ccvTimeoutPeriod" | "ccv_timeout_period" => Ok(GeneratedField::CcvTimeoutPeriod),
+                            "transferTimeoutPeriod" | "transfer_timeout_period" => Ok(GeneratedField::TransferTimeoutPeriod),
+                            "consumerRedistributionFraction" | "consumer_redistribution_fraction" => Ok(GeneratedField::ConsumerRedistributionFraction),
+                            "blocksPerDistributionTransmission" | "blocks_per_distribution_transmission" => Ok(GeneratedField::BlocksPerDistributionTransmission),
+                            "historicalEntries" | "historical_entries" => Ok(GeneratedField::HistoricalEntries),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerAdditionProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerAdditionProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerAdditionProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut description__ = None;
+                let mut chain_id__ = None;
+                let mut initial_height__ = None;
+                let mut genesis_hash__ = None;
+                let mut binary_hash__ = None;
+                let mut spawn_time__ = None;
+                let mut unbonding_period__ = None;
+                let mut ccv_timeout_period__ = None;
+                let mut transfer_timeout_period__ = None;
+                let mut consumer_redistribution_fraction__ = None;
+                let mut blocks_per_distribution_transmission__ = None;
+                let mut historical_entries__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitialHeight => {
+                            if initial_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialHeight"));
+                            }
+                            initial_height__ = map_.next_value()?;
+                        }
+                        GeneratedField::GenesisHash => {
+                            if genesis_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesisHash"));
+                            }
+                            genesis_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BinaryHash => {
+                            if binary_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("binaryHash"));
+                            }
+                            binary_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SpawnTime => {
+                            if spawn_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spawnTime"));
+                            }
+                            spawn_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondingPeriod => {
+                            if unbonding_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingPeriod"));
+                            }
+                            unbonding_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::CcvTimeoutPeriod => {
+                            if ccv_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ccvTimeoutPeriod"));
+                            }
+                            ccv_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::TransferTimeoutPeriod => {
+                            if transfer_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferTimeoutPeriod"));
+                            }
+                            transfer_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::ConsumerRedistributionFraction => {
+                            if consumer_redistribution_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerRedistributionFraction"));
+                            }
+                            consumer_redistribution_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlocksPerDistributionTransmission => {
+                            if blocks_per_distribution_transmission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocksPerDistributionTransmission"));
+                            }
+                            blocks_per_distribution_transmission__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HistoricalEntries => {
+                            if historical_entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("historicalEntries"));
+                            }
+                            historical_entries__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerAdditionProposal {
+                    title: title__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                    initial_height: initial_height__,
+                    genesis_hash: genesis_hash__.unwrap_or_default(),
+                    binary_hash: binary_hash__.unwrap_or_default(),
+                    spawn_time: spawn_time__,
+                    unbonding_period: unbonding_period__,
+                    ccv_timeout_period: ccv_timeout_period__,
+                    transfer_timeout_period: transfer_timeout_period__,
+                    consumer_redistribution_fraction: consumer_redistribution_fraction__.unwrap_or_default(),
+                    blocks_per_distribution_transmission: blocks_per_distribution_transmission__.unwrap_or_default(),
+                    historical_entries: historical_entries__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerAdditionProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerAdditionProposals {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerAdditionProposals", len)?;
+        if true {
+            struct_ser.serialize_field("pending", &self.pending)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerAdditionProposals {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pending",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pending,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pending" => Ok(GeneratedField::Pending),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerAdditionProposals;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerAdditionProposals")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerAdditionProposals, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pending__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pending => {
+                            if pending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pending"));
+                            }
+                            pending__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerAdditionProposals {
+                    pending: pending__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerAdditionProposals", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerAddrsToPrune {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.consumer_addrs.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerAddrsToPrune", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vscId", ToString::to_string(&self.vsc_id).as_str())?;
+        }
+        if let Some(v) = self.consumer_addrs.as_ref() {
+            struct_ser.serialize_field("consumerAddrs", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerAddrsToPrune {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "vsc_id",
+            "vscId",
+            "consumer_addrs",
+            "consumerAddrs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            VscId,
+            ConsumerAddrs,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "vscId" | "vsc_id" => Ok(GeneratedField::VscId),
+                            "consumerAddrs" | "consumer_addrs" => Ok(GeneratedField::ConsumerAddrs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerAddrsToPrune;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerAddrsToPrune")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerAddrsToPrune, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut vsc_id__ = None;
+                let mut consumer_addrs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VscId => {
+                            if vsc_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscId"));
+                            }
+                            vsc_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ConsumerAddrs => {
+                            if consumer_addrs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAddrs"));
+                            }
+                            consumer_addrs__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerAddrsToPrune {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    vsc_id: vsc_id__.unwrap_or_default(),
+                    consumer_addrs: consumer_addrs__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerAddrsToPrune", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerRemovalProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.stop_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerRemovalProposal", len)?;
+        if true {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if true {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.stop_time.as_ref() {
+            struct_ser.serialize_field("stopTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerRemovalProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "description",
+            "chain_id",
+            "chainId",
+            "stop_time",
+            "stopTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            Description,
+            ChainId,
+            StopTime,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "description" => Ok(GeneratedField::Description),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "stopTime" | "stop_time" => Ok(GeneratedField::StopTime),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerRemovalProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerRemovalProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerRemovalProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut description__ = None;
+                let mut chain_id__ = None;
+                let mut stop_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StopTime => {
+                            if stop_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopTime"));
+                            }
+                            stop_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerRemovalProposal {
+                    title: title__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                    stop_time: stop_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerRemovalProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerRemovalProposals {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerRemovalProposals", len)?;
+        if true {
+            struct_ser.serialize_field("pending", &self.pending)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerRemovalProposals {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pending",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pending,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pending" => Ok(GeneratedField::Pending),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerRemovalProposals;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerRemovalProposals")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerRemovalProposals, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pending__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pending => {
+                            if pending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pending"));
+                            }
+                            pending__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerRemovalProposals {
+                    pending: pending__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerRemovalProposals", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConsumerState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.consumer_genesis.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ConsumerState", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("initialHeight", ToString::to_string(&self.initial_height).as_str())?;
+        }
+        if let Some(v) = self.consumer_genesis.as_ref() {
+            struct_ser.serialize_field("consumerGenesis", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("pendingValsetChanges", &self.pending_valset_changes)?;
+        }
+        if true {
+            struct_ser.serialize_field("slashDowntimeAck", &self.slash_downtime_ack)?;
+        }
+        if true {
+            struct_ser.serialize_field("unbondingOpsIndex", &self.unbonding_ops_index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConsumerState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "channel_id",
+            "channelId",
+            "client_id",
+            "clientId",
+            "initial_height",
+            "initialHeight",
+            "consumer_genesis",
+            "consumerGenesis",
+            "pending_valset_changes",
+            "pendingValsetChanges",
+            "slash_downtime_ack",
+            "slashDowntimeAck",
+            "unbonding_ops_index",
+            "unbondingOpsIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ChannelId,
+            ClientId,
+            InitialHeight,
+            ConsumerGenesis,
+            PendingValsetChanges,
+            SlashDowntimeAck,
+            UnbondingOpsIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "initialHeight" | "initial_height" => Ok(GeneratedField::InitialHeight),
+                            "consumerGenesis" | "consumer_genesis" => Ok(GeneratedField::ConsumerGenesis),
+                            "pendingValsetChanges" | "pending_valset_changes" => Ok(GeneratedField::PendingValsetChanges),
+                            "slashDowntimeAck" | "slash_downtime_ack" => Ok(GeneratedField::SlashDowntimeAck),
+                            "unbondingOpsIndex" | "unbonding_ops_index" => Ok(GeneratedField::UnbondingOpsIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConsumerState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ConsumerState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConsumerState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut channel_id__ = None;
+                let mut client_id__ = None;
+                let mut initial_height__ = None;
+                let mut consumer_genesis__ = None;
+                let mut pending_valset_changes__ = None;
+                let mut slash_downtime_ack__ = None;
+                let mut unbonding_ops_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
+                            }
+                            client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InitialHeight => {
+                            if initial_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialHeight"));
+                            }
+                            initial_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ConsumerGenesis => {
+                            if consumer_genesis__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerGenesis"));
+                            }
+                            consumer_genesis__ = map_.next_value()?;
+                        }
+                        GeneratedField::PendingValsetChanges => {
+                            if pending_valset_changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingValsetChanges"));
+                            }
+                            pending_valset_changes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SlashDowntimeAck => {
+                            if slash_downtime_ack__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashDowntimeAck"));
+                            }
+                            slash_downtime_ack__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UnbondingOpsIndex => {
+                            if unbonding_ops_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingOpsIndex"));
+                            }
+                            unbonding_ops_index__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConsumerState {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
+                    client_id: client_id__.unwrap_or_default(),
+                    initial_height: initial_height__.unwrap_or_default(),
+                    consumer_genesis: consumer_genesis__,
+                    pending_valset_changes: pending_valset_changes__.unwrap_or_default(),
+                    slash_downtime_ack: slash_downtime_ack__.unwrap_or_default(),
+                    unbonding_ops_index: unbonding_ops_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ConsumerState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EquivocationProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.EquivocationProposal", len)?;
+        if true {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if true {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if true {
+            struct_ser.serialize_field("equivocations", &self.equivocations)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EquivocationProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "description",
+            "equivocations",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            Description,
+            Equivocations,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "description" => Ok(GeneratedField::Description),
+                            "equivocations" => Ok(GeneratedField::Equivocations),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EquivocationProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.EquivocationProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EquivocationProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut description__ = None;
+                let mut equivocations__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Equivocations => {
+                            if equivocations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("equivocations"));
+                            }
+                            equivocations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EquivocationProposal {
+                    title: title__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    equivocations: equivocations__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.EquivocationProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.mature_unbonding_ops.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.params.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.GenesisState", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("valsetUpdateId", ToString::to_string(&self.valset_update_id).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerStates", &self.consumer_states)?;
+        }
+        if true {
+            struct_ser.serialize_field("unbondingOps", &self.unbonding_ops)?;
+        }
+        if let Some(v) = self.mature_unbonding_ops.as_ref() {
+            struct_ser.serialize_field("matureUnbondingOps", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("valsetUpdateIdToHeight", &self.valset_update_id_to_height)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerAdditionProposals", &self.consumer_addition_proposals)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerRemovalProposals", &self.consumer_removal_proposals)?;
+        }
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("validatorConsumerPubkeys", &self.validator_consumer_pubkeys)?;
+        }
+        if true {
+            struct_ser.serialize_field("validatorsByConsumerAddr", &self.validators_by_consumer_addr)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerAddrsToPrune", &self.consumer_addrs_to_prune)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "valset_update_id",
+            "valsetUpdateId",
+            "consumer_states",
+            "consumerStates",
+            "unbonding_ops",
+            "unbondingOps",
+            "mature_unbonding_ops",
+            "matureUnbondingOps",
+            "valset_update_id_to_height",
+            "valsetUpdateIdToHeight",
+            "consumer_addition_proposals",
+            "consumerAdditionProposals",
+            "consumer_removal_proposals",
+            "consumerRemovalProposals",
+            "params",
+            "validator_consumer_pubkeys",
+            "validatorConsumerPubkeys",
+            "validators_by_consumer_addr",
+            "validatorsByConsumerAddr",
+            "consumer_addrs_to_prune",
+            "consumerAddrsToPrune",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValsetUpdateId,
+            ConsumerStates,
+            UnbondingOps,
+            MatureUnbondingOps,
+            ValsetUpdateIdToHeight,
+            ConsumerAdditionProposals,
+            ConsumerRemovalProposals,
+            Params,
+            ValidatorConsumerPubkeys,
+            ValidatorsByConsumerAddr,
+            ConsumerAddrsToPrune,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "valsetUpdateId" | "valset_update_id" => Ok(GeneratedField::ValsetUpdateId),
+                            "consumerStates" | "consumer_states" => Ok(GeneratedField::ConsumerStates),
+                            "unbondingOps" | "unbonding_ops" => Ok(GeneratedField::UnbondingOps),
+                            "matureUnbondingOps" | "mature_unbonding_ops" => Ok(GeneratedField::MatureUnbondingOps),
+                            "valsetUpdateIdToHeight" | "valset_update_id_to_height" => Ok(GeneratedField::ValsetUpdateIdToHeight),
+                            "consumerAdditionProposals" | "consumer_addition_proposals" => Ok(GeneratedField::ConsumerAdditionProposals),
+                            "consumerRemovalProposals" | "consumer_removal_proposals" => Ok(GeneratedField::ConsumerRemovalProposals),
+                            "params" => Ok(GeneratedField::Params),
+                            "validatorConsumerPubkeys" | "validator_consumer_pubkeys" => Ok(GeneratedField::ValidatorConsumerPubkeys),
+                            "validatorsByConsumerAddr" | "validators_by_consumer_addr" => Ok(GeneratedField::ValidatorsByConsumerAddr),
+                            "consumerAddrsToPrune" | "consumer_addrs_to_prune" => Ok(GeneratedField::ConsumerAddrsToPrune),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.GenesisState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut valset_update_id__ = None;
+                let mut consumer_states__ = None;
+                let mut unbonding_ops__ = None;
+                let mut mature_unbonding_ops__ = None;
+                let mut valset_update_id_to_height__ = None;
+                let mut consumer_addition_proposals__ = None;
+                let mut consumer_removal_proposals__ = None;
+                let mut params__ = None;
+                let mut validator_consumer_pubkeys__ = None;
+                let mut validators_by_consumer_addr__ = None;
+                let mut consumer_addrs_to_prune__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValsetUpdateId => {
+                            if valset_update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valsetUpdateId"));
+                            }
+                            valset_update_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ConsumerStates => {
+                            if consumer_states__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerStates"));
+                            }
+                            consumer_states__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UnbondingOps => {
+                            if unbonding_ops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingOps"));
+                            }
+                            unbonding_ops__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MatureUnbondingOps => {
+                            if mature_unbonding_ops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("matureUnbondingOps"));
+                            }
+                            mature_unbonding_ops__ = map_.next_value()?;
+                        }
+                        GeneratedField::ValsetUpdateIdToHeight => {
+                            if valset_update_id_to_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valsetUpdateIdToHeight"));
+                            }
+                            valset_update_id_to_height__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerAdditionProposals => {
+                            if consumer_addition_proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAdditionProposals"));
+                            }
+                            consumer_addition_proposals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerRemovalProposals => {
+                            if consumer_removal_proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerRemovalProposals"));
+                            }
+                            consumer_removal_proposals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = map_.next_value()?;
+                        }
+                        GeneratedField::ValidatorConsumerPubkeys => {
+                            if validator_consumer_pubkeys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorConsumerPubkeys"));
+                            }
+                            validator_consumer_pubkeys__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorsByConsumerAddr => {
+                            if validators_by_consumer_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorsByConsumerAddr"));
+                            }
+                            validators_by_consumer_addr__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerAddrsToPrune => {
+                            if consumer_addrs_to_prune__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAddrsToPrune"));
+                            }
+                            consumer_addrs_to_prune__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisState {
+                    valset_update_id: valset_update_id__.unwrap_or_default(),
+                    consumer_states: consumer_states__.unwrap_or_default(),
+                    unbonding_ops: unbonding_ops__.unwrap_or_default(),
+                    mature_unbonding_ops: mature_unbonding_ops__,
+                    valset_update_id_to_height: valset_update_id_to_height__.unwrap_or_default(),
+                    consumer_addition_proposals: consumer_addition_proposals__.unwrap_or_default(),
+                    consumer_removal_proposals: consumer_removal_proposals__.unwrap_or_default(),
+                    params: params__,
+                    validator_consumer_pubkeys: validator_consumer_pubkeys__.unwrap_or_default(),
+                    validators_by_consumer_addr: validators_by_consumer_addr__.unwrap_or_default(),
+                    consumer_addrs_to_prune: consumer_addrs_to_prune__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.GenesisState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GlobalSlashEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.recv_time.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.GlobalSlashEntry", len)?;
+        if let Some(v) = self.recv_time.as_ref() {
+            struct_ser.serialize_field("recvTime", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerChainId", &self.consumer_chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ibcSeqNum", ToString::to_string(&self.ibc_seq_num).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("providerValConsAddr", pbjson::private::base64::encode(&self.provider_val_cons_addr).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GlobalSlashEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recv_time",
+            "recvTime",
+            "consumer_chain_id",
+            "consumerChainId",
+            "ibc_seq_num",
+            "ibcSeqNum",
+            "provider_val_cons_addr",
+            "providerValConsAddr",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecvTime,
+            ConsumerChainId,
+            IbcSeqNum,
+            ProviderValConsAddr,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recvTime" | "recv_time" => Ok(GeneratedField::RecvTime),
+                            "consumerChainId" | "consumer_chain_id" => Ok(GeneratedField::ConsumerChainId),
+                            "ibcSeqNum" | "ibc_seq_num" => Ok(GeneratedField::IbcSeqNum),
+                            "providerValConsAddr" | "provider_val_cons_addr" => Ok(GeneratedField::ProviderValConsAddr),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GlobalSlashEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.GlobalSlashEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GlobalSlashEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recv_time__ = None;
+                let mut consumer_chain_id__ = None;
+                let mut ibc_seq_num__ = None;
+                let mut provider_val_cons_addr__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RecvTime => {
+                            if recv_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recvTime"));
+                            }
+                            recv_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::ConsumerChainId => {
+                            if consumer_chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerChainId"));
+                            }
+                            consumer_chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IbcSeqNum => {
+                            if ibc_seq_num__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcSeqNum"));
+                            }
+                            ibc_seq_num__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProviderValConsAddr => {
+                            if provider_val_cons_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerValConsAddr"));
+                            }
+                            provider_val_cons_addr__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GlobalSlashEntry {
+                    recv_time: recv_time__,
+                    consumer_chain_id: consumer_chain_id__.unwrap_or_default(),
+                    ibc_seq_num: ibc_seq_num__.unwrap_or_default(),
+                    provider_val_cons_addr: provider_val_cons_addr__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.GlobalSlashEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for HandshakeMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.HandshakeMetadata", len)?;
+        if true {
+            struct_ser.serialize_field("providerFeePoolAddr", &self.provider_fee_pool_addr)?;
+        }
+        if true {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for HandshakeMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "provider_fee_pool_addr",
+            "providerFeePoolAddr",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProviderFeePoolAddr,
+            Version,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "providerFeePoolAddr" | "provider_fee_pool_addr" => Ok(GeneratedField::ProviderFeePoolAddr),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = HandshakeMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.HandshakeMetadata")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HandshakeMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut provider_fee_pool_addr__ = None;
+                let mut version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProviderFeePoolAddr => {
+                            if provider_fee_pool_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerFeePoolAddr"));
+                            }
+                            provider_fee_pool_addr__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(HandshakeMetadata {
+                    provider_fee_pool_addr: provider_fee_pool_addr__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.HandshakeMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InitTimeoutTimestamp {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.InitTimeoutTimestamp", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InitTimeoutTimestamp {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Timestamp,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InitTimeoutTimestamp;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.InitTimeoutTimestamp")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitTimeoutTimestamp, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(InitTimeoutTimestamp {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.InitTimeoutTimestamp", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for KeyAssignmentReplacement {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if self.prev_c_key.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.KeyAssignmentReplacement", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("providerAddr", pbjson::private::base64::encode(&self.provider_addr).as_str())?;
+        }
+        if let Some(v) = self.prev_c_key.as_ref() {
+            struct_ser.serialize_field("prevCKey", v)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("power", ToString::to_string(&self.power).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for KeyAssignmentReplacement {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "provider_addr",
+            "providerAddr",
+            "prev_c_key",
+            "prevCKey",
+            "power",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProviderAddr,
+            PrevCKey,
+            Power,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "providerAddr" | "provider_addr" => Ok(GeneratedField::ProviderAddr),
+                            "prevCKey" | "prev_c_key" => Ok(GeneratedField::PrevCKey),
+                            "power" => Ok(GeneratedField::Power),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = KeyAssignmentReplacement;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.KeyAssignmentReplacement")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<KeyAssignmentReplacement, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut provider_addr__ = None;
+                let mut prev_c_key__ = None;
+                let mut power__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProviderAddr => {
+                            if provider_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddr"));
+                            }
+                            provider_addr__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrevCKey => {
+                            if prev_c_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevCKey"));
+                            }
+                            prev_c_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Power => {
+                            if power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("power"));
+                            }
+                            power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(KeyAssignmentReplacement {
+                    provider_addr: provider_addr__.unwrap_or_default(),
+                    prev_c_key: prev_c_key__,
+                    power: power__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.KeyAssignmentReplacement", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MsgAssignConsumerKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.consumer_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.MsgAssignConsumerKey", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("providerAddr", &self.provider_addr)?;
+        }
+        if let Some(v) = self.consumer_key.as_ref() {
+            struct_ser.serialize_field("consumerKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MsgAssignConsumerKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "provider_addr",
+            "providerAddr",
+            "consumer_key",
+            "consumerKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProviderAddr,
+            ConsumerKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "providerAddr" | "provider_addr" => Ok(GeneratedField::ProviderAddr),
+                            "consumerKey" | "consumer_key" => Ok(GeneratedField::ConsumerKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MsgAssignConsumerKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.MsgAssignConsumerKey")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MsgAssignConsumerKey, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut provider_addr__ = None;
+                let mut consumer_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderAddr => {
+                            if provider_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddr"));
+                            }
+                            provider_addr__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerKey => {
+                            if consumer_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerKey"));
+                            }
+                            consumer_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MsgAssignConsumerKey {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    provider_addr: provider_addr__.unwrap_or_default(),
+                    consumer_key: consumer_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.MsgAssignConsumerKey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MsgAssignConsumerKeyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.MsgAssignConsumerKeyResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MsgAssignConsumerKeyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MsgAssignConsumerKeyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.MsgAssignConsumerKeyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MsgAssignConsumerKeyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(MsgAssignConsumerKeyResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.MsgAssignConsumerKeyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MsgSubmitConsumerMisbehaviour {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if self.misbehaviour.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviour", len)?;
+        if true {
+            struct_ser.serialize_field("submitter", &self.submitter)?;
+        }
+        if let Some(v) = self.misbehaviour.as_ref() {
+            struct_ser.serialize_field("misbehaviour", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MsgSubmitConsumerMisbehaviour {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "submitter",
+            "misbehaviour",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Submitter,
+            Misbehaviour,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "submitter" => Ok(GeneratedField::Submitter),
+                            "misbehaviour" => Ok(GeneratedField::Misbehaviour),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MsgSubmitConsumerMisbehaviour;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviour")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MsgSubmitConsumerMisbehaviour, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut submitter__ = None;
+                let mut misbehaviour__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Submitter => {
+                            if submitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitter"));
+                            }
+                            submitter__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Misbehaviour => {
+                            if misbehaviour__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("misbehaviour"));
+                            }
+                            misbehaviour__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MsgSubmitConsumerMisbehaviour {
+                    submitter: submitter__.unwrap_or_default(),
+                    misbehaviour: misbehaviour__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviour", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MsgSubmitConsumerMisbehaviourResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviourResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MsgSubmitConsumerMisbehaviourResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MsgSubmitConsumerMisbehaviourResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviourResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MsgSubmitConsumerMisbehaviourResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(MsgSubmitConsumerMisbehaviourResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.MsgSubmitConsumerMisbehaviourResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Params {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.template_client.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.ccv_timeout_period.is_some() {
+            len += 1;
+        }
+        if self.init_timeout_period.is_some() {
+            len += 1;
+        }
+        if self.vsc_timeout_period.is_some() {
+            len += 1;
+        }
+        if self.slash_meter_replenish_period.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.Params", len)?;
+        if let Some(v) = self.template_client.as_ref() {
+            struct_ser.serialize_field("templateClient", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("trustingPeriodFraction", &self.trusting_period_fraction)?;
+        }
+        if let Some(v) = self.ccv_timeout_period.as_ref() {
+            struct_ser.serialize_field("ccvTimeoutPeriod", v)?;
+        }
+        if let Some(v) = self.init_timeout_period.as_ref() {
+            struct_ser.serialize_field("initTimeoutPeriod", v)?;
+        }
+        if let Some(v) = self.vsc_timeout_period.as_ref() {
+            struct_ser.serialize_field("vscTimeoutPeriod", v)?;
+        }
+        if let Some(v) = self.slash_meter_replenish_period.as_ref() {
+            struct_ser.serialize_field("slashMeterReplenishPeriod", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("slashMeterReplenishFraction", &self.slash_meter_replenish_fraction)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxThrottledPackets", ToString::to_string(&self.max_throttled_packets).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Params {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "template_client",
+            "templateClient",
+            "trusting_period_fraction",
+            "trustingPeriodFraction",
+            "ccv_timeout_period",
+            "ccvTimeoutPeriod",
+            "init_timeout_period",
+            "initTimeoutPeriod",
+            "vsc_timeout_period",
+            "vscTimeoutPeriod",
+            "slash_meter_replenish_period",
+            "slashMeterReplenishPeriod",
+            "slash_meter_replenish_fraction",
+            "slashMeterReplenishFraction",
+            "max_throttled_packets",
+            "maxThrottledPackets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TemplateClient,
+            TrustingPeriodFraction,
+            CcvTimeoutPeriod,
+            InitTimeoutPeriod,
+            VscTimeoutPeriod,
+            SlashMeterReplenishPeriod,
+            SlashMeterReplenishFraction,
+            MaxThrottledPackets,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "templateClient" | "template_client" => Ok(GeneratedField::TemplateClient),
+                            "trustingPeriodFraction" | "trusting_period_fraction" => Ok(GeneratedField::TrustingPeriodFraction),
+                            "ccvTimeoutPeriod" | "ccv_timeout_period" => Ok(GeneratedField::CcvTimeoutPeriod),
+                            "initTimeoutPeriod" | "init_timeout_period" => Ok(GeneratedField::InitTimeoutPeriod),
+                            "vscTimeoutPeriod" | "vsc_timeout_period" => Ok(GeneratedField::VscTimeoutPeriod),
+                            "slashMeterReplenishPeriod" | "slash_meter_replenish_period" => Ok(GeneratedField::SlashMeterReplenishPeriod),
+                            "slashMeterReplenishFraction" | "slash_meter_replenish_fraction" => Ok(GeneratedField::SlashMeterReplenishFraction),
+                            "maxThrottledPackets" | "max_throttled_packets" => Ok(GeneratedField::MaxThrottledPackets),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Params;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.Params")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Params, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut template_client__ = None;
+                let mut trusting_period_fraction__ = None;
+                let mut ccv_timeout_period__ = None;
+                let mut init_timeout_period__ = None;
+                let mut vsc_timeout_period__ = None;
+                let mut slash_meter_replenish_period__ = None;
+                let mut slash_meter_replenish_fraction__ = None;
+                let mut max_throttled_packets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TemplateClient => {
+                            if template_client__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("templateClient"));
+                            }
+                            template_client__ = map_.next_value()?;
+                        }
+                        GeneratedField::TrustingPeriodFraction => {
+                            if trusting_period_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trustingPeriodFraction"));
+                            }
+                            trusting_period_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CcvTimeoutPeriod => {
+                            if ccv_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ccvTimeoutPeriod"));
+                            }
+                            ccv_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitTimeoutPeriod => {
+                            if init_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initTimeoutPeriod"));
+                            }
+                            init_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::VscTimeoutPeriod => {
+                            if vsc_timeout_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscTimeoutPeriod"));
+                            }
+                            vsc_timeout_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::SlashMeterReplenishPeriod => {
+                            if slash_meter_replenish_period__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashMeterReplenishPeriod"));
+                            }
+                            slash_meter_replenish_period__ = map_.next_value()?;
+                        }
+                        GeneratedField::SlashMeterReplenishFraction => {
+                            if slash_meter_replenish_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashMeterReplenishFraction"));
+                            }
+                            slash_meter_replenish_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxThrottledPackets => {
+                            if max_throttled_packets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxThrottledPackets"));
+                            }
+                            max_throttled_packets__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Params {
+                    template_client: template_client__,
+                    trusting_period_fraction: trusting_period_fraction__.unwrap_or_default(),
+                    ccv_timeout_period: ccv_timeout_period__,
+                    init_timeout_period: init_timeout_period__,
+                    vsc_timeout_period: vsc_timeout_period__,
+                    slash_meter_replenish_period: slash_meter_replenish_period__,
+                    slash_meter_replenish_fraction: slash_meter_replenish_fraction__.unwrap_or_default(),
+                    max_throttled_packets: max_throttled_packets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.Params", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainStartProposalsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainStartProposalsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainStartProposalsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainStartProposalsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryConsumerChainStartProposalsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainStartProposalsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposals.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsResponse", len)?;
+        if let Some(v) = self.proposals.as_ref() {
+            struct_ser.serialize_field("proposals", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainStartProposalsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposals,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposals" => Ok(GeneratedField::Proposals),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainStartProposalsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainStartProposalsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposals => {
+                            if proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposals"));
+                            }
+                            proposals__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryConsumerChainStartProposalsResponse {
+                    proposals: proposals__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStartProposalsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainStopProposalsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainStopProposalsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainStopProposalsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainStopProposalsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryConsumerChainStopProposalsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainStopProposalsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposals.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsResponse", len)?;
+        if let Some(v) = self.proposals.as_ref() {
+            struct_ser.serialize_field("proposals", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainStopProposalsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposals,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposals" => Ok(GeneratedField::Proposals),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainStopProposalsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainStopProposalsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposals => {
+                            if proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposals"));
+                            }
+                            proposals__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryConsumerChainStopProposalsResponse {
+                    proposals: proposals__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainStopProposalsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryConsumerChainsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerChainsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainsResponse", len)?;
+        if true {
+            struct_ser.serialize_field("chains", &self.chains)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerChainsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chains",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Chains,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chains" => Ok(GeneratedField::Chains),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerChainsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerChainsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerChainsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chains__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Chains => {
+                            if chains__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chains"));
+                            }
+                            chains__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryConsumerChainsResponse {
+                    chains: chains__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerChainsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerGenesisRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerGenesisRequest", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerGenesisRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerGenesisRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerGenesisRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerGenesisRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryConsumerGenesisRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerGenesisRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryConsumerGenesisResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.genesis_state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryConsumerGenesisResponse", len)?;
+        if let Some(v) = self.genesis_state.as_ref() {
+            struct_ser.serialize_field("genesisState", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryConsumerGenesisResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "genesis_state",
+            "genesisState",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GenesisState,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "genesisState" | "genesis_state" => Ok(GeneratedField::GenesisState),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryConsumerGenesisResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryConsumerGenesisResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryConsumerGenesisResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut genesis_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GenesisState => {
+                            if genesis_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesisState"));
+                            }
+                            genesis_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryConsumerGenesisResponse {
+                    genesis_state: genesis_state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryConsumerGenesisResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryThrottleStateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryThrottleStateRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryThrottleStateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryThrottleStateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryThrottleStateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryThrottleStateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(QueryThrottleStateRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryThrottleStateRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryThrottleStateResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.next_replenish_candidate.is_some() {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryThrottleStateResponse", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("slashMeter", ToString::to_string(&self.slash_meter).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("slashMeterAllowance", ToString::to_string(&self.slash_meter_allowance).as_str())?;
+        }
+        if let Some(v) = self.next_replenish_candidate.as_ref() {
+            struct_ser.serialize_field("nextReplenishCandidate", v)?;
+        }
+        if true {
+            struct_ser.serialize_field("packets", &self.packets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryThrottleStateResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "slash_meter",
+            "slashMeter",
+            "slash_meter_allowance",
+            "slashMeterAllowance",
+            "next_replenish_candidate",
+            "nextReplenishCandidate",
+            "packets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SlashMeter,
+            SlashMeterAllowance,
+            NextReplenishCandidate,
+            Packets,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "slashMeter" | "slash_meter" => Ok(GeneratedField::SlashMeter),
+                            "slashMeterAllowance" | "slash_meter_allowance" => Ok(GeneratedField::SlashMeterAllowance),
+                            "nextReplenishCandidate" | "next_replenish_candidate" => Ok(GeneratedField::NextReplenishCandidate),
+                            "packets" => Ok(GeneratedField::Packets),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryThrottleStateResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryThrottleStateResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryThrottleStateResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut slash_meter__ = None;
+                let mut slash_meter_allowance__ = None;
+                let mut next_replenish_candidate__ = None;
+                let mut packets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SlashMeter => {
+                            if slash_meter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashMeter"));
+                            }
+                            slash_meter__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SlashMeterAllowance => {
+                            if slash_meter_allowance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashMeterAllowance"));
+                            }
+                            slash_meter_allowance__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextReplenishCandidate => {
+                            if next_replenish_candidate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextReplenishCandidate"));
+                            }
+                            next_replenish_candidate__ = map_.next_value()?;
+                        }
+                        GeneratedField::Packets => {
+                            if packets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packets"));
+                            }
+                            packets__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryThrottleStateResponse {
+                    slash_meter: slash_meter__.unwrap_or_default(),
+                    slash_meter_allowance: slash_meter_allowance__.unwrap_or_default(),
+                    next_replenish_candidate: next_replenish_candidate__,
+                    packets: packets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryThrottleStateResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryThrottledConsumerPacketDataRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataRequest", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryThrottledConsumerPacketDataRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryThrottledConsumerPacketDataRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryThrottledConsumerPacketDataRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryThrottledConsumerPacketDataRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryThrottledConsumerPacketDataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataResponse", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("size", ToString::to_string(&self.size).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("packetDataInstances", &self.packet_data_instances)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryThrottledConsumerPacketDataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "size",
+            "packet_data_instances",
+            "packetDataInstances",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Size,
+            PacketDataInstances,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "size" => Ok(GeneratedField::Size),
+                            "packetDataInstances" | "packet_data_instances" => Ok(GeneratedField::PacketDataInstances),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryThrottledConsumerPacketDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryThrottledConsumerPacketDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut size__ = None;
+                let mut packet_data_instances__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PacketDataInstances => {
+                            if packet_data_instances__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("packetDataInstances"));
+                            }
+                            packet_data_instances__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryThrottledConsumerPacketDataResponse {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    size: size__.unwrap_or_default(),
+                    packet_data_instances: packet_data_instances__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryThrottledConsumerPacketDataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryValidatorConsumerAddrRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrRequest", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("providerAddress", &self.provider_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryValidatorConsumerAddrRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "provider_address",
+            "providerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProviderAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "providerAddress" | "provider_address" => Ok(GeneratedField::ProviderAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryValidatorConsumerAddrRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryValidatorConsumerAddrRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut provider_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderAddress => {
+                            if provider_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddress"));
+                            }
+                            provider_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryValidatorConsumerAddrRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    provider_address: provider_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryValidatorConsumerAddrResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrResponse", len)?;
+        if true {
+            struct_ser.serialize_field("consumerAddress", &self.consumer_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryValidatorConsumerAddrResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "consumer_address",
+            "consumerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConsumerAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "consumerAddress" | "consumer_address" => Ok(GeneratedField::ConsumerAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryValidatorConsumerAddrResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryValidatorConsumerAddrResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut consumer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConsumerAddress => {
+                            if consumer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAddress"));
+                            }
+                            consumer_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryValidatorConsumerAddrResponse {
+                    consumer_address: consumer_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryValidatorConsumerAddrResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryValidatorProviderAddrRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryValidatorProviderAddrRequest", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            struct_ser.serialize_field("consumerAddress", &self.consumer_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryValidatorProviderAddrRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "consumer_address",
+            "consumerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ConsumerAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "consumerAddress" | "consumer_address" => Ok(GeneratedField::ConsumerAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryValidatorProviderAddrRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryValidatorProviderAddrRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryValidatorProviderAddrRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut consumer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerAddress => {
+                            if consumer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAddress"));
+                            }
+                            consumer_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryValidatorProviderAddrRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    consumer_address: consumer_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryValidatorProviderAddrRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueryValidatorProviderAddrResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.QueryValidatorProviderAddrResponse", len)?;
+        if true {
+            struct_ser.serialize_field("providerAddress", &self.provider_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryValidatorProviderAddrResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "provider_address",
+            "providerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProviderAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "providerAddress" | "provider_address" => Ok(GeneratedField::ProviderAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryValidatorProviderAddrResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.QueryValidatorProviderAddrResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryValidatorProviderAddrResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut provider_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProviderAddress => {
+                            if provider_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddress"));
+                            }
+                            provider_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryValidatorProviderAddrResponse {
+                    provider_address: provider_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.QueryValidatorProviderAddrResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SlashAcks {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.SlashAcks", len)?;
+        if true {
+            struct_ser.serialize_field("addresses", &self.addresses)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SlashAcks {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addresses",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addresses,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addresses" => Ok(GeneratedField::Addresses),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SlashAcks;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.SlashAcks")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SlashAcks, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut addresses__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addresses => {
+                            if addresses__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addresses"));
+                            }
+                            addresses__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SlashAcks {
+                    addresses: addresses__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.SlashAcks", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ThrottledPacketDataWrapper {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ThrottledPacketDataWrapper", len)?;
+        if let Some(v) = self.data.as_ref() {
+            match v {
+                throttled_packet_data_wrapper::Data::SlashPacket(v) => {
+                    struct_ser.serialize_field("slashPacket", v)?;
+                }
+                throttled_packet_data_wrapper::Data::VscMaturedPacket(v) => {
+                    struct_ser.serialize_field("vscMaturedPacket", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ThrottledPacketDataWrapper {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "slash_packet",
+            "slashPacket",
+            "vsc_matured_packet",
+            "vscMaturedPacket",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SlashPacket,
+            VscMaturedPacket,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "slashPacket" | "slash_packet" => Ok(GeneratedField::SlashPacket),
+                            "vscMaturedPacket" | "vsc_matured_packet" => Ok(GeneratedField::VscMaturedPacket),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ThrottledPacketDataWrapper;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ThrottledPacketDataWrapper")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ThrottledPacketDataWrapper, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SlashPacket => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashPacket"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(throttled_packet_data_wrapper::Data::SlashPacket);
+                        }
+                        GeneratedField::VscMaturedPacket => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscMaturedPacket"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(throttled_packet_data_wrapper::Data::VscMaturedPacket);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ThrottledPacketDataWrapper {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ThrottledPacketDataWrapper", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ThrottledSlashPacket {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.global_entry.is_some() {
+            len += 1;
+        }
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ThrottledSlashPacket", len)?;
+        if let Some(v) = self.global_entry.as_ref() {
+            struct_ser.serialize_field("globalEntry", v)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ThrottledSlashPacket {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "global_entry",
+            "globalEntry",
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GlobalEntry,
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "globalEntry" | "global_entry" => Ok(GeneratedField::GlobalEntry),
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ThrottledSlashPacket;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ThrottledSlashPacket")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ThrottledSlashPacket, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut global_entry__ = None;
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GlobalEntry => {
+                            if global_entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("globalEntry"));
+                            }
+                            global_entry__ = map_.next_value()?;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ThrottledSlashPacket {
+                    global_entry: global_entry__,
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ThrottledSlashPacket", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnbondingOp {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.UnbondingOp", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("unbondingConsumerChains", &self.unbonding_consumer_chains)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondingOp {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "unbonding_consumer_chains",
+            "unbondingConsumerChains",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            UnbondingConsumerChains,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "unbondingConsumerChains" | "unbonding_consumer_chains" => Ok(GeneratedField::UnbondingConsumerChains),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondingOp;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.UnbondingOp")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondingOp, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut unbonding_consumer_chains__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondingConsumerChains => {
+                            if unbonding_consumer_chains__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingConsumerChains"));
+                            }
+                            unbonding_consumer_chains__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UnbondingOp {
+                    id: id__.unwrap_or_default(),
+                    unbonding_consumer_chains: unbonding_consumer_chains__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.UnbondingOp", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorByConsumerAddr {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ValidatorByConsumerAddr", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("consumerAddr", pbjson::private::base64::encode(&self.consumer_addr).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("providerAddr", pbjson::private::base64::encode(&self.provider_addr).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorByConsumerAddr {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "consumer_addr",
+            "consumerAddr",
+            "provider_addr",
+            "providerAddr",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ConsumerAddr,
+            ProviderAddr,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "consumerAddr" | "consumer_addr" => Ok(GeneratedField::ConsumerAddr),
+                            "providerAddr" | "provider_addr" => Ok(GeneratedField::ProviderAddr),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorByConsumerAddr;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ValidatorByConsumerAddr")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorByConsumerAddr, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut consumer_addr__ = None;
+                let mut provider_addr__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConsumerAddr => {
+                            if consumer_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerAddr"));
+                            }
+                            consumer_addr__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProviderAddr => {
+                            if provider_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddr"));
+                            }
+                            provider_addr__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorByConsumerAddr {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    consumer_addr: consumer_addr__.unwrap_or_default(),
+                    provider_addr: provider_addr__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ValidatorByConsumerAddr", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorConsumerPubKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        if self.consumer_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ValidatorConsumerPubKey", len)?;
+        if true {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("providerAddr", pbjson::private::base64::encode(&self.provider_addr).as_str())?;
+        }
+        if let Some(v) = self.consumer_key.as_ref() {
+            struct_ser.serialize_field("consumerKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorConsumerPubKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "provider_addr",
+            "providerAddr",
+            "consumer_key",
+            "consumerKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ProviderAddr,
+            ConsumerKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "providerAddr" | "provider_addr" => Ok(GeneratedField::ProviderAddr),
+                            "consumerKey" | "consumer_key" => Ok(GeneratedField::ConsumerKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorConsumerPubKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ValidatorConsumerPubKey")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorConsumerPubKey, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut provider_addr__ = None;
+                let mut consumer_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProviderAddr => {
+                            if provider_addr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerAddr"));
+                            }
+                            provider_addr__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ConsumerKey => {
+                            if consumer_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("consumerKey"));
+                            }
+                            consumer_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorConsumerPubKey {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    provider_addr: provider_addr__.unwrap_or_default(),
+                    consumer_key: consumer_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ValidatorConsumerPubKey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValsetUpdateIdToHeight {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.ValsetUpdateIdToHeight", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("valsetUpdateId", ToString::to_string(&self.valset_update_id).as_str())?;
+        }
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValsetUpdateIdToHeight {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "valset_update_id",
+            "valsetUpdateId",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValsetUpdateId,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "valsetUpdateId" | "valset_update_id" => Ok(GeneratedField::ValsetUpdateId),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValsetUpdateIdToHeight;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.ValsetUpdateIdToHeight")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValsetUpdateIdToHeight, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut valset_update_id__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValsetUpdateId => {
+                            if valset_update_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valsetUpdateId"));
+                            }
+                            valset_update_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValsetUpdateIdToHeight {
+                    valset_update_id: valset_update_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.ValsetUpdateIdToHeight", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VscSendTimestamp {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if self.timestamp.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.VscSendTimestamp", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vscId", ToString::to_string(&self.vsc_id).as_str())?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            struct_ser.serialize_field("timestamp", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VscSendTimestamp {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vsc_id",
+            "vscId",
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VscId,
+            Timestamp,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vscId" | "vsc_id" => Ok(GeneratedField::VscId),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VscSendTimestamp;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.VscSendTimestamp")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VscSendTimestamp, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vsc_id__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VscId => {
+                            if vsc_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscId"));
+                            }
+                            vsc_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VscSendTimestamp {
+                    vsc_id: vsc_id__.unwrap_or_default(),
+                    timestamp: timestamp__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.VscSendTimestamp", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VscUnbondingOps {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if true {
+            len += 1;
+        }
+        if true {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("interchain_security.ccv.provider.v1.VscUnbondingOps", len)?;
+        if true {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vscId", ToString::to_string(&self.vsc_id).as_str())?;
+        }
+        if true {
+            struct_ser.serialize_field("unbondingOpIds", &self.unbonding_op_ids.iter().map(ToString::to_string).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VscUnbondingOps {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vsc_id",
+            "vscId",
+            "unbonding_op_ids",
+            "unbondingOpIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VscId,
+            UnbondingOpIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vscId" | "vsc_id" => Ok(GeneratedField::VscId),
+                            "unbondingOpIds" | "unbonding_op_ids" => Ok(GeneratedField::UnbondingOpIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VscUnbondingOps;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct interchain_security.ccv.provider.v1.VscUnbondingOps")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VscUnbondingOps, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vsc_id__ = None;
+                let mut unbonding_op_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VscId => {
+                            if vsc_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vscId"));
+                            }
+                            vsc_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondingOpIds => {
+                            if unbonding_op_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingOpIds"));
+                            }
+                            unbonding_op_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VscUnbondingOps {
+                    vsc_id: vsc_id__.unwrap_or_default(),
+                    unbonding_op_ids: unbonding_op_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("interchain_security.ccv.provider.v1.VscUnbondingOps", FIELDS, GeneratedVisitor)
+    }
+}
@@ -9,6 +9,7 @@ pub mod query_client {
     pub struct QueryClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl QueryClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -19,6 +20,27 @@ pub mod query_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl QueryClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> QueryClient<T>
     where
@@ -227,6 +249,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -237,6 +260,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -462,6 +506,7 @@ pub struct ValidatorSetChangePackets {
 }
 /// This packet is sent from the consumer chain to the provider chain
 /// to notify that a VSC packet reached maturity on the consumer chain.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VscMaturedPacketData {
@@ -488,6 +533,7 @@ pub struct SlashPacketData {
     pub infraction: i32,
 }
 /// MaturedUnbondingOps defines a list of ids corresponding to ids of matured unbonding operations.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MaturedUnbondingOps {
@@ -560,3 +606,44 @@ impl ConsumerPacketDataType {
         }
     }
 }
+impl crate::protobuf::Name for ValidatorSetChangePacketData {
+    const NAME: &'static str = "ValidatorSetChangePacketData";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for ValidatorSetChangePackets {
+    const NAME: &'static str = "ValidatorSetChangePackets";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for VscMaturedPacketData {
+    const NAME: &'static str = "VSCMaturedPacketData";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for SlashPacketData {
+    const NAME: &'static str = "SlashPacketData";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for MaturedUnbondingOps {
+    const NAME: &'static str = "MaturedUnbondingOps";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for ConsumerPacketData {
+    const NAME: &'static str = "ConsumerPacketData";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+impl crate::protobuf::Name for ConsumerPacketDataList {
+    const NAME: &'static str = "ConsumerPacketDataList";
+    const PACKAGE: &'static str = "interchain_security.ccv.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<ValidatorSetChangePacketData>();
+    registry.register::<ValidatorSetChangePackets>();
+    registry.register::<VscMaturedPacketData>();
+    registry.register::<SlashPacketData>();
+    registry.register::<MaturedUnbondingOps>();
+    registry.register::<ConsumerPacketData>();
+    registry.register::<ConsumerPacketDataList>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("interchain_security.ccv.v1.bin");
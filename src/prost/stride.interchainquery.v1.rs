@@ -17,6 +17,7 @@ pub struct MsgSubmitQueryResponse {
 }
 /// MsgSubmitQueryResponseResponse defines the MsgSubmitQueryResponse response
 /// type.
+#[derive(Eq, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSubmitQueryResponseResponse {}
@@ -31,6 +32,7 @@ pub mod msg_client {
     pub struct MsgClient<T> {
         inner: tonic::client::Grpc<T>,
     }
+    #[cfg(feature = "transport")]
     impl MsgClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
@@ -41,6 +43,27 @@ pub mod msg_client {
             let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
             Ok(Self::new(conn))
         }
+        /// Attempt to create a new client by connecting to a given endpoint over TLS,
+        /// eg. `https://grpc.cosmos.network:443`, trusting the Mozilla root certificates.
+        #[cfg(feature = "client-tls")]
+        pub async fn connect_tls<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?
+                .tls_config(tonic::transport::ClientTlsConfig::new())?
+                .connect()
+                .await?;
+            Ok(Self::new(conn))
+        }
+    }
+    #[cfg(all(feature = "grpc-web", target_arch = "wasm32"))]
+    impl MsgClient<tonic_web_wasm_client::Client> {
+        /// Create a new client of the given gRPC-web endpoint, eg. from a browser.
+        pub fn new_grpc_web(base_url: impl Into<String>) -> Self {
+            Self::new(tonic_web_wasm_client::Client::new(base_url.into()))
+        }
     }
     impl<T> MsgClient<T>
     where
@@ -324,3 +347,19 @@ pub mod msg_server {
         const NAME: &'static str = "stride.interchainquery.v1.Msg";
     }
 }
+impl crate::protobuf::Name for MsgSubmitQueryResponse {
+    const NAME: &'static str = "MsgSubmitQueryResponse";
+    const PACKAGE: &'static str = "stride.interchainquery.v1";
+}
+impl crate::protobuf::Name for MsgSubmitQueryResponseResponse {
+    const NAME: &'static str = "MsgSubmitQueryResponseResponse";
+    const PACKAGE: &'static str = "stride.interchainquery.v1";
+}
+/// Registers all the messages of this package in the given registry.
+pub(crate) fn register_messages(registry: &mut crate::registry::Registry) {
+    registry.register::<MsgSubmitQueryResponse>();
+    registry.register::<MsgSubmitQueryResponseResponse>();
+}
+/// The descriptors of the files of this package and of their dependencies, eg. to
+/// register the package with the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("stride.interchainquery.v1.bin");
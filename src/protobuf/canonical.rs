@@ -0,0 +1,42 @@
+use alloc::vec::Vec;
+
+use prost::encoding::{decode_key, skip_field, DecodeContext};
+use prost::Message;
+
+/// Encodes a Protobuf message deterministically, as required to produce sign bytes,
+/// following [ADR-027]: its fields are written in ascending field number order and
+/// the fields holding default values are left out.
+///
+/// `prost` writes the fields in order of declaration, with the oneofs last, eg. the
+/// `validators` of `cosmos.staking.v1beta1.StakeAuthorization` come after its
+/// `authorization_type`. The fields of the message are thus sorted once encoded,
+/// keeping the elements of repeated fields in order. Nested messages are written as
+/// encoded by `prost`, which is in field number order for all the messages of the
+/// transactions.
+///
+/// [ADR-027]: https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-027-deterministic-protobuf-serialization.md
+pub fn encode_canonical<M: Message>(message: &M) -> Vec<u8> {
+    let bytes = message.encode_to_vec();
+
+    let mut fields: Vec<(u32, &[u8])> = Vec::new();
+    let mut buf = bytes.as_slice();
+    while !buf.is_empty() {
+        let start = bytes.len() - buf.len();
+        let (tag, wire_type) = decode_key(&mut buf).expect("prost should encode valid keys");
+        skip_field(wire_type, tag, &mut buf, DecodeContext::default())
+            .expect("prost should encode valid fields");
+        fields.push((tag, &bytes[start..bytes.len() - buf.len()]));
+    }
+
+    if fields.windows(2).all(|pair| pair[0].0 <= pair[1].0) {
+        return bytes;
+    }
+
+    // The sort is stable, so that the elements of repeated fields keep their order.
+    fields.sort_by_key(|(tag, _)| *tag);
+    fields
+        .into_iter()
+        .flat_map(|(_, field)| field)
+        .copied()
+        .collect()
+}
@@ -1,38 +1,259 @@
-use alloc::format;
-use alloc::string::String;
-use core::fmt::Display;
-use core::format_args;
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::any::type_name;
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
-use flex_error::{define_error, DisplayOnly};
 use prost::{DecodeError, EncodeError};
 
 use super::erased::TryFrom;
 
-define_error! {
-    Error {
-        TryFromProtobuf
-            { reason: String }
-            | e | {
-                format_args!("error converting message type into domain type: {}",
-                    e.reason)
-            },
-
-        EncodeMessage
-            [ DisplayOnly<EncodeError> ]
-            | _ | { "error encoding message into buffer" },
-
-        DecodeMessage
-            [ DisplayOnly<DecodeError> ]
-            | _ | { "error decoding buffer into message" },
-    }
+/// The errors of the encoding and decoding of the domain types, see
+/// [`Protobuf`](super::Protobuf).
+///
+/// With the `std` feature, the errors implement `std::error::Error`, whose `source`
+/// is the error of `prost`, of the hex or base64 decoding, or the original error of the
+/// conversion into the domain type, eg. for `anyhow` and `eyre` to report the chain of
+/// causes, or to downcast it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The raw message could not be converted into the domain type.
+    TryFromProtobuf {
+        raw_type: String,
+        domain_type: String,
+        reason: ConversionError,
+    },
+    /// The message could not be encoded, as the buffer is too small.
+    EncodeMessage(EncodeError),
+    /// The message could not be decoded, at the given offset in the buffer.
+    DecodeMessage {
+        raw_type: String,
+        type_url: Option<String>,
+        offset: usize,
+        source: DecodeError,
+    },
+    DecodeHex(subtle_encoding::Error),
+    DecodeBase64(base64::DecodeError),
+    /// The message is larger than the maximum length, see
+    /// [`set_max_decode_len`](super::set_max_decode_len).
+    MessageTooLarge {
+        len: usize,
+        max_len: usize,
+    },
+    /// No message is registered for the type URL, see [`registry`](crate::registry).
+    UnknownTypeUrl {
+        type_url: String,
+    },
+    /// The type URL of a `google.protobuf.Any` is not the one of the message to unpack.
+    MismatchedTypeUrl {
+        expected: String,
+        actual: String,
+    },
 }
 
 impl Error {
     pub fn try_from<Raw, T, E>(e: E) -> Error
     where
-        E: Display,
+        E: IntoConversionError,
         T: TryFrom<Raw, Error = E>,
     {
-        Error::try_from_protobuf(format!("{e}"))
+        Error::TryFromProtobuf {
+            raw_type: type_name::<Raw>().to_string(),
+            domain_type: type_name::<T>().to_string(),
+            reason: ConversionError::new(e),
+        }
+    }
+
+    /// Error decoding a message of type `Raw`, with the type URL of the message
+    /// if known, eg. when unpacking a `google.protobuf.Any`, and the offset in
+    /// the buffer at which decoding stopped.
+    pub fn decode<Raw>(e: DecodeError, type_url: Option<String>, offset: usize) -> Error {
+        Error::decode_message(type_name::<Raw>().to_string(), type_url, offset, e)
+    }
+
+    pub fn try_from_protobuf(raw_type: String, domain_type: String, reason: String) -> Error {
+        Error::TryFromProtobuf {
+            raw_type,
+            domain_type,
+            reason: ConversionError::new(reason),
+        }
+    }
+
+    pub fn encode_message(e: EncodeError) -> Error {
+        Error::EncodeMessage(e)
+    }
+
+    pub fn decode_message(
+        raw_type: String,
+        type_url: Option<String>,
+        offset: usize,
+        e: DecodeError,
+    ) -> Error {
+        Error::DecodeMessage {
+            raw_type,
+            type_url,
+            offset,
+            source: e,
+        }
+    }
+
+    pub fn decode_hex(e: subtle_encoding::Error) -> Error {
+        Error::DecodeHex(e)
+    }
+
+    pub fn decode_base64(e: base64::DecodeError) -> Error {
+        Error::DecodeBase64(e)
+    }
+
+    pub fn message_too_large(len: usize, max_len: usize) -> Error {
+        Error::MessageTooLarge { len, max_len }
+    }
+
+    pub fn unknown_type_url(type_url: String) -> Error {
+        Error::UnknownTypeUrl { type_url }
+    }
+
+    pub fn mismatched_type_url(expected: String, actual: String) -> Error {
+        Error::MismatchedTypeUrl { expected, actual }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TryFromProtobuf {
+                raw_type,
+                domain_type,
+                reason,
+            } => write!(
+                f,
+                "error converting message `{raw_type}` into domain type `{domain_type}`: \
+                 {reason}"
+            ),
+            Error::EncodeMessage(e) => write!(f, "error encoding message into buffer: {e}"),
+            Error::DecodeMessage {
+                raw_type,
+                type_url,
+                offset,
+                source,
+            } => write!(
+                f,
+                "error decoding buffer into message `{raw_type}` (type URL `{}`) at byte \
+                 {offset}: {source}",
+                type_url.as_deref().unwrap_or("<unknown>"),
+            ),
+            Error::DecodeHex(e) => write!(f, "error decoding hex string into buffer: {e}"),
+            Error::DecodeBase64(e) => write!(f, "error decoding base64 string into buffer: {e}"),
+            Error::MessageTooLarge { len, max_len } => write!(
+                f,
+                "message of {len} bytes exceeds the maximum length of {max_len} bytes"
+            ),
+            Error::UnknownTypeUrl { type_url } => {
+                write!(f, "no message registered for type URL `{type_url}`")
+            }
+            Error::MismatchedTypeUrl { expected, actual } => write!(
+                f,
+                "mismatched type URL: expected `{expected}`, got `{actual}`"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::TryFromProtobuf { reason, .. } => Some(reason.inner()),
+            Error::EncodeMessage(e) => Some(e),
+            Error::DecodeMessage { source, .. } => Some(source),
+            Error::DecodeHex(e) => Some(e),
+            Error::DecodeBase64(e) => Some(e),
+            Error::MessageTooLarge { .. }
+            | Error::UnknownTypeUrl { .. }
+            | Error::MismatchedTypeUrl { .. } => None,
+        }
+    }
+}
+
+/// The bound on the errors of the conversions of the raw messages into their domain types,
+/// see [`Protobuf`](super::Protobuf).
+///
+/// The errors must implement `Display`, and with the `std` feature, convert into a
+/// `Box<dyn std::error::Error + Send + Sync>`, as do the `Send` and `Sync` errors and the
+/// strings, so that [`Error::source`](std::error::Error::source) returns them.
+#[cfg(feature = "std")]
+pub trait IntoConversionError:
+    Display + Into<Box<dyn std::error::Error + Send + Sync + 'static>>
+{
+}
+
+#[cfg(feature = "std")]
+impl<E> IntoConversionError for E where
+    E: Display + Into<Box<dyn std::error::Error + Send + Sync + 'static>>
+{
+}
+
+/// The bound on the errors of the conversions of the raw messages into their domain types,
+/// which must implement `Display` without the `std` feature.
+#[cfg(not(feature = "std"))]
+pub trait IntoConversionError: Display {}
+
+#[cfg(not(feature = "std"))]
+impl<E: Display> IntoConversionError for E {}
+
+/// The error of the conversion of a raw message into its domain type.
+///
+/// With the `std` feature, the original error is kept, behind an `Arc` so that the
+/// error remains `Clone`, and is the `source` of [`Error`]. Without it, only its message
+/// is kept. The errors are compared by their message.
+#[derive(Clone, Debug)]
+pub struct ConversionError {
+    message: String,
+    #[cfg(feature = "std")]
+    inner: Arc<dyn std::error::Error + Send + Sync + 'static>,
+}
+
+impl ConversionError {
+    fn new<E: IntoConversionError>(e: E) -> Self {
+        Self {
+            message: e.to_string(),
+            #[cfg(feature = "std")]
+            inner: Arc::from(e.into()),
+        }
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the original error of the conversion, eg. to downcast it.
+    #[cfg(feature = "std")]
+    pub fn inner(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        &*self.inner
+    }
+}
+
+impl PartialEq for ConversionError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl Eq for ConversionError {}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner.source()
     }
 }
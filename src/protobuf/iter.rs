@@ -0,0 +1,140 @@
+use core::marker::PhantomData;
+
+use bytes::Buf;
+use prost::Message;
+
+use super::{
+    decode_length_delimited_raw, erased, max_decode_len, Error, IntoConversionError, Protobuf,
+};
+
+/// Iterator over the length-delimited instances of `T` found back to back in a
+/// buffer, see [`Protobuf::decode_length_delimited_iter`].
+pub struct DecodeLengthDelimitedIter<T, Raw, B> {
+    buf: B,
+    offset: usize,
+    failed: bool,
+    _marker: PhantomData<fn() -> (T, Raw)>,
+}
+
+impl<T, Raw, B> DecodeLengthDelimitedIter<T, Raw, B> {
+    pub(super) fn new(buf: B) -> Self {
+        Self {
+            buf,
+            offset: 0,
+            failed: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of bytes of the buffer decoded so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<T, Raw, B> Iterator for DecodeLengthDelimitedIter<T, Raw, B>
+where
+    T: Protobuf<Raw>,
+    Raw: Message + Default,
+    B: Buf,
+    <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || !self.buf.has_remaining() {
+            return None;
+        }
+
+        let remaining = self.buf.remaining();
+        let result =
+            decode_length_delimited_raw::<Raw, _>(&mut self.buf, max_decode_len(), self.offset)
+                .and_then(|raw| T::try_from(raw).map_err(Error::try_from::<Raw, T, _>));
+        self.offset += remaining - self.buf.remaining();
+        self.failed = result.is_err();
+
+        Some(result)
+    }
+}
+
+/// Returns a stream decoding the length-delimited instances of `T` read back to
+/// back from `reader`, the asynchronous counterpart of
+/// [`Protobuf::decode_length_delimited_iter`].
+///
+/// The stream ends once the reader is exhausted, or after the first error. The
+/// errors reading from `reader` are reported as decoding errors, at the offset
+/// at which they occurred.
+#[cfg(feature = "async")]
+pub fn decode_length_delimited_stream<T, Raw, R>(
+    reader: R,
+) -> impl futures_util::Stream<Item = Result<T, Error>>
+where
+    T: Protobuf<Raw>,
+    Raw: Message + Default,
+    R: futures_util::AsyncRead + Unpin,
+    <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+{
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use futures_util::{stream, AsyncReadExt};
+    use prost::DecodeError;
+
+    use super::{check_len, decode_raw};
+
+    fn read_error<Raw>(e: std::io::Error, offset: usize) -> Error {
+        Error::decode::<Raw>(DecodeError::new(e.to_string()), None, offset)
+    }
+
+    stream::try_unfold((reader, 0), move |(mut reader, offset)| async move {
+        // Reads the varint length of the next message, a byte at a time.
+        let mut len = 0u64;
+        let mut prefix_len = 0;
+        loop {
+            let mut byte = [0];
+            let read = reader
+                .read(&mut byte)
+                .await
+                .map_err(|e| read_error::<Raw>(e, offset + prefix_len))?;
+            if read == 0 {
+                if prefix_len == 0 {
+                    return Ok(None);
+                }
+                let e = DecodeError::new("unexpected end of stream");
+                return Err(Error::decode::<Raw>(e, None, offset + prefix_len));
+            }
+            if prefix_len == 10 {
+                let e = DecodeError::new("invalid varint");
+                return Err(Error::decode::<Raw>(e, None, offset));
+            }
+
+            len |= u64::from(byte[0] & 0x7f) << (7 * prefix_len);
+            prefix_len += 1;
+            if byte[0] < 0x80 {
+                break;
+            }
+        }
+
+        let len = usize::try_from(len).unwrap_or(usize::MAX);
+        check_len(len, max_decode_len())?;
+
+        // The buffer grows with the bytes actually read, rather than with the
+        // announced length, which comes from the untrusted input.
+        let offset = offset + prefix_len;
+        let mut buf = Vec::new();
+        (&mut reader)
+            .take(len as u64)
+            .read_to_end(&mut buf)
+            .await
+            .map_err(|e| read_error::<Raw>(e, offset + buf.len()))?;
+        if buf.len() < len {
+            let e = DecodeError::new("unexpected end of stream");
+            return Err(Error::decode::<Raw>(e, None, offset + buf.len()));
+        }
+
+        let raw = decode_raw::<Raw, _>(buf.as_slice(), None, offset)?;
+        let value = T::try_from(raw).map_err(Error::try_from::<Raw, T, _>)?;
+
+        Ok::<_, Error>(Some((value, (reader, offset + len))))
+    })
+}
@@ -0,0 +1,24 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static MAX_DECODE_LEN: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Returns the maximum length, in bytes, of the messages decoded with
+/// [`Protobuf::decode`] and the other decoding methods without an explicit
+/// limit, see [`set_max_decode_len`].
+///
+/// [`Protobuf::decode`]: super::Protobuf::decode
+pub fn max_decode_len() -> usize {
+    MAX_DECODE_LEN.load(Ordering::Relaxed)
+}
+
+/// Sets the maximum length, in bytes, of the messages decoded with
+/// [`Protobuf::decode`] and the other decoding methods without an explicit
+/// limit, for the whole process.
+///
+/// There is no limit by default. Relayers and light clients decoding data of
+/// untrusted counterparties should set one, or use the `*_with_limit` methods.
+///
+/// [`Protobuf::decode`]: super::Protobuf::decode
+pub fn set_max_decode_len(max_len: usize) {
+    MAX_DECODE_LEN.store(max_len, Ordering::Relaxed);
+}
@@ -55,35 +55,152 @@
 //!
 //! impl Protobuf<MyRawType> for MyDomainType {}
 //! ```
+mod canonical;
 pub mod erased;
 mod error;
+mod iter;
+mod limit;
+mod name;
 
 #[allow(unused_imports)]
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::fmt::Display;
 
-use bytes::Buf;
-use prost::Message;
+use ::base64::prelude::*;
+use bytes::{Buf, BufMut};
+use prost::encoding::decode_varint;
+use prost::{DecodeError, Message};
 use subtle_encoding::hex;
 
-pub use self::error::Error;
+use crate::google::protobuf::Any;
+
+pub use self::canonical::encode_canonical;
+pub use self::error::{ConversionError, Error, IntoConversionError};
+#[cfg(feature = "async")]
+pub use self::iter::decode_length_delimited_stream;
+pub use self::iter::DecodeLengthDelimitedIter;
+pub use self::limit::{max_decode_len, set_max_decode_len};
+pub use self::name::Name;
+
+/// Derives [`Protobuf`] for a domain type, see [`ibc_proto_derive::Protobuf`].
+#[cfg(feature = "derive")]
+pub use ibc_proto_derive::Protobuf;
+
+/// Checks that the derive macro rejects invalid inputs, against this valid one:
+///
+/// ```
+/// use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(raw = "RawHeight", from, try_from = "core::convert::Infallible")]
+/// struct Height {
+///     #[protobuf(into)]
+///     revision_number: u64,
+///     #[protobuf(into)]
+///     revision_height: u64,
+/// }
+/// ```
+///
+/// `into` without `try_from`:
+///
+/// ```compile_fail
+/// use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(raw = "RawHeight", from)]
+/// struct Height {
+///     #[protobuf(into)]
+///     revision_number: u64,
+///     revision_height: u64,
+/// }
+///
+/// impl TryFrom<RawHeight> for Height {
+///     type Error = core::convert::Infallible;
+///
+///     fn try_from(raw: RawHeight) -> Result<Self, Self::Error> {
+///         Ok(Self {
+///             revision_number: raw.revision_number,
+///             revision_height: raw.revision_height,
+///         })
+///     }
+/// }
+/// ```
+///
+/// A missing raw type:
+///
+/// ```compile_fail
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(from, try_from = "core::convert::Infallible")]
+/// struct Height {
+///     #[protobuf(into)]
+///     revision_number: u64,
+///     #[protobuf(into)]
+///     revision_height: u64,
+/// }
+/// ```
+///
+/// Conversions on a tuple struct:
+///
+/// ```compile_fail
+/// use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(raw = "RawHeight", from, try_from = "core::convert::Infallible")]
+/// struct Height(u64, u64);
+/// ```
+///
+/// An unsupported attribute:
+///
+/// ```compile_fail
+/// use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+/// use ibc_proto::protobuf::Protobuf;
+///
+/// #[derive(Clone, Protobuf)]
+/// #[protobuf(raw = "RawHeight", from, try_from = "core::convert::Infallible", into)]
+/// struct Height {
+///     #[protobuf(into)]
+///     revision_number: u64,
+///     #[protobuf(into)]
+///     revision_height: u64,
+/// }
+/// ```
+#[cfg(all(doctest, feature = "derive"))]
+pub struct DeriveProtobufCompileFail;
 
 /// Object safe equivalent of `tendermint_proto::Protobuf`.
 pub trait Protobuf<Raw: Message + Default>
 where
     Self: erased::TryFrom<Raw> + erased::CloneInto<Raw>,
-    <Self as erased::TryFrom<Raw>>::Error: Display,
+    <Self as erased::TryFrom<Raw>>::Error: IntoConversionError,
 {
+    /// Converts into the counterpart Protobuf data structure, which all the
+    /// encoding methods go through exactly once.
+    ///
+    /// Defaults to cloning the domain type before converting it with `Into`.
+    /// Types holding large fields, eg. proofs or signatures, should override it
+    /// to build the raw type from references instead, eg. with a
+    /// `From<&Self> for Raw` implementation.
+    fn to_raw(&self) -> Raw {
+        self.clone_into()
+    }
+
     /// Encode into a buffer in Protobuf format.
     ///
     /// Uses [`prost::Message::encode`] after converting into its counterpart
-    /// Protobuf data structure.
+    /// Protobuf data structure with [`Protobuf::to_raw`].
     ///
     /// [`prost::Message::encode`]: https://docs.rs/prost/*/prost/trait.Message.html#method.encode
     fn encode(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
-        self.clone_into().encode(buf).map_err(Error::encode_message)
+        let raw = self.to_raw();
+        // Grows the buffer once, rather than as the fields get written.
+        buf.reserve(raw.encoded_len());
+        raw.encode(buf).map_err(Error::encode_message)
     }
 
     /// Encode with a length-delimiter to a buffer in Protobuf format.
@@ -95,8 +212,29 @@ where
     ///
     /// [`prost::Message::encode_length_delimited`]: https://docs.rs/prost/*/prost/trait.Message.html#method.encode_length_delimited
     fn encode_length_delimited(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
-        self.clone_into()
-            .encode_length_delimited(buf)
+        let raw = self.to_raw();
+        let len = raw.encoded_len();
+        buf.reserve(prost::length_delimiter_len(len) + len);
+        raw.encode_length_delimited(buf)
+            .map_err(Error::encode_message)
+    }
+
+    /// Same as [`Protobuf::encode`], but into any buffer, given as a trait object
+    /// so that the method can be called on a `dyn Protobuf<Raw>`, eg. to encode
+    /// a list of boxed domain types one after the other.
+    ///
+    /// An error will be returned if the buffer does not have sufficient capacity.
+    fn encode_erased(&self, mut buf: &mut dyn BufMut) -> Result<(), Error> {
+        self.to_raw()
+            .encode(&mut buf)
+            .map_err(Error::encode_message)
+    }
+
+    /// Same as [`Protobuf::encode_length_delimited`], but into any buffer, given
+    /// as a trait object, see [`Protobuf::encode_erased`].
+    fn encode_length_delimited_erased(&self, mut buf: &mut dyn BufMut) -> Result<(), Error> {
+        self.to_raw()
+            .encode_length_delimited(&mut buf)
             .map_err(Error::encode_message)
     }
 
@@ -107,12 +245,25 @@ where
     /// Similar to [`prost::Message::decode`] but with additional validation
     /// prior to constructing the destination type.
     ///
+    /// Fails if the buffer is longer than [`max_decode_len`].
+    ///
     /// [`prost::Message::decode`]: https://docs.rs/prost/*/prost/trait.Message.html#method.decode
     fn decode<B: Buf>(buf: B) -> Result<Self, Error>
     where
         Self: Sized,
     {
-        let raw = Raw::decode(buf).map_err(Error::decode_message)?;
+        Self::decode_with_limit(buf, max_decode_len())
+    }
+
+    /// Same as [`Protobuf::decode`], but fails if the buffer is longer than
+    /// `max_len` bytes, before decoding anything.
+    fn decode_with_limit<B: Buf>(buf: B, max_len: usize) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        check_len(buf.remaining(), max_len)?;
+
+        let raw = decode_raw::<Raw, _>(buf, None, 0)?;
 
         Self::try_from(raw).map_err(Error::try_from::<Raw, Self, _>)
     }
@@ -125,16 +276,41 @@ where
     /// Similar to [`prost::Message::decode_length_delimited`] but with
     /// additional validation prior to constructing the destination type.
     ///
+    /// Fails if the length of the message is greater than [`max_decode_len`].
+    ///
     /// [`prost::Message::decode_length_delimited`]: https://docs.rs/prost/*/prost/trait.Message.html#method.decode_length_delimited
     fn decode_length_delimited<B: Buf>(buf: B) -> Result<Self, Error>
     where
         Self: Sized,
     {
-        let raw = Raw::decode_length_delimited(buf).map_err(Error::decode_message)?;
+        Self::decode_length_delimited_with_limit(buf, max_decode_len())
+    }
+
+    /// Same as [`Protobuf::decode_length_delimited`], but fails if the length
+    /// of the message, as given by its delimiter, is greater than `max_len`
+    /// bytes, before decoding the message itself.
+    fn decode_length_delimited_with_limit<B: Buf>(mut buf: B, max_len: usize) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let raw = decode_length_delimited_raw::<Raw, _>(&mut buf, max_len, 0)?;
 
         Self::try_from(raw).map_err(Error::try_from::<Raw, Self, _>)
     }
 
+    /// Returns an iterator decoding the length-delimited instances found back to
+    /// back in the buffer, eg. in a block export or a state-sync snapshot.
+    ///
+    /// The iterator stops at the end of the buffer, or after the first error, whose
+    /// offset is given from the start of the buffer. The length of each message is
+    /// limited to [`max_decode_len`].
+    fn decode_length_delimited_iter<B: Buf>(buf: B) -> DecodeLengthDelimitedIter<Self, Raw, B>
+    where
+        Self: Sized,
+    {
+        DecodeLengthDelimitedIter::new(buf)
+    }
+
     /// Returns the encoded length of the message without a length delimiter.
     ///
     /// Uses [`prost::Message::encoded_len`] after converting to its
@@ -142,12 +318,20 @@ where
     ///
     /// [`prost::Message::encoded_len`]: https://docs.rs/prost/*/prost/trait.Message.html#method.encoded_len
     fn encoded_len(&self) -> usize {
-        self.clone_into().encoded_len()
+        self.to_raw().encoded_len()
     }
 
     /// Encodes into a Protobuf-encoded `Vec<u8>`.
     fn encode_vec(&self) -> Vec<u8> {
-        self.clone_into().encode_to_vec()
+        self.to_raw().encode_to_vec()
+    }
+
+    /// Encodes into a deterministic Protobuf-encoded `Vec<u8>`, eg. to produce sign bytes.
+    ///
+    /// Unlike [`Protobuf::encode_vec`], the fields are guaranteed to be written in field
+    /// number order, see [`encode_canonical`].
+    fn encode_canonical(&self) -> Vec<u8> {
+        encode_canonical(&self.to_raw())
     }
 
     /// Constructor that attempts to decode a Protobuf-encoded instance from a
@@ -159,9 +343,18 @@ where
         Self::decode(v)
     }
 
+    /// Same as [`Protobuf::decode_vec`], but fails if the message is longer
+    /// than `max_len` bytes.
+    fn decode_vec_with_limit(v: &[u8], max_len: usize) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        Self::decode_with_limit(v, max_len)
+    }
+
     /// Encode with a length-delimiter to a `Vec<u8>` Protobuf-encoded message.
     fn encode_length_delimited_vec(&self) -> Vec<u8> {
-        self.clone_into().encode_length_delimited_to_vec()
+        self.to_raw().encode_length_delimited_to_vec()
     }
 
     /// Constructor that attempts to decode a Protobuf-encoded instance with a
@@ -178,10 +371,128 @@ where
         let encoded = hex::encode(buf);
         String::from_utf8(encoded).expect("hex-encoded string should always be valid UTF-8")
     }
+
+    /// Constructor that attempts to decode an instance from a hex-encoded
+    /// Protobuf message, eg. the result of an ABCI query.
+    ///
+    /// The hex string may be upper or lower case, and prefixed with `0x`.
+    fn decode_from_hex_string(s: &str) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let s = s.trim();
+        let s = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        let buf = hex::decode(s.to_ascii_lowercase()).map_err(Error::decode_hex)?;
+
+        Self::decode_vec(&buf)
+    }
+
+    /// Encodes into a base64 string of the Protobuf-encoded message, using the
+    /// standard alphabet with padding, eg. for event attributes.
+    fn encode_to_base64_string(&self) -> String {
+        BASE64_STANDARD.encode(self.encode_vec())
+    }
+
+    /// Constructor that attempts to decode an instance from a base64 string of
+    /// the Protobuf-encoded message, eg. as found in RPC responses.
+    fn decode_from_base64_string(s: &str) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let buf = BASE64_STANDARD
+            .decode(s.trim())
+            .map_err(Error::decode_base64)?;
+
+        Self::decode_vec(&buf)
+    }
+
+    /// Packs into a `google.protobuf.Any`, using the type URL of the
+    /// counterpart Protobuf message.
+    fn to_any(&self) -> Any
+    where
+        Raw: Name,
+    {
+        Any {
+            type_url: Raw::type_url(),
+            value: self.encode_vec(),
+        }
+    }
+
+    /// Constructor that attempts to unpack an instance from a `google.protobuf.Any`.
+    ///
+    /// Fails if the type URL of the `Any` does not match the one of the
+    /// counterpart Protobuf message, or if the value cannot be decoded.
+    fn from_any(any: &Any) -> Result<Self, Error>
+    where
+        Self: Sized,
+        Raw: Name,
+    {
+        let expected = Raw::type_url();
+        if any.type_url != expected {
+            return Err(Error::mismatched_type_url(expected, any.type_url.clone()));
+        }
+
+        check_len(any.value.len(), max_decode_len())?;
+
+        let raw = decode_raw::<Raw, _>(any.value.as_slice(), Some(expected), 0)?;
+
+        Self::try_from(raw).map_err(Error::try_from::<Raw, Self, _>)
+    }
+}
+
+/// Decodes a length-delimited raw message from the start of a buffer found at
+/// `offset` in the input, leaving the bytes after the message in the buffer.
+fn decode_length_delimited_raw<Raw, B>(
+    buf: &mut B,
+    max_len: usize,
+    offset: usize,
+) -> Result<Raw, Error>
+where
+    Raw: Message + Default,
+    B: Buf,
+{
+    let total = buf.remaining();
+    let len = decode_varint(&mut *buf).map_err(|e| Error::decode::<Raw>(e, None, offset))?;
+    let len = usize::try_from(len).unwrap_or(usize::MAX);
+    let offset = offset + total - buf.remaining();
+    check_len(len, max_len)?;
+    if len > buf.remaining() {
+        let e = DecodeError::new("buffer underflow");
+        return Err(Error::decode::<Raw>(e, None, offset));
+    }
+
+    decode_raw::<Raw, _>(buf.take(len), None, offset)
+}
+
+/// Decodes a raw message from a buffer found at `offset` in the input, keeping
+/// track of where decoding stopped on failure.
+fn decode_raw<Raw, B>(mut buf: B, type_url: Option<String>, offset: usize) -> Result<Raw, Error>
+where
+    Raw: Message + Default,
+    B: Buf,
+{
+    let len = buf.remaining();
+
+    Raw::decode(&mut buf)
+        .map_err(|e| Error::decode::<Raw>(e, type_url, offset + len - buf.remaining()))
+}
+
+pub(crate) fn check_len(len: usize, max_len: usize) -> Result<(), Error> {
+    if len > max_len {
+        return Err(Error::message_too_large(len, max_len));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
+    use alloc::borrow::ToOwned;
+    use alloc::string::ToString;
+    use alloc::vec;
     use core::convert::{From, TryFrom};
 
     use super::*;
@@ -217,4 +528,346 @@ mod test {
             }
         }
     }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct RawCounter {
+        #[prost(uint64, tag = "1")]
+        count: u64,
+    }
+
+    impl Name for RawCounter {
+        const NAME: &'static str = "Counter";
+        const PACKAGE: &'static str = "ibc.mock";
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Counter(u64);
+
+    impl Protobuf<RawCounter> for Counter {}
+
+    impl TryFrom<RawCounter> for Counter {
+        type Error = String;
+
+        fn try_from(raw: RawCounter) -> Result<Self, Self::Error> {
+            if raw.count == 0 {
+                return Err("count must be greater than 0".to_owned());
+            }
+            Ok(Self(raw.count))
+        }
+    }
+
+    impl From<Counter> for RawCounter {
+        fn from(value: Counter) -> Self {
+            Self { count: value.0 }
+        }
+    }
+
+    #[test]
+    fn test_any_roundtrip() {
+        let any = Counter(42).to_any();
+        assert_eq!(any.type_url, "/ibc.mock.Counter");
+        assert_eq!(Counter::from_any(&any).unwrap(), Counter(42));
+    }
+
+    #[test]
+    fn test_from_any_rejects_mismatched_type_url() {
+        let mut any = Counter(42).to_any();
+        any.type_url = "/ibc.mock.Other".to_owned();
+        assert!(Counter::from_any(&any).is_err());
+
+        let any = Counter(0).to_any();
+        assert!(Counter::from_any(&any).is_err());
+    }
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let hex = Counter(42).encode_to_hex_string();
+        assert_eq!(hex, "082a");
+        assert_eq!(Counter::decode_from_hex_string(&hex).unwrap(), Counter(42));
+        assert_eq!(
+            Counter::decode_from_hex_string("0x082A").unwrap(),
+            Counter(42)
+        );
+        assert!(Counter::decode_from_hex_string("0x08z").is_err());
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let base64 = Counter(42).encode_to_base64_string();
+        assert_eq!(base64, "CCo=");
+        assert_eq!(
+            Counter::decode_from_base64_string(&base64).unwrap(),
+            Counter(42)
+        );
+        assert!(Counter::decode_from_base64_string("CC*").is_err());
+    }
+
+    #[test]
+    fn test_decode_error_context() {
+        // The value of the field is missing after its key.
+        let err = Counter::decode_vec(&[0x08]).unwrap_err().to_string();
+        assert!(err.contains("RawCounter"), "{err}");
+        assert!(err.contains("at byte 1"), "{err}");
+
+        let any = Any {
+            type_url: "/ibc.mock.Counter".to_owned(),
+            value: vec![0x08],
+        };
+        let err = Counter::from_any(&any).unwrap_err().to_string();
+        assert!(err.contains("/ibc.mock.Counter"), "{err}");
+
+        let err = Counter::decode_vec(&[0x08, 0x00]).unwrap_err().to_string();
+        assert!(
+            err.contains("RawCounter") && err.contains("Counter`"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_source() {
+        use std::error::Error as _;
+
+        let err = Counter::decode_vec(&[0x08]).unwrap_err();
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<DecodeError>().is_some(), "{source}");
+
+        let err = Counter::decode_vec(&[0x08, 0x00]).unwrap_err();
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "count must be greater than 0");
+        assert!(source.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_source_downcast() {
+        use std::error::Error as _;
+
+        #[derive(Debug, PartialEq)]
+        struct ZeroCount;
+
+        impl core::fmt::Display for ZeroCount {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("count is zero")
+            }
+        }
+
+        impl std::error::Error for ZeroCount {}
+
+        #[derive(Clone, Debug)]
+        struct StrictCounter;
+
+        impl Protobuf<RawCounter> for StrictCounter {}
+
+        impl TryFrom<RawCounter> for StrictCounter {
+            type Error = ZeroCount;
+
+            fn try_from(_: RawCounter) -> Result<Self, Self::Error> {
+                Err(ZeroCount)
+            }
+        }
+
+        impl From<StrictCounter> for RawCounter {
+            fn from(_: StrictCounter) -> Self {
+                Self { count: 0 }
+            }
+        }
+
+        let err = StrictCounter::decode_vec(&[]).unwrap_err();
+        assert_eq!(
+            err.source().unwrap().downcast_ref::<ZeroCount>(),
+            Some(&ZeroCount)
+        );
+        assert_eq!(err.clone(), err);
+
+        match err {
+            Error::TryFromProtobuf { reason, .. } => {
+                assert_eq!(reason.message(), "count is zero");
+                assert!(reason.inner().downcast_ref::<ZeroCount>().is_some());
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_erased() {
+        let msgs: Vec<Box<dyn Protobuf<RawCounter, Error = String>>> =
+            vec![Box::new(Counter(1)), Box::new(Counter(2))];
+
+        let mut buf = bytes::BytesMut::new();
+        for msg in &msgs {
+            msg.encode_length_delimited_erased(&mut buf).unwrap();
+        }
+
+        let mut buf = buf.freeze();
+        assert_eq!(
+            Counter::decode_length_delimited(&mut buf).unwrap(),
+            Counter(1)
+        );
+        assert_eq!(
+            Counter::decode_length_delimited(&mut buf).unwrap(),
+            Counter(2)
+        );
+    }
+
+    #[test]
+    fn test_decode_length_delimited_iter() {
+        let mut buf = Counter(1).encode_length_delimited_vec();
+        buf.extend(Counter(2).encode_length_delimited_vec());
+        let decoded: Vec<_> = Counter::decode_length_delimited_iter(buf.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(decoded, [Counter(1), Counter(2)]);
+
+        // The iterator stops after the invalid second message.
+        buf.extend(Counter(3).encode_length_delimited_vec());
+        buf[3] = 0x00;
+        let mut iter = Counter::decode_length_delimited_iter(buf.as_slice());
+        assert_eq!(iter.next().unwrap().unwrap(), Counter(1));
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.offset(), 4);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_decode_length_delimited_stream() {
+        use futures_util::{FutureExt, StreamExt};
+
+        let mut buf = Counter(1).encode_length_delimited_vec();
+        buf.extend(Counter(2).encode_length_delimited_vec());
+        let decoded: Vec<_> =
+            decode_length_delimited_stream::<Counter, RawCounter, _>(buf.as_slice())
+                .collect::<Vec<_>>()
+                .now_or_never()
+                .unwrap();
+        assert_eq!(decoded, [Ok(Counter(1)), Ok(Counter(2))]);
+
+        // A length prefix announcing far more bytes than the stream holds is
+        // reported as a truncation, without allocating the announced length.
+        let mut buf = Counter(1).encode_length_delimited_vec();
+        buf.extend([
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 0x08, 0x2a,
+        ]);
+        let mut stream = Box::pin(decode_length_delimited_stream::<Counter, RawCounter, _>(
+            buf.as_slice(),
+        ));
+        assert_eq!(
+            stream.next().now_or_never().unwrap().unwrap().unwrap(),
+            Counter(1)
+        );
+        match stream.next().now_or_never().unwrap().unwrap() {
+            Err(Error::DecodeMessage { offset, .. }) => assert_eq!(offset, 3 + 9 + 2),
+            result => panic!("unexpected result: {result:?}"),
+        }
+        assert!(stream.next().now_or_never().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_with_limit() {
+        let encoded = Counter(42).encode_vec();
+        assert_eq!(
+            Counter::decode_with_limit(encoded.as_slice(), encoded.len()).unwrap(),
+            Counter(42)
+        );
+        assert!(Counter::decode_with_limit(encoded.as_slice(), encoded.len() - 1).is_err());
+
+        let encoded = Counter(42).encode_length_delimited_vec();
+        assert_eq!(
+            Counter::decode_length_delimited_with_limit(encoded.as_slice(), 2).unwrap(),
+            Counter(42)
+        );
+        assert!(Counter::decode_length_delimited_with_limit(encoded.as_slice(), 1).is_err());
+
+        // A delimiter announcing more bytes than available is rejected.
+        let truncated = [0x05, 0x08, 0x2a];
+        assert!(Counter::decode_length_delimited_with_limit(truncated.as_slice(), 8).is_err());
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct RawProof {
+        #[prost(bytes = "vec", tag = "1")]
+        proof: Vec<u8>,
+    }
+
+    impl Name for RawProof {
+        const NAME: &'static str = "Proof";
+        const PACKAGE: &'static str = "ibc.mock";
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Proof(Vec<u8>);
+
+    // Encoding must not go through the (here unusable) clone of the domain type.
+    impl Clone for Proof {
+        fn clone(&self) -> Self {
+            panic!("proofs must not be cloned to be encoded")
+        }
+    }
+
+    impl Protobuf<RawProof> for Proof {
+        fn to_raw(&self) -> RawProof {
+            RawProof {
+                proof: self.0.clone(),
+            }
+        }
+    }
+
+    impl TryFrom<RawProof> for Proof {
+        type Error = String;
+
+        fn try_from(raw: RawProof) -> Result<Self, Self::Error> {
+            Ok(Self(raw.proof))
+        }
+    }
+
+    impl From<Proof> for RawProof {
+        fn from(value: Proof) -> Self {
+            Self { proof: value.0 }
+        }
+    }
+
+    #[test]
+    fn test_encode_with_to_raw() {
+        let proof = Proof(vec![0xab; 16]);
+
+        let encoded = proof.encode_vec();
+        assert_eq!(proof.encoded_len(), encoded.len());
+        assert_eq!(Proof::decode_vec(&encoded).unwrap(), Proof(vec![0xab; 16]));
+
+        let any = proof.to_any();
+        assert_eq!(Proof::from_any(&any).unwrap(), Proof(vec![0xab; 16]));
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct RawUnordered {
+        #[prost(uint64, tag = "2")]
+        b: u64,
+        #[prost(string, repeated, tag = "1")]
+        a: Vec<String>,
+        #[prost(bytes = "vec", tag = "3")]
+        c: Vec<u8>,
+    }
+
+    #[test]
+    fn test_encode_canonical_sorts_fields() {
+        let raw = RawUnordered {
+            b: 7,
+            a: vec!["x".to_owned(), "y".to_owned()],
+            c: vec![0xff],
+        };
+
+        let expected = [
+            0x0a, 0x01, b'x', // a: "x"
+            0x0a, 0x01, b'y', // a: "y"
+            0x10, 0x07, // b: 7
+            0x1a, 0x01, 0xff, // c: [0xff]
+        ];
+        assert_eq!(encode_canonical(&raw), expected);
+        assert_eq!(RawUnordered::decode(expected.as_slice()).unwrap(), raw);
+
+        // Already ordered encodings are left untouched.
+        let raw = RawCounter { count: 42 };
+        assert_eq!(encode_canonical(&raw), raw.encode_to_vec());
+    }
 }
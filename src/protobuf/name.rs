@@ -0,0 +1,29 @@
+//! Associates Protobuf messages with their fully-qualified name and type URL.
+
+use alloc::format;
+use alloc::string::String;
+
+use prost::Message;
+
+/// Equivalent of `prost::Name` from newer `prost` releases: associates a
+/// Protobuf message with its fully-qualified name and its type URL, as used
+/// in `google.protobuf.Any`.
+pub trait Name: Message {
+    /// Simple name of the message, eg. `MsgTransfer`.
+    ///
+    /// Nested messages are prefixed with the name of their parent, eg. `Data.Single`.
+    const NAME: &'static str;
+
+    /// Package the message is defined in, eg. `ibc.applications.transfer.v1`.
+    const PACKAGE: &'static str;
+
+    /// Fully-qualified name of the message, eg. `ibc.applications.transfer.v1.MsgTransfer`.
+    fn full_name() -> String {
+        format!("{}.{}", Self::PACKAGE, Self::NAME)
+    }
+
+    /// Type URL of the message, eg. `/ibc.applications.transfer.v1.MsgTransfer`.
+    fn type_url() -> String {
+        format!("/{}", Self::full_name())
+    }
+}
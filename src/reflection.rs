@@ -0,0 +1,127 @@
+//! The gRPC server reflection service of `tonic-reflection`, describing the services of
+//! this crate, eg. for `grpcurl` to list and call the services of a mock chain or of a
+//! gateway built on the generated servers:
+//!
+//! ```ignore
+//! Server::builder()
+//!     .add_service(reflection::service()?)
+//!     .add_service(QueryServer::new(MockChain::default()))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+use alloc::vec::Vec;
+
+use tonic_reflection::server::{Builder, Error, ServerReflection, ServerReflectionServer};
+
+/// Returns the reflection service, describing the services of the enabled features.
+pub fn service() -> Result<ServerReflectionServer<impl ServerReflection>, Error> {
+    builder().build()
+}
+
+/// Returns a builder of the reflection service, with the descriptors of the services of
+/// the enabled features already registered, eg. to register those of other services
+/// served alongside.
+pub fn builder() -> Builder<'static> {
+    file_descriptor_sets().into_iter().fold(
+        Builder::configure(),
+        Builder::register_encoded_file_descriptor_set,
+    )
+}
+
+/// Returns the encoded descriptor sets of the packages of the enabled features which
+/// define services.
+// The pushes depend on the enabled features.
+#[allow(clippy::vec_init_then_push)]
+pub fn file_descriptor_sets() -> Vec<&'static [u8]> {
+    let mut sets = Vec::new();
+    #[cfg(feature = "cosmos-auth")]
+    sets.push(crate::cosmos::auth::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-authz")]
+    sets.push(crate::cosmos::authz::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-autocli")]
+    sets.push(crate::cosmos::autocli::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-bank")]
+    sets.push(crate::cosmos::bank::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::node::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::reflection::v1beta1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::reflection::v2alpha1::FILE_DESCRIPTOR_SET);
+    sets.push(crate::cosmos::base::tendermint::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-consensus")]
+    sets.push(crate::cosmos::consensus::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-distribution")]
+    sets.push(crate::cosmos::distribution::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-evidence")]
+    sets.push(crate::cosmos::evidence::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-feegrant")]
+    sets.push(crate::cosmos::feegrant::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-gov")]
+    {
+        sets.push(crate::cosmos::gov::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::cosmos::gov::v1beta1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "cosmos-group")]
+    sets.push(crate::cosmos::group::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-mint")]
+    sets.push(crate::cosmos::mint::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-nft")]
+    sets.push(crate::cosmos::nft::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-slashing")]
+    sets.push(crate::cosmos::slashing::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-staking")]
+    sets.push(crate::cosmos::staking::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-tx")]
+    sets.push(crate::cosmos::tx::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-upgrade")]
+    sets.push(crate::cosmos::upgrade::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "cosmos-vesting")]
+    sets.push(crate::cosmos::vesting::v1beta1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-fee")]
+    sets.push(crate::ibc::applications::fee::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-interchain-accounts")]
+    {
+        sets.push(
+            crate::ibc::applications::interchain_accounts::controller::v1::FILE_DESCRIPTOR_SET,
+        );
+        sets.push(crate::ibc::applications::interchain_accounts::host::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-nft-transfer")]
+    sets.push(crate::ibc::applications::nft_transfer::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-transfer")]
+    sets.push(crate::ibc::applications::transfer::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ibc-core")]
+    {
+        sets.push(crate::ibc::core::channel::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::client::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::ibc::core::connection::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "ibc-lightclients")]
+    sets.push(crate::ibc::lightclients::wasm::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "icq")]
+    sets.push(crate::icq::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "interchain-security")]
+    {
+        sets.push(crate::interchain_security::ccv::consumer::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::interchain_security::ccv::provider::v1::FILE_DESCRIPTOR_SET);
+        sets.push(crate::interchain_security::ccv::v1::FILE_DESCRIPTOR_SET);
+    }
+    #[cfg(feature = "packetforward")]
+    sets.push(crate::packetforward::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "ratelimit")]
+    sets.push(crate::ratelimit::v1::FILE_DESCRIPTOR_SET);
+    #[cfg(feature = "stride")]
+    sets.push(crate::stride::interchainquery::v1::FILE_DESCRIPTOR_SET);
+
+    sets
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_service() {
+        assert!(!file_descriptor_sets().is_empty());
+        assert!(service().is_ok());
+    }
+}
@@ -0,0 +1,440 @@
+//! A registry of the messages of this crate and of its users, to decode the
+//! `google.protobuf.Any` found in arbitrary payloads, eg. the messages of the
+//! transactions of a block, without matching on their type URL by hand.
+//!
+//! The messages are decoded into a [`DynMessage`] trait object, which can be
+//! downcast to the type of the message:
+//!
+//! ```rust,ignore
+//! use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+//! use ibc_proto::registry::decode_any_dynamic;
+//!
+//! let msg = decode_any_dynamic(&any)?;
+//! if let Some(msg) = msg.downcast_ref::<MsgSend>() {
+//!     println!("{} sent {:?}", msg.from_address, msg.amount);
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::any::Any as CoreAny;
+use core::fmt;
+
+use prost::Message;
+
+use crate::google::protobuf::Any;
+use crate::protobuf::{check_len, max_decode_len, Error, Name};
+
+/// A decoded message, whose type is only known at runtime.
+pub trait DynMessage: CoreAny + fmt::Debug + Send + Sync {
+    /// Packs the message back into a `google.protobuf.Any`.
+    fn to_any(&self) -> Any;
+
+    /// Returns the message as a `core::any::Any`, to downcast it.
+    fn as_any(&self) -> &dyn CoreAny;
+
+    /// Same as [`DynMessage::as_any`], for a boxed message.
+    fn into_any(self: Box<Self>) -> Box<dyn CoreAny>;
+}
+
+impl<M> DynMessage for M
+where
+    M: Message + Name + 'static,
+{
+    fn to_any(&self) -> Any {
+        Any {
+            type_url: M::type_url(),
+            value: self.encode_to_vec(),
+        }
+    }
+
+    fn as_any(&self) -> &dyn CoreAny {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn CoreAny> {
+        self
+    }
+}
+
+impl dyn DynMessage {
+    /// Returns whether the message is of type `M`.
+    pub fn is<M: DynMessage>(&self) -> bool {
+        self.as_any().is::<M>()
+    }
+
+    /// Returns the message if it is of type `M`.
+    pub fn downcast_ref<M: DynMessage>(&self) -> Option<&M> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the boxed message if it is of type `M`, or gives it back otherwise.
+    pub fn downcast<M: DynMessage>(self: Box<Self>) -> Result<Box<M>, Box<Self>> {
+        if self.is::<M>() {
+            Ok(self
+                .into_any()
+                .downcast()
+                .expect("the type was checked above"))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// Decodes the value of a `google.protobuf.Any` into a message of the type given
+/// by its type URL.
+pub type Decoder = fn(&[u8]) -> Result<Box<dyn DynMessage>, Error>;
+
+fn decode<M>(mut bytes: &[u8]) -> Result<Box<dyn DynMessage>, Error>
+where
+    M: Message + Default + Name + 'static,
+{
+    let len = bytes.len();
+    check_len(len, max_decode_len())?;
+
+    match M::decode(&mut bytes) {
+        Ok(msg) => Ok(Box::new(msg)),
+        Err(e) => Err(Error::decode::<M>(
+            e,
+            Some(M::type_url()),
+            len - bytes.len(),
+        )),
+    }
+}
+
+/// Maps the type URLs of the messages to the functions decoding them.
+#[derive(Clone, Default)]
+pub struct Registry {
+    decoders: BTreeMap<String, Decoder>,
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.decoders.keys()).finish()
+    }
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry of all the messages of this crate, among the enabled
+    /// features.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+
+        crate::google::protobuf::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-auth")]
+        {
+            crate::cosmos::auth::v1beta1::register_messages(&mut registry);
+            registry.register::<crate::cosmos::auth::v1beta1::EthAccount>();
+        }
+        #[cfg(feature = "cosmos-autocli")]
+        crate::cosmos::autocli::v1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-authz")]
+        crate::cosmos::authz::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-evidence")]
+        crate::cosmos::evidence::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-nft")]
+        crate::cosmos::nft::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-staking")]
+        crate::cosmos::staking::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-bank")]
+        crate::cosmos::bank::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::abci::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::kv::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::node::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::query::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::reflection::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::reflection::v2alpha1::register_messages(&mut registry);
+        crate::cosmos::base::snapshots::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::store::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::v1beta1::register_messages(&mut registry);
+        crate::cosmos::base::tendermint::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-consensus")]
+        crate::cosmos::consensus::v1::register_messages(&mut registry);
+        crate::cosmos::crypto::ed25519::register_messages(&mut registry);
+        crate::cosmos::crypto::multisig::register_messages(&mut registry);
+        crate::cosmos::crypto::multisig::v1beta1::register_messages(&mut registry);
+        crate::cosmos::crypto::secp256k1::register_messages(&mut registry);
+        crate::cosmos::crypto::secp256r1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-tx")]
+        {
+            crate::cosmos::tx::signing::v1beta1::register_messages(&mut registry);
+            crate::cosmos::tx::v1beta1::register_messages(&mut registry);
+        }
+        #[cfg(feature = "cosmos-upgrade")]
+        crate::cosmos::upgrade::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-vesting")]
+        crate::cosmos::vesting::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-distribution")]
+        crate::cosmos::distribution::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-mint")]
+        crate::cosmos::mint::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-slashing")]
+        crate::cosmos::slashing::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-feegrant")]
+        crate::cosmos::feegrant::v1beta1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-group")]
+        crate::cosmos::group::v1::register_messages(&mut registry);
+        #[cfg(feature = "cosmos-gov")]
+        {
+            crate::cosmos::gov::v1::register_messages(&mut registry);
+            crate::cosmos::gov::v1beta1::register_messages(&mut registry);
+        }
+        #[cfg(feature = "ibc-transfer")]
+        {
+            crate::ibc::applications::transfer::v1::register_messages(&mut registry);
+            crate::ibc::applications::transfer::v2::register_messages(&mut registry);
+        }
+        #[cfg(feature = "ibc-fee")]
+        crate::ibc::applications::fee::v1::register_messages(&mut registry);
+        #[cfg(feature = "ibc-interchain-accounts")]
+        {
+            crate::ibc::applications::interchain_accounts::v1::register_messages(&mut registry);
+            crate::ibc::applications::interchain_accounts::controller::v1::register_messages(
+                &mut registry,
+            );
+            crate::ibc::applications::interchain_accounts::genesis::v1::register_messages(
+                &mut registry,
+            );
+            crate::ibc::applications::interchain_accounts::host::v1::register_messages(
+                &mut registry,
+            );
+        }
+        #[cfg(feature = "ibc-nft-transfer")]
+        crate::ibc::applications::nft_transfer::v1::register_messages(&mut registry);
+        #[cfg(feature = "ibc-core")]
+        {
+            crate::ibc::core::channel::v1::register_messages(&mut registry);
+            crate::ibc::core::client::v1::register_messages(&mut registry);
+            crate::ibc::core::commitment::v1::register_messages(&mut registry);
+            crate::ibc::core::connection::v1::register_messages(&mut registry);
+            crate::ibc::core::types::v1::register_messages(&mut registry);
+        }
+        #[cfg(feature = "ibc-lightclients")]
+        {
+            crate::ibc::lightclients::localhost::v1::register_messages(&mut registry);
+            crate::ibc::lightclients::localhost::v2::register_messages(&mut registry);
+            crate::ibc::lightclients::solomachine::v1::register_messages(&mut registry);
+            crate::ibc::lightclients::solomachine::v3::register_messages(&mut registry);
+            crate::ibc::lightclients::tendermint::v1::register_messages(&mut registry);
+            crate::ibc::lightclients::wasm::v1::register_messages(&mut registry);
+        }
+        #[cfg(feature = "ibc-core")]
+        crate::ibc::mock::register_messages(&mut registry);
+        #[cfg(feature = "icq")]
+        crate::icq::v1::register_messages(&mut registry);
+        #[cfg(feature = "interchain-security")]
+        {
+            crate::interchain_security::ccv::v1::register_messages(&mut registry);
+            crate::interchain_security::ccv::provider::v1::register_messages(&mut registry);
+            crate::interchain_security::ccv::consumer::v1::register_messages(&mut registry);
+        }
+        #[cfg(feature = "packetforward")]
+        crate::packetforward::v1::register_messages(&mut registry);
+        #[cfg(feature = "ratelimit")]
+        crate::ratelimit::v1::register_messages(&mut registry);
+        #[cfg(feature = "stride")]
+        crate::stride::interchainquery::v1::register_messages(&mut registry);
+        #[cfg(feature = "ics23")]
+        {
+            use ics23::*;
+
+            registry.register::<ExistenceProof>();
+            registry.register::<NonExistenceProof>();
+            registry.register::<CommitmentProof>();
+            registry.register::<LeafOp>();
+            registry.register::<InnerOp>();
+            registry.register::<ProofSpec>();
+            registry.register::<InnerSpec>();
+            registry.register::<BatchProof>();
+            registry.register::<BatchEntry>();
+            registry.register::<CompressedBatchProof>();
+            registry.register::<CompressedBatchEntry>();
+            registry.register::<CompressedExistenceProof>();
+            registry.register::<CompressedNonExistenceProof>();
+        }
+
+        registry
+    }
+
+    /// Registers the messages of type `M`, under their type URL.
+    pub fn register<M>(&mut self)
+    where
+        M: Message + Default + Name + 'static,
+    {
+        self.register_with(M::type_url(), decode::<M>);
+    }
+
+    /// Registers the messages with the given type URL, decoded with `decoder`.
+    pub fn register_with(&mut self, type_url: impl Into<String>, decoder: Decoder) {
+        self.decoders.insert(type_url.into(), decoder);
+    }
+
+    /// Returns whether messages with the given type URL are registered.
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Returns the function decoding the messages with the given type URL, if any.
+    pub fn decoder(&self, type_url: &str) -> Option<Decoder> {
+        self.decoders.get(type_url).copied()
+    }
+
+    /// Decodes a packed message into the type registered for its type URL.
+    pub fn decode(&self, any: &Any) -> Result<Box<dyn DynMessage>, Error> {
+        let decoder = self
+            .decoder(&any.type_url)
+            .ok_or_else(|| Error::unknown_type_url(any.type_url.clone()))?;
+
+        decoder(&any.value)
+    }
+}
+
+#[cfg(feature = "std")]
+mod global {
+    use std::sync::{PoisonError, RwLock};
+
+    use super::*;
+
+    /// The registry of [`decode_any_dynamic`], created with the defaults on first use.
+    static GLOBAL: RwLock<Option<Registry>> = RwLock::new(None);
+
+    fn with_global<R>(f: impl FnOnce(&mut Registry) -> R) -> R {
+        let mut global = GLOBAL.write().unwrap_or_else(PoisonError::into_inner);
+        f(global.get_or_insert_with(Registry::with_defaults))
+    }
+
+    /// Registers the messages of type `M` in the global registry, see
+    /// [`decode_any_dynamic`].
+    pub fn register<M>()
+    where
+        M: Message + Default + Name + 'static,
+    {
+        with_global(|registry| registry.register::<M>());
+    }
+
+    /// Registers the messages with the given type URL in the global registry,
+    /// see [`decode_any_dynamic`].
+    pub fn register_with(type_url: impl Into<String>, decoder: Decoder) {
+        with_global(|registry| registry.register_with(type_url, decoder));
+    }
+
+    /// Decodes a packed message into the type registered for its type URL in the
+    /// global registry, which holds all the messages of this crate along with the
+    /// ones registered with [`register`] and [`register_with`].
+    pub fn decode_any_dynamic(any: &Any) -> Result<Box<dyn DynMessage>, Error> {
+        let cached = GLOBAL
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|registry| registry.decoder(&any.type_url));
+        let decoder = match cached {
+            Some(decoder) => decoder,
+            None => with_global(|registry| registry.decoder(&any.type_url)),
+        };
+
+        let decoder = decoder.ok_or_else(|| Error::unknown_type_url(any.type_url.clone()))?;
+        decoder(&any.value)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::global::{decode_any_dynamic, register, register_with};
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+    use alloc::vec;
+
+    use super::*;
+    use crate::cosmos::crypto::{ed25519, secp256k1};
+
+    fn pub_key() -> secp256k1::PubKey {
+        secp256k1::PubKey {
+            key: vec![0x02; 33],
+        }
+    }
+
+    #[test]
+    fn test_decode_registered() {
+        let registry = Registry::with_defaults();
+        assert!(registry.contains("/cosmos.crypto.secp256k1.PubKey"));
+
+        let any = pub_key().to_any();
+        let msg = registry.decode(&any).unwrap();
+        assert!(msg.is::<secp256k1::PubKey>());
+        assert!(!msg.is::<ed25519::PubKey>());
+        assert_eq!(msg.downcast_ref::<secp256k1::PubKey>(), Some(&pub_key()));
+        assert_eq!(msg.to_any(), any);
+
+        let msg = msg.downcast::<ed25519::PubKey>().unwrap_err();
+        assert_eq!(*msg.downcast::<secp256k1::PubKey>().unwrap(), pub_key());
+    }
+
+    #[test]
+    fn test_decode_unknown_type_url() {
+        let mut registry = Registry::new();
+        let any = pub_key().to_any();
+        assert_eq!(
+            registry.decode(&any).unwrap_err(),
+            Error::unknown_type_url(any.type_url.clone())
+        );
+
+        // Registering a message under another type URL.
+        registry.register_with("/custom.PubKey", decode::<secp256k1::PubKey>);
+        let custom = Any {
+            type_url: "/custom.PubKey".into(),
+            value: any.value.clone(),
+        };
+        assert!(registry.decode(&custom).is_ok());
+        assert!(registry.decode(&any).is_err());
+
+        registry.register::<secp256k1::PubKey>();
+        assert!(registry.decode(&any).is_ok());
+        assert_eq!(
+            format!("{registry:?}"),
+            r#"{"/cosmos.crypto.secp256k1.PubKey", "/custom.PubKey"}"#
+        );
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let registry = Registry::with_defaults();
+
+        // The key is announced with 33 bytes, of which only 2 are given after the 2 bytes
+        // of its tag and length.
+        let any = Any {
+            type_url: "/cosmos.crypto.secp256k1.PubKey".into(),
+            value: vec![0x0a, 0x21, 0x02, 0x02],
+        };
+        match registry.decode(&any).unwrap_err() {
+            Error::DecodeMessage {
+                type_url, offset, ..
+            } => {
+                assert_eq!(type_url.as_deref(), Some("/cosmos.crypto.secp256k1.PubKey"));
+                assert_eq!(offset, 2);
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_any_dynamic() {
+        let msg = decode_any_dynamic(&pub_key().to_any()).unwrap();
+        assert_eq!(msg.downcast_ref::<secp256k1::PubKey>(), Some(&pub_key()));
+
+        let any = Any {
+            type_url: "/registry.test.PubKey".into(),
+            value: pub_key().encode_to_vec(),
+        };
+        assert!(decode_any_dynamic(&any).is_err());
+        register_with("/registry.test.PubKey", decode::<secp256k1::PubKey>);
+        assert!(decode_any_dynamic(&any).unwrap().is::<secp256k1::PubKey>());
+    }
+}
@@ -0,0 +1,192 @@
+//! Serializers for the fields of the types deriving `serde::Serialize` and
+//! `serde::Deserialize`, following the proto3 JSON mapping used by the REST endpoints
+//! of the chains, ie. 64-bit integers as strings and bytes as base64.
+//!
+//! Integers are also accepted as JSON numbers when deserializing.
+
+pub mod base64 {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use ::base64::prelude::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let encoded = BASE64_STANDARD.encode(bytes);
+        String::serialize(&encoded, serializer)
+    }
+
+    /// Deserializes into either a `Vec<u8>` or a `bytes::Bytes`.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<Vec<u8>>,
+    {
+        let base64 = String::deserialize(deserializer)?;
+        let bytes = BASE64_STANDARD
+            .decode(base64.as_bytes())
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(bytes.into())
+    }
+}
+
+pub mod base64_vec {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use ::base64::prelude::*;
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes either `Vec<u8>`s or `bytes::Bytes`.
+    pub fn serialize<S, T>(bytes: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        let mut seq = serializer.serialize_seq(Some(bytes.len()))?;
+        for bytes in bytes {
+            seq.serialize_element(&BASE64_STANDARD.encode(bytes))?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes into either `Vec<u8>`s or `bytes::Bytes`.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<Vec<u8>>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|base64| BASE64_STANDARD.decode(base64.as_bytes()).map(T::from))
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+pub mod from_str {
+    use alloc::string::String;
+    use core::fmt::{self, Display};
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<i64>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(StringOrNumber(PhantomData))
+    }
+
+    /// Visits an integer given either as a string or as a number.
+    pub(super) struct StringOrNumber<T>(pub(super) PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for StringOrNumber<T>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<i64>,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an integer or a string holding one")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            value.parse().map_err(E::custom)
+        }
+
+        fn visit_string<E: de::Error>(self, value: String) -> Result<T, E> {
+            self.visit_str(&value)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+            T::try_from(value).map_err(|_| E::custom("integer out of range"))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+            T::try_from(value).map_err(|_| E::custom("integer out of range"))
+        }
+    }
+}
+
+// Only the packages of some features, eg. `ibc-core`, have such fields.
+#[allow(dead_code)]
+pub mod from_str_vec {
+    use alloc::vec::Vec;
+    use core::fmt::{self, Display};
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use super::from_str::StringOrNumber;
+
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(alloc::string::ToString::to_string))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<i64>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(Seq(PhantomData))
+    }
+
+    struct Seq<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for Seq<T>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<i64>,
+        T::Err: Display,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a sequence of integers or of strings holding one")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(value) = seq.next_element_seed(Element(PhantomData))? {
+                values.push(value);
+            }
+            Ok(values)
+        }
+    }
+
+    struct Element<T>(PhantomData<T>);
+
+    impl<'de, T> DeserializeSeed<'de> for Element<T>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<i64>,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+            deserializer.deserialize_any(StringOrNumber(PhantomData))
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Display and parsing of the states of the connections and channels, and of the
+//! orderings of the channels.
+//!
+//! These are displayed by their canonical names, eg. `STATE_OPEN` or `ORDER_UNORDERED`,
+//! as by ibc-go, and parsed either from these names or from their short forms, without
+//! the prefix nor the `_UNSPECIFIED` suffix, in any case, eg. `Open` or `unordered`.
+//!
+//! The messages holding these enums, as `i32`, get `try_*` accessors failing on the
+//! values which are none of their variants, unlike the accessors derived by `prost`,
+//! which fall back to the default variant.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::ibc::core::channel::v1::{
+    self as channel, Channel, IdentifiedChannel, MsgAcknowledgementResponse,
+    MsgChannelUpgradeAckResponse, MsgChannelUpgradeConfirm, MsgChannelUpgradeConfirmResponse,
+    MsgChannelUpgradeOpen, MsgChannelUpgradeTryResponse, MsgRecvPacketResponse,
+    MsgTimeoutOnCloseResponse, MsgTimeoutResponse, Order, ResponseResultType, UpgradeFields,
+};
+use crate::ibc::core::connection::v1::{self as connection, ConnectionEnd, IdentifiedConnection};
+
+macro_rules! impl_display_from_str {
+    ($($ty:ty => ($name:literal, $prefix:literal, [$($variant:ident),* $(,)?] $(,)?)),* $(,)?) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(self.as_str_name())
+                }
+            }
+
+            impl FromStr for $ty {
+                type Err = ParseEnumError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    [$(<$ty>::$variant),*]
+                        .into_iter()
+                        .find(|variant| matches_name(s, variant.as_str_name(), $prefix))
+                        .ok_or_else(|| ParseEnumError {
+                            name: $name,
+                            value: s.to_string(),
+                        })
+                }
+            }
+        )*
+    };
+}
+
+impl_display_from_str! {
+    connection::State => (
+        "connection state",
+        "STATE_",
+        [UninitializedUnspecified, Init, Tryopen, Open],
+    ),
+    channel::State => (
+        "channel state",
+        "STATE_",
+        [UninitializedUnspecified, Init, Tryopen, Open, Closed, Flushing, Flushcomplete],
+    ),
+    Order => (
+        "channel ordering",
+        "ORDER_",
+        [NoneUnspecified, Unordered, Ordered],
+    ),
+}
+
+macro_rules! impl_try_accessors {
+    ($($msg:ty => [$($accessor:ident: $field:ident => $enum:ty),* $(,)?]),* $(,)?) => {
+        $(
+            impl $msg {
+                $(
+                    #[doc = concat!(
+                        "Returns the `", stringify!($field), "` field, or an error if it ",
+                        "holds none of the values of the enum."
+                    )]
+                    pub fn $accessor(&self) -> Result<$enum, UnknownEnumValue> {
+                        <$enum>::from_i32(self.$field).ok_or(UnknownEnumValue {
+                            name: stringify!($field),
+                            value: self.$field,
+                        })
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+impl_try_accessors! {
+    ConnectionEnd => [try_state: state => connection::State],
+    IdentifiedConnection => [try_state: state => connection::State],
+    Channel => [try_state: state => channel::State, try_ordering: ordering => Order],
+    IdentifiedChannel => [try_state: state => channel::State, try_ordering: ordering => Order],
+    UpgradeFields => [try_ordering: ordering => Order],
+    MsgChannelUpgradeConfirm => [
+        try_counterparty_channel_state: counterparty_channel_state => channel::State,
+    ],
+    MsgChannelUpgradeOpen => [
+        try_counterparty_channel_state: counterparty_channel_state => channel::State,
+    ],
+    MsgRecvPacketResponse => [try_result: result => ResponseResultType],
+    MsgTimeoutResponse => [try_result: result => ResponseResultType],
+    MsgTimeoutOnCloseResponse => [try_result: result => ResponseResultType],
+    MsgAcknowledgementResponse => [try_result: result => ResponseResultType],
+    MsgChannelUpgradeTryResponse => [try_result: result => ResponseResultType],
+    MsgChannelUpgradeAckResponse => [try_result: result => ResponseResultType],
+    MsgChannelUpgradeConfirmResponse => [try_result: result => ResponseResultType],
+}
+
+/// Returns whether `s` is the canonical name of a variant, or its short form.
+fn matches_name(s: &str, name: &str, prefix: &str) -> bool {
+    if s == name {
+        return true;
+    }
+
+    let short = name.strip_prefix(prefix).unwrap_or(name);
+    let short = short.strip_suffix("_UNSPECIFIED").unwrap_or(short);
+    s.eq_ignore_ascii_case(short)
+}
+
+/// The error returned when parsing a connection or channel state, or a channel ordering,
+/// from a string which is none of its names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseEnumError {
+    /// The enum being parsed, eg. `channel state`.
+    pub name: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a valid {}", self.value, self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEnumError {}
+
+/// The error returned by the `try_*` accessors of the enum fields, when the field holds
+/// none of the values of the enum, eg. a state added by a newer version of ibc-go.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct UnknownEnumValue {
+    /// The field holding the value, eg. `state`.
+    pub name: &'static str,
+    pub value: i32,
+}
+
+impl fmt::Display for UnknownEnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown value `{}` of the `{}` field",
+            self.value, self.name
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownEnumValue {}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_connection_state_round_trip() {
+        for state in [
+            connection::State::UninitializedUnspecified,
+            connection::State::Init,
+            connection::State::Tryopen,
+            connection::State::Open,
+        ] {
+            assert_eq!(state.to_string().parse::<connection::State>(), Ok(state));
+        }
+
+        assert_eq!(connection::State::Open.to_string(), "STATE_OPEN");
+        assert_eq!("OPEN".parse(), Ok(connection::State::Open));
+        assert_eq!("tryopen".parse(), Ok(connection::State::Tryopen));
+        assert_eq!(
+            "UNINITIALIZED".parse(),
+            Ok(connection::State::UninitializedUnspecified)
+        );
+        assert!("STATE_CLOSED".parse::<connection::State>().is_err());
+    }
+
+    #[test]
+    fn test_channel_state_round_trip() {
+        for state in [
+            channel::State::UninitializedUnspecified,
+            channel::State::Init,
+            channel::State::Tryopen,
+            channel::State::Open,
+            channel::State::Closed,
+            channel::State::Flushing,
+            channel::State::Flushcomplete,
+        ] {
+            assert_eq!(state.to_string().parse::<channel::State>(), Ok(state));
+        }
+
+        assert_eq!(channel::State::Closed.to_string(), "STATE_CLOSED");
+        assert_eq!("OPEN".parse(), Ok(channel::State::Open));
+        assert_eq!("FlushComplete".parse(), Ok(channel::State::Flushcomplete));
+        assert_eq!(
+            "STATE_OPENED".parse::<channel::State>(),
+            Err(ParseEnumError {
+                name: "channel state",
+                value: "STATE_OPENED".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_order_round_trip() {
+        for order in [Order::NoneUnspecified, Order::Unordered, Order::Ordered] {
+            assert_eq!(order.to_string().parse::<Order>(), Ok(order));
+        }
+
+        assert_eq!(Order::Unordered.to_string(), "ORDER_UNORDERED");
+        assert_eq!("ORDERED".parse(), Ok(Order::Ordered));
+        assert_eq!("unordered".parse(), Ok(Order::Unordered));
+        assert_eq!("NONE".parse(), Ok(Order::NoneUnspecified));
+        assert!("STATE_OPEN".parse::<Order>().is_err());
+    }
+}
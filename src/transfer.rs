@@ -0,0 +1,403 @@
+//! Helpers to build the ICS-20 `FungibleTokenPacketData` of the transfers, and to encode
+//! it to and decode it from its JSON form, which is the data of the packets, rather
+//! than its Protobuf encoding, eg.
+//!
+//! ```json
+//! {"amount":"100","denom":"uatom","receiver":"osmo1...","sender":"cosmos1..."}
+//! ```
+//!
+//! The JSON is encoded as by ibc-go, so that the commitments of the packets match, ie.
+//! with the fields sorted by name, the empty ones left out, eg. the memo, and the
+//! amount given as a string, as it may not fit in 64 bits.
+//!
+//! The `DenomTrace` of the tokens received through IBC are mapped to their `ibc/{hash}`
+//! denoms, and parsed back from their full paths, as by ibc-go.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use sha2::{Digest, Sha256};
+
+use crate::ibc::applications::transfer::v1::DenomTrace;
+use crate::ibc::applications::transfer::v2::FungibleTokenPacketData;
+use crate::wire_json;
+
+impl FungibleTokenPacketData {
+    /// Creates the data of a transfer of `amount` tokens of `denom`, given with its
+    /// trace, eg. `transfer/channel-0/uatom`, without any memo.
+    pub fn new(
+        denom: impl Into<String>,
+        amount: impl Into<String>,
+        sender: impl Into<String>,
+        receiver: impl Into<String>,
+    ) -> Self {
+        Self {
+            denom: denom.into(),
+            amount: amount.into(),
+            sender: sender.into(),
+            receiver: receiver.into(),
+            memo: String::new(),
+        }
+    }
+
+    /// Sets the memo of the transfer, eg. the JSON of the packet forward middleware.
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    /// Encodes the packet data to its JSON form, as the data of the packets.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        let fields = [
+            ("amount", &self.amount),
+            ("denom", &self.denom),
+            ("memo", &self.memo),
+            ("receiver", &self.receiver),
+            ("sender", &self.sender),
+        ];
+
+        wire_json::write_object(
+            fields
+                .into_iter()
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(key, value)| (key, value.as_str())),
+        )
+    }
+
+    /// Decodes the packet data from its JSON form, whose fields may be in any order,
+    /// failing on the unknown fields as ibc-go.
+    ///
+    /// The fields are not validated, eg. that the amount is a positive integer.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, PacketDataError> {
+        let fields = wire_json::parse_object(bytes).ok_or(PacketDataError::InvalidJson)?;
+
+        let mut data = Self::default();
+        for (key, value) in fields {
+            let field = match key.as_str() {
+                "denom" => &mut data.denom,
+                "amount" => &mut data.amount,
+                "sender" => &mut data.sender,
+                "receiver" => &mut data.receiver,
+                "memo" => &mut data.memo,
+                _ => return Err(PacketDataError::UnknownField(key)),
+            };
+            if !field.is_empty() {
+                return Err(PacketDataError::DuplicateField(key));
+            }
+            *field = value;
+        }
+
+        Ok(data)
+    }
+}
+
+impl DenomTrace {
+    /// Parses the full path of a denom, eg. `transfer/channel-0/uatom`, into its trace
+    /// of `{port}/{channel}` hops and its base denom, as `ParseDenomTrace` in ibc-go.
+    ///
+    /// A hop is only recognized if its channel is of the `channel-{n}` form, so that
+    /// the base denoms holding slashes are kept whole, eg. `gamm/pool/1`. The `ibc/{hash}`
+    /// denoms are parsed as a base denom, as their trace cannot be recovered from them.
+    pub fn parse(full_path: &str) -> Self {
+        let items: Vec<&str> = full_path.split('/').collect();
+
+        let mut len = 0;
+        while items.len() > 2 && len + 1 < items.len() && is_channel_id(items[len + 1]) {
+            len += 2;
+        }
+
+        Self {
+            path: items[..len].join("/"),
+            base_denom: items[len..].join("/"),
+        }
+    }
+
+    /// Returns the full path of the denom, ie. `{path}/{base_denom}`, or the base denom
+    /// for the native tokens.
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            alloc::format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Returns whether the denom is the one of a native token, ie. without any trace.
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Returns the SHA-256 hash of the full path of the denom.
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.full_path().as_bytes()).into()
+    }
+
+    /// Returns the denom of the tokens on the chain, ie. `ibc/{hash}` with the hash in
+    /// upper-case hexadecimal, or the base denom for the native tokens.
+    pub fn ibc_denom(&self) -> String {
+        if self.is_native() {
+            return self.base_denom.clone();
+        }
+
+        let mut denom = String::from("ibc/");
+        for byte in self.hash() {
+            let _ = write!(denom, "{byte:02X}");
+        }
+        denom
+    }
+
+    /// Parses the hash of an `ibc/{hash}` denom, given in hexadecimal.
+    pub fn parse_ibc_denom(denom: &str) -> Result<[u8; 32], DenomError> {
+        let invalid = || DenomError::InvalidIbcDenom(denom.to_string());
+
+        let hash = denom.strip_prefix("ibc/").ok_or_else(invalid)?;
+        let hash =
+            subtle_encoding::hex::decode(hash.to_ascii_lowercase()).map_err(|_| invalid())?;
+
+        hash.try_into().map_err(|_| invalid())
+    }
+
+    /// Validates the trace, made of pairs of valid port and channel identifiers, and the
+    /// base denom, which must not be blank, as `DenomTrace.Validate` in ibc-go.
+    pub fn validate(&self) -> Result<(), DenomError> {
+        if !self.path.is_empty() {
+            let items: Vec<&str> = self.path.split('/').collect();
+            if !items.len().is_multiple_of(2) {
+                return Err(DenomError::InvalidTrace(self.path.clone()));
+            }
+
+            for hop in items.chunks(2) {
+                if !is_identifier(hop[0], 2, 128) || !is_identifier(hop[1], 8, 64) {
+                    return Err(DenomError::InvalidTrace(self.path.clone()));
+                }
+            }
+        }
+
+        if self.base_denom.trim().is_empty() {
+            return Err(DenomError::EmptyBaseDenom);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether the identifier is a channel identifier of ibc-go, ie. `channel-{n}`,
+/// where `n` is made of decimal digits only, eg. without any sign.
+fn is_channel_id(id: &str) -> bool {
+    id.strip_prefix("channel-")
+        .is_some_and(|n| n.bytes().all(|b| b.is_ascii_digit()) && n.parse::<u64>().is_ok())
+}
+
+/// Returns whether the identifier is valid, as the identifiers of ICS-24.
+fn is_identifier(id: &str, min_len: usize, max_len: usize) -> bool {
+    (min_len..=max_len).contains(&id.len())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._+-#[]<>".contains(c))
+}
+
+/// The errors of the validation of a [`DenomTrace`], or of the parsing of an `ibc/{hash}`
+/// denom.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DenomError {
+    /// The trace is not made of pairs of valid port and channel identifiers.
+    InvalidTrace(String),
+    /// The base denom is blank.
+    EmptyBaseDenom,
+    /// The denom is not of the `ibc/{hash}` form, with a SHA-256 hash in hexadecimal.
+    InvalidIbcDenom(String),
+}
+
+impl fmt::Display for DenomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DenomError::InvalidTrace(path) => write!(f, "invalid denom trace `{path}`"),
+            DenomError::EmptyBaseDenom => write!(f, "base denom cannot be blank"),
+            DenomError::InvalidIbcDenom(denom) => {
+                write!(f, "`{denom}` is not a denom of the `ibc/{{hash}}` form")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DenomError {}
+
+/// The errors of the decoding of a [`FungibleTokenPacketData`] from its JSON form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PacketDataError {
+    /// The packet data is not a JSON object made of string fields.
+    InvalidJson,
+    /// The field is not one of the packet data.
+    UnknownField(String),
+    /// The field is given several times.
+    DuplicateField(String),
+}
+
+impl fmt::Display for PacketDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketDataError::InvalidJson => {
+                write!(f, "packet data is not a JSON object with string fields")
+            }
+            PacketDataError::UnknownField(key) => write!(f, "unknown packet data field `{key}`"),
+            PacketDataError::DuplicateField(key) => {
+                write!(f, "duplicate packet data field `{key}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PacketDataError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ATOM_ON_OSMOSIS: &str =
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+    const OSMO_ON_COSMOS_HUB: &str =
+        "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC";
+
+    fn trace(path: &str, base_denom: &str) -> DenomTrace {
+        DenomTrace {
+            path: path.to_string(),
+            base_denom: base_denom.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_packet_data_json() {
+        let data = FungibleTokenPacketData::new(
+            "transfer/channel-0/uatom",
+            "100",
+            "cosmos1sender",
+            "osmo1receiver",
+        );
+        let json = br#"{"amount":"100","denom":"transfer/channel-0/uatom","receiver":"osmo1receiver","sender":"cosmos1sender"}"#;
+
+        assert_eq!(data.to_json_bytes(), json);
+        assert_eq!(FungibleTokenPacketData::from_json_bytes(json), Ok(data));
+
+        let data = FungibleTokenPacketData::new("uatom", "1", "a", "b").with_memo("<memo>");
+        assert_eq!(
+            data.to_json_bytes(),
+            br#"{"amount":"1","denom":"uatom","memo":"\u003cmemo\u003e","receiver":"b","sender":"a"}"#
+        );
+        assert_eq!(
+            FungibleTokenPacketData::from_json_bytes(&data.to_json_bytes()),
+            Ok(data)
+        );
+    }
+
+    #[test]
+    fn test_packet_data_json_errors() {
+        assert_eq!(
+            FungibleTokenPacketData::from_json_bytes(b"[]"),
+            Err(PacketDataError::InvalidJson)
+        );
+        assert_eq!(
+            FungibleTokenPacketData::from_json_bytes(br#"{"amount":100}"#),
+            Err(PacketDataError::InvalidJson)
+        );
+        assert_eq!(
+            FungibleTokenPacketData::from_json_bytes(br#"{"fee":"1"}"#),
+            Err(PacketDataError::UnknownField("fee".to_string()))
+        );
+        assert_eq!(
+            FungibleTokenPacketData::from_json_bytes(br#"{"amount":"1","amount":"2"}"#),
+            Err(PacketDataError::DuplicateField("amount".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_denom_trace() {
+        let cases = [
+            ("uatom", trace("", "uatom")),
+            (
+                "transfer/channel-0/uatom",
+                trace("transfer/channel-0", "uatom"),
+            ),
+            (
+                "transfer/channel-0/transfer/channel-141/uosmo",
+                trace("transfer/channel-0/transfer/channel-141", "uosmo"),
+            ),
+            ("gamm/pool/1", trace("", "gamm/pool/1")),
+            (
+                "transfer/channel-0/gamm/pool/1",
+                trace("transfer/channel-0", "gamm/pool/1"),
+            ),
+            (
+                "transfer/channel-+1/uatom",
+                trace("", "transfer/channel-+1/uatom"),
+            ),
+            (
+                "transfer/channel-/uatom",
+                trace("", "transfer/channel-/uatom"),
+            ),
+            (ATOM_ON_OSMOSIS, trace("", ATOM_ON_OSMOSIS)),
+        ];
+
+        for (full_path, expected) in cases {
+            let trace = DenomTrace::parse(full_path);
+            assert_eq!(trace, expected, "{full_path}");
+            assert_eq!(trace.full_path(), full_path);
+        }
+    }
+
+    #[test]
+    fn test_ibc_denom() {
+        assert_eq!(
+            DenomTrace::parse("transfer/channel-0/uatom").ibc_denom(),
+            ATOM_ON_OSMOSIS
+        );
+        assert_eq!(
+            DenomTrace::parse("transfer/channel-141/uosmo").ibc_denom(),
+            OSMO_ON_COSMOS_HUB
+        );
+        assert_eq!(DenomTrace::parse("uatom").ibc_denom(), "uatom");
+    }
+
+    #[test]
+    fn test_parse_ibc_denom() {
+        let trace = DenomTrace::parse("transfer/channel-0/uatom");
+
+        assert_eq!(
+            DenomTrace::parse_ibc_denom(ATOM_ON_OSMOSIS),
+            Ok(trace.hash())
+        );
+        assert_eq!(
+            DenomTrace::parse_ibc_denom(&ATOM_ON_OSMOSIS.to_ascii_lowercase()),
+            Ok(trace.hash())
+        );
+
+        for denom in ["uatom", "ibc/", "ibc/27394F", "ibc/not-hex"] {
+            assert_eq!(
+                DenomTrace::parse_ibc_denom(denom),
+                Err(DenomError::InvalidIbcDenom(denom.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_denom_trace() {
+        assert_eq!(trace("transfer/channel-0", "uatom").validate(), Ok(()));
+        assert_eq!(trace("", "uatom").validate(), Ok(()));
+        assert_eq!(
+            trace("transfer", "uatom").validate(),
+            Err(DenomError::InvalidTrace("transfer".to_string()))
+        );
+        assert_eq!(
+            trace("transfer/ch", "uatom").validate(),
+            Err(DenomError::InvalidTrace("transfer/ch".to_string()))
+        );
+        assert_eq!(
+            trace("transfer/channel-0", " ").validate(),
+            Err(DenomError::EmptyBaseDenom)
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Helpers to build and sign the transactions of the `cosmos.tx.v1beta1` module.
+//!
+//! A transaction signed with `SIGN_MODE_DIRECT` is assembled as follows:
+//!
+//! 1. the messages are packed into a [`TxBody`], and the fee and the signers into an
+//!    [`AuthInfo`], made of one [`SignerInfo`] per signer;
+//! 2. each signer signs the [`SignDoc::sign_bytes`] of the [`SignDoc`] built for their
+//!    account number, the chain ID and the encoded body and authentication info;
+//! 3. the encoded body and authentication info are broadcast along with the signatures,
+//!    in the order of the signers, in a [`TxRaw`] built with [`TxRaw::new`].
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use prost::Message;
+
+use crate::cosmos::base::v1beta1::Coin;
+use crate::cosmos::tx::signing::v1beta1::SignMode;
+use crate::cosmos::tx::v1beta1::mode_info::{self, Sum};
+use crate::cosmos::tx::v1beta1::{AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, TxBody, TxRaw};
+use crate::google::protobuf::Any;
+use crate::protobuf::{encode_canonical, erased, IntoConversionError, Name, Protobuf};
+
+impl TxBody {
+    /// Creates a transaction body executing the given, already packed, messages,
+    /// without any timeout nor extension options.
+    pub fn new(messages: impl IntoIterator<Item = Any>, memo: impl Into<String>) -> Self {
+        Self {
+            messages: messages.into_iter().collect(),
+            memo: memo.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Packs the given domain message into a `google.protobuf.Any` and appends it
+    /// to the messages to execute.
+    pub fn push<T, Raw>(&mut self, msg: &T)
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.messages.push(msg.to_any());
+    }
+
+    /// Same as [`TxBody::push`], but consumes and returns `self`.
+    pub fn with_msg<T, Raw>(mut self, msg: &T) -> Self
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + Name,
+        <T as erased::TryFrom<Raw>>::Error: IntoConversionError,
+    {
+        self.push(msg);
+        self
+    }
+
+    /// Sets the block height after which the transaction is not valid anymore.
+    pub fn with_timeout_height(mut self, timeout_height: u64) -> Self {
+        self.timeout_height = timeout_height;
+        self
+    }
+}
+
+impl AuthInfo {
+    /// Creates the authentication info of a transaction paying the given fee,
+    /// with the signers in the order their signatures are given in the `TxRaw`.
+    pub fn new(signer_infos: impl IntoIterator<Item = SignerInfo>, fee: Fee) -> Self {
+        Self {
+            signer_infos: signer_infos.into_iter().collect(),
+            fee: Some(fee),
+            ..Default::default()
+        }
+    }
+}
+
+impl SignerInfo {
+    /// Creates the info of a single signer, signing with `SIGN_MODE_DIRECT`.
+    ///
+    /// The `public_key` may be left out if the account of the signer already has
+    /// one in state, ie. if it has signed a transaction before.
+    pub fn direct(public_key: Option<Any>, sequence: u64) -> Self {
+        Self {
+            public_key,
+            mode_info: Some(ModeInfo {
+                sum: Some(Sum::Single(mode_info::Single {
+                    mode: SignMode::Direct as i32,
+                })),
+            }),
+            sequence,
+        }
+    }
+}
+
+impl Fee {
+    /// Creates a fee of the given amount, for a transaction using at most
+    /// `gas_limit` units of gas.
+    pub fn new(amount: impl IntoIterator<Item = Coin>, gas_limit: u64) -> Self {
+        Self {
+            amount: amount.into_iter().collect(),
+            gas_limit,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the account paying the fee, in place of the first signer.
+    pub fn with_payer(mut self, payer: impl Into<String>) -> Self {
+        self.payer = payer.into();
+        self
+    }
+
+    /// Sets the account granting the fee through a `cosmos.feegrant` allowance.
+    pub fn with_granter(mut self, granter: impl Into<String>) -> Self {
+        self.granter = granter.into();
+        self
+    }
+}
+
+impl SignDoc {
+    /// Creates the document to sign with `SIGN_MODE_DIRECT` for the given transaction
+    /// body and authentication info, both encoded with [`encode_canonical`].
+    ///
+    /// The very same `body_bytes` and `auth_info_bytes` must be used in the `TxRaw`
+    /// which is broadcast, for the signature to verify.
+    pub fn new(
+        body: &TxBody,
+        auth_info: &AuthInfo,
+        chain_id: impl Into<String>,
+        account_number: u64,
+    ) -> Self {
+        Self {
+            body_bytes: encode_canonical(body),
+            auth_info_bytes: encode_canonical(auth_info),
+            chain_id: chain_id.into(),
+            account_number,
+        }
+    }
+
+    /// Returns the bytes to sign with `SIGN_MODE_DIRECT`, ie. the deterministic
+    /// encoding of the document.
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        encode_canonical(self)
+    }
+}
+
+impl TxRaw {
+    /// Finalizes a transaction with the encoded body and authentication info of the
+    /// given sign document, and the signatures of its signers, in the order of the
+    /// signer infos.
+    pub fn new(sign_doc: SignDoc, signatures: impl IntoIterator<Item = Vec<u8>>) -> Self {
+        Self {
+            body_bytes: sign_doc.body_bytes.into(),
+            auth_info_bytes: sign_doc.auth_info_bytes.into(),
+            signatures: signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the bytes to broadcast, eg. with the `BroadcastTx` endpoint of the
+    /// `cosmos.tx.v1beta1.Service`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_canonical(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+    use crate::coin::CoinError;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Amount(Coin);
+
+    impl Protobuf<Coin> for Amount {}
+
+    impl TryFrom<Coin> for Amount {
+        type Error = CoinError;
+
+        fn try_from(coin: Coin) -> Result<Self, Self::Error> {
+            coin.validate_amount()?;
+            Ok(Self(coin))
+        }
+    }
+
+    impl From<Amount> for Coin {
+        fn from(amount: Amount) -> Self {
+            amount.0
+        }
+    }
+
+    fn tx() -> (TxBody, AuthInfo) {
+        let body = TxBody::new([], "memo")
+            .with_msg(&Amount(Coin::new(10, "uatom")))
+            .with_timeout_height(100);
+        let fee = Fee::new([Coin::new(5, "uatom")], 200_000)
+            .with_payer("payer")
+            .with_granter("granter");
+        let auth_info = AuthInfo::new([SignerInfo::direct(None, 3)], fee);
+        (body, auth_info)
+    }
+
+    #[test]
+    fn test_tx_body() {
+        let (body, _) = tx();
+        assert_eq!(body.memo, "memo");
+        assert_eq!(body.timeout_height, 100);
+        assert_eq!(body.messages.len(), 1);
+        assert_eq!(body.messages[0].type_url, Coin::type_url());
+        assert_eq!(
+            Amount::from_any(&body.messages[0]).unwrap(),
+            Amount(Coin::new(10, "uatom"))
+        );
+    }
+
+    #[test]
+    fn test_auth_info() {
+        let (_, auth_info) = tx();
+        let fee = auth_info.fee.unwrap();
+        assert_eq!(fee.amount, vec![Coin::new(5, "uatom")]);
+        assert_eq!(fee.gas_limit, 200_000);
+        assert_eq!(fee.payer, "payer");
+        assert_eq!(fee.granter, "granter");
+
+        let [signer_info] = &auth_info.signer_infos[..] else {
+            panic!("expected a single signer");
+        };
+        assert_eq!(signer_info.public_key, None);
+        assert_eq!(signer_info.sequence, 3);
+        assert_eq!(
+            signer_info.mode_info.clone().unwrap().sum,
+            Some(Sum::Single(mode_info::Single {
+                mode: SignMode::Direct as i32,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_sign_doc() {
+        let (body, auth_info) = tx();
+        let sign_doc = SignDoc::new(&body, &auth_info, "cosmoshub-4", 7);
+        assert_eq!(sign_doc.body_bytes, encode_canonical(&body));
+        assert_eq!(sign_doc.auth_info_bytes, encode_canonical(&auth_info));
+        assert_eq!(sign_doc.chain_id, "cosmoshub-4");
+        assert_eq!(sign_doc.account_number, 7);
+        assert_eq!(TxBody::decode(&*sign_doc.body_bytes).unwrap(), body);
+        assert_eq!(
+            AuthInfo::decode(&*sign_doc.auth_info_bytes).unwrap(),
+            auth_info
+        );
+
+        let sign_bytes = sign_doc.sign_bytes();
+        assert_eq!(sign_bytes, encode_canonical(&sign_doc));
+        assert_eq!(SignDoc::decode(&*sign_bytes).unwrap(), sign_doc);
+    }
+
+    #[test]
+    fn test_tx_raw() {
+        let (body, auth_info) = tx();
+        let sign_doc = SignDoc::new(&body, &auth_info, "cosmoshub-4", 7);
+        let tx = TxRaw::new(sign_doc.clone(), [vec![1, 2, 3], vec![4, 5, 6]]);
+
+        // The signatures only verify over the very bytes which were signed.
+        assert_eq!(tx.body_bytes, sign_doc.body_bytes);
+        assert_eq!(tx.auth_info_bytes, sign_doc.auth_info_bytes);
+        assert_eq!(tx.signatures, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let bytes = tx.to_bytes();
+        assert_eq!(bytes, encode_canonical(&tx));
+        assert_eq!(TxRaw::decode(&*bytes).unwrap(), tx);
+    }
+}
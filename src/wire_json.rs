@@ -0,0 +1,131 @@
+//! Encoding and decoding of the JSON objects made of string fields, which the IBC
+//! applications write as the data and acknowledgements of their packets, rather than
+//! their Protobuf encoding.
+//!
+//! The strings are escaped as by Go's `encoding/json`, ie. as by ibc-go, so that the
+//! commitments of the packets match.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::Chars;
+
+/// Writes a JSON string, escaping the characters as Go's `encoding/json`.
+fn write_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{0}'..='\u{1f}' | '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                json.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Writes a JSON object made of the given string fields, in order.
+pub(crate) fn write_object<'a>(fields: impl IntoIterator<Item = (&'a str, &'a str)>) -> Vec<u8> {
+    let mut json = String::from("{");
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_string(&mut json, key);
+        json.push(':');
+        write_string(&mut json, value);
+    }
+    json.push('}');
+
+    json.into_bytes()
+}
+
+/// Parses a JSON object made of string fields, returning their keys and values in
+/// order, or `None` if the bytes are not such an object.
+pub(crate) fn parse_object(bytes: &[u8]) -> Option<Vec<(String, String)>> {
+    let json = core::str::from_utf8(bytes).ok()?;
+    let mut rest = json
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')?
+        .trim_start();
+
+    let mut fields = Vec::new();
+    while !rest.is_empty() {
+        if !fields.is_empty() {
+            rest = rest.strip_prefix(',')?.trim_start();
+        }
+
+        let mut chars = rest.chars();
+        let key = parse_string(&mut chars)?;
+        rest = chars.as_str().trim_start().strip_prefix(':')?.trim_start();
+
+        let mut chars = rest.chars();
+        let value = parse_string(&mut chars)?;
+        rest = chars.as_str().trim_start();
+
+        fields.push((key, value));
+    }
+
+    Some(fields)
+}
+
+fn parse_string(chars: &mut Chars<'_>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => s.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => parse_escape(chars)?,
+                _ => return None,
+            }),
+            '\u{0}'..='\u{1f}' => return None,
+            c => s.push(c),
+        }
+    }
+}
+
+/// Parses the `XXXX` of a `\uXXXX` escape, and the low surrogate following a high one.
+fn parse_escape(chars: &mut Chars<'_>) -> Option<char> {
+    let code = parse_hex4(chars)?;
+    if !(0xd800..0xdc00).contains(&code) {
+        return char::from_u32(code);
+    }
+
+    if chars.next()? != '\\' || chars.next()? != 'u' {
+        return None;
+    }
+    let low = parse_hex4(chars)?;
+    if !(0xdc00..0xe000).contains(&low) {
+        return None;
+    }
+
+    char::from_u32(0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00))
+}
+
+fn parse_hex4(chars: &mut Chars<'_>) -> Option<u32> {
+    let hex = chars.as_str().get(..4)?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    chars.nth(3);
+    u32::from_str_radix(hex, 16).ok()
+}
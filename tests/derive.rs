@@ -0,0 +1,120 @@
+//! Derives `Protobuf` along with its conversions for a domain type of the IBC
+//! `Height`, and checks them against the raw type.
+
+#![cfg(feature = "derive")]
+
+use std::fmt;
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::protobuf::{Error, Protobuf};
+use prost::Message;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RevisionHeight(u64);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ZeroHeight;
+
+impl TryFrom<u64> for RevisionHeight {
+    type Error = ZeroHeight;
+
+    fn try_from(height: u64) -> Result<Self, Self::Error> {
+        if height == 0 {
+            Err(ZeroHeight)
+        } else {
+            Ok(Self(height))
+        }
+    }
+}
+
+impl From<RevisionHeight> for u64 {
+    fn from(height: RevisionHeight) -> Self {
+        height.0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum HeightError {
+    Zero,
+}
+
+impl From<ZeroHeight> for HeightError {
+    fn from(_: ZeroHeight) -> Self {
+        Self::Zero
+    }
+}
+
+impl fmt::Display for HeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the revision height is zero")
+    }
+}
+
+impl std::error::Error for HeightError {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Protobuf)]
+#[protobuf(raw = "RawHeight", from, try_from = "HeightError")]
+struct Height {
+    #[protobuf(into)]
+    revision_number: u64,
+    revision_height: RevisionHeight,
+}
+
+/// Only derives `Protobuf`, with the conversions implemented by hand.
+#[derive(Clone, Debug, PartialEq, Eq, Protobuf)]
+#[protobuf(raw = "RawHeight", crate = "ibc_proto")]
+struct RevisionNumber(u64);
+
+impl TryFrom<RawHeight> for RevisionNumber {
+    type Error = HeightError;
+
+    fn try_from(raw: RawHeight) -> Result<Self, Self::Error> {
+        Ok(Self(raw.revision_number))
+    }
+}
+
+impl From<RevisionNumber> for RawHeight {
+    fn from(number: RevisionNumber) -> Self {
+        RawHeight::new(number.0, 1)
+    }
+}
+
+#[test]
+fn test_derived_conversions() {
+    let height = Height {
+        revision_number: 1,
+        revision_height: RevisionHeight(42),
+    };
+    assert_eq!(RawHeight::from(height.clone()), RawHeight::new(1, 42));
+    assert_eq!(Height::try_from(RawHeight::new(1, 42)).unwrap(), height);
+    assert_eq!(
+        Height::try_from(RawHeight::new(1, 0)).unwrap_err(),
+        HeightError::Zero
+    );
+}
+
+#[test]
+fn test_derived_protobuf() {
+    let height = Height {
+        revision_number: 1,
+        revision_height: RevisionHeight(42),
+    };
+    let encoded = height.encode_vec();
+    assert_eq!(encoded, RawHeight::new(1, 42).encode_to_vec());
+    assert_eq!(Height::decode_vec(&encoded).unwrap(), height);
+    assert_eq!(Height::from_any(&height.to_any()).unwrap(), height);
+
+    let encoded = RawHeight::new(1, 0).encode_to_vec();
+    match Height::decode_vec(&encoded).unwrap_err() {
+        Error::TryFromProtobuf { reason, .. } => {
+            assert_eq!(reason.inner().downcast_ref(), Some(&HeightError::Zero));
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+
+    let number = RevisionNumber(3);
+    assert_eq!(
+        RevisionNumber::decode_vec(&number.encode_vec()).unwrap(),
+        number
+    );
+}
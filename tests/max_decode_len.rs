@@ -0,0 +1,28 @@
+//! Checks that the decoding methods without an explicit limit follow the one set with
+//! `set_max_decode_len`, which is process-wide, hence this test of its own.
+
+use ibc_proto::cosmos::crypto::secp256k1::PubKey;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::{set_max_decode_len, Error, Name};
+use ibc_proto::registry::Registry;
+use prost::Message;
+
+#[test]
+fn test_registry_max_decode_len() {
+    let key = PubKey { key: vec![2; 33] };
+    let any = Any {
+        type_url: PubKey::type_url(),
+        value: key.encode_to_vec(),
+    };
+    let registry = Registry::with_defaults();
+    assert!(registry.decode(&any).is_ok());
+
+    set_max_decode_len(any.value.len() - 1);
+    assert_eq!(
+        registry.decode(&any).unwrap_err(),
+        Error::message_too_large(any.value.len(), any.value.len() - 1)
+    );
+
+    set_max_decode_len(any.value.len());
+    assert!(registry.decode(&any).is_ok());
+}
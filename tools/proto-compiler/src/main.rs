@@ -1,6 +1,8 @@
 use argh::FromArgs;
 
 mod cmd;
+#[path = "../../../build/codegen.rs"]
+mod codegen;
 use cmd::clone::CloneCmd;
 use cmd::compile::CompileCmd;
 